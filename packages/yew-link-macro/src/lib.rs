@@ -12,6 +12,24 @@ use syn::{FnArg, Ident, ImplItem, ImplItemFn, ItemImpl, Pat, PatType, parse_macr
 /// `impl LinkedStateResolve for T { … }` with the user-provided resolve body.
 /// This half is stripped from WASM bundles automatically.
 ///
+/// ## Request context (optional)
+///
+/// If `resolve` takes a third `&RequestContext` parameter, the body is moved to
+/// `LinkedStateResolve::resolve_with_request` and receives the headers and extensions of the
+/// request being resolved. `resolve` then calls it with an empty context.
+///
+/// ## `fn subscribe` (optional)
+///
+/// If a `fn subscribe` returning a stream of updates is provided, it is moved to an
+/// `impl LinkedStateSubscribe for T { … }`, also stripped from WASM bundles. Like `resolve`, it
+/// may take a third `&RequestContext` parameter, in which case it becomes
+/// `LinkedStateSubscribe::subscribe_with_request`.
+///
+/// ## `fn options`, `fn error_status` and `fn persist_version` (optional)
+///
+/// A `fn options() -> LinkOptions`, a `fn error_status(error: &Self::Error) -> u16` and a
+/// `fn persist_version() -> Option<u32>` are kept in the `LinkedState` impl on all targets.
+///
 /// ## `type Error` (optional)
 ///
 /// If `type Error` is omitted, it defaults to [`yew_link::Never`] (an uninhabited
@@ -61,6 +79,8 @@ fn expand(impl_block: ItemImpl) -> syn::Result<proc_macro2::TokenStream> {
     let mut context_ty = None;
     let mut error_ty: Option<&syn::Type> = None;
     let mut resolve_fn: Option<&ImplItemFn> = None;
+    let mut subscribe_fn: Option<&ImplItemFn> = None;
+    let mut state_fns: Vec<&ImplItemFn> = Vec::new();
 
     for item in &impl_block.items {
         match item {
@@ -68,11 +88,21 @@ fn expand(impl_block: ItemImpl) -> syn::Result<proc_macro2::TokenStream> {
             ImplItem::Type(t) if t.ident == "Context" => context_ty = Some(&t.ty),
             ImplItem::Type(t) if t.ident == "Error" => error_ty = Some(&t.ty),
             ImplItem::Fn(f) if f.sig.ident == "resolve" => resolve_fn = Some(f),
+            ImplItem::Fn(f) if f.sig.ident == "subscribe" => subscribe_fn = Some(f),
+            ImplItem::Fn(f)
+                if f.sig.ident == "options"
+                    || f.sig.ident == "error_status"
+                    || f.sig.ident == "persist_version" =>
+            {
+                state_fns.push(f)
+            }
             other => {
                 return Err(syn::Error::new_spanned(
                     other,
                     "#[linked_state] expects only `type Input`, `type Context`, `type Error` \
-                     (optional), and `async fn resolve`",
+                     (optional), `async fn resolve`, `fn subscribe` (optional), `fn options` \
+                     (optional), `fn error_status` (optional), and `fn persist_version` \
+                     (optional)",
                 ));
             }
         }
@@ -85,6 +115,196 @@ fn expand(impl_block: ItemImpl) -> syn::Result<proc_macro2::TokenStream> {
     let resolve_fn = resolve_fn
         .ok_or_else(|| syn::Error::new(Span::call_site(), "missing `async fn resolve`"))?;
 
+    let params = resolve_params(resolve_fn, "input")?;
+    let (error_ty_tokens, resolve_body) = resolve_body(error_ty, resolve_fn);
+    let resolve_fns = resolve_fns(
+        quote! { ::yew_link::LinkedStateResolve },
+        quote! { <Self as ::yew_link::LinkedState>::Input },
+        quote! { ::core::result::Result<Self, <Self as ::yew_link::LinkedState>::Error> },
+        &params,
+        resolve_body,
+    );
+
+    let subscribe_impl = subscribe_fn.map(|subscribe_fn| {
+        let subscribe_fns = if subscribe_fn.sig.inputs.len() == 3 {
+            let mut with_request = subscribe_fn.clone();
+            with_request.sig.ident =
+                Ident::new("subscribe_with_request", subscribe_fn.sig.ident.span());
+            let output = &subscribe_fn.sig.output;
+            quote! {
+                fn subscribe(
+                    ctx: &Self::Context,
+                    input: &<Self as ::yew_link::LinkedState>::Input,
+                ) #output {
+                    <Self as ::yew_link::LinkedStateSubscribe>::subscribe_with_request(
+                        ctx,
+                        input,
+                        &::yew_link::RequestContext::default(),
+                    )
+                }
+
+                #with_request
+            }
+        } else {
+            quote! { #subscribe_fn }
+        };
+
+        quote! {
+            #[cfg(not(target_arch = "wasm32"))]
+            impl #impl_generics ::yew_link::LinkedStateSubscribe
+                for #self_ty #ty_generics #where_clause
+            {
+                #subscribe_fns
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::yew_link::LinkedState for #self_ty #ty_generics #where_clause {
+            type Input = #input_ty;
+            type Error = #error_ty_tokens;
+            const TYPE_KEY: &'static str =
+                ::core::concat!(::core::module_path!(), "::", ::core::stringify!(#self_ty));
+
+            #(#state_fns)*
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        impl #impl_generics ::yew_link::LinkedStateResolve for #self_ty #ty_generics #where_clause {
+            type Context = #context_ty;
+
+            #resolve_fns
+        }
+
+        #subscribe_impl
+    })
+}
+
+/// Derive a [`LinkedAction`] implementation from an impl block that declares
+/// `type Context`, `type Output`, and `async fn resolve`.
+///
+/// On all targets the macro emits `impl LinkedAction for T { type Output = …; type Error = …; }`,
+/// including the `optimistic`, `on_success` and `error_status` methods if they are provided.
+///
+/// On the server (`not(target_arch = "wasm32")`) it additionally emits
+/// `impl LinkedActionResolve for T { … }` with the user-provided resolve body.
+///
+/// `type Error` and the optional `&RequestContext` parameter of `resolve` behave like in
+/// [`macro@linked_state`].
+///
+/// # Example
+///
+/// ```ignore
+/// #[linked_action]
+/// impl LinkedAction for CreatePost {
+///     type Context = DbPool;
+///     type Output = u32;
+///
+///     async fn resolve(ctx: &DbPool, action: &CreatePost) -> u32 {
+///         ctx.insert_post(&action.title).await
+///     }
+///
+///     fn on_success(&self, _id: &u32, client: &LinkClient) {
+///         client.invalidate::<PostList>(&());
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn linked_action(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let impl_block = parse_macro_input!(item as ItemImpl);
+    match expand_action(impl_block) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_action(impl_block: ItemImpl) -> syn::Result<proc_macro2::TokenStream> {
+    let self_ty = &impl_block.self_ty;
+    let (impl_generics, ty_generics, where_clause) = impl_block.generics.split_for_impl();
+
+    let mut output_ty = None;
+    let mut context_ty = None;
+    let mut error_ty: Option<&syn::Type> = None;
+    let mut resolve_fn: Option<&ImplItemFn> = None;
+    let mut client_fns: Vec<&ImplItemFn> = Vec::new();
+
+    for item in &impl_block.items {
+        match item {
+            ImplItem::Type(t) if t.ident == "Output" => output_ty = Some(&t.ty),
+            ImplItem::Type(t) if t.ident == "Context" => context_ty = Some(&t.ty),
+            ImplItem::Type(t) if t.ident == "Error" => error_ty = Some(&t.ty),
+            ImplItem::Fn(f) if f.sig.ident == "resolve" => resolve_fn = Some(f),
+            ImplItem::Fn(f)
+                if f.sig.ident == "optimistic"
+                    || f.sig.ident == "on_success"
+                    || f.sig.ident == "error_status" =>
+            {
+                client_fns.push(f)
+            }
+            other => {
+                return Err(syn::Error::new_spanned(
+                    other,
+                    "#[linked_action] expects only `type Output`, `type Context`, `type Error` \
+                     (optional), `async fn resolve`, `fn optimistic` (optional), `fn on_success` \
+                     (optional), and `fn error_status` (optional)",
+                ));
+            }
+        }
+    }
+
+    let output_ty =
+        output_ty.ok_or_else(|| syn::Error::new(Span::call_site(), "missing `type Output`"))?;
+    let context_ty =
+        context_ty.ok_or_else(|| syn::Error::new(Span::call_site(), "missing `type Context`"))?;
+    let resolve_fn = resolve_fn
+        .ok_or_else(|| syn::Error::new(Span::call_site(), "missing `async fn resolve`"))?;
+
+    let params = resolve_params(resolve_fn, "action")?;
+    let (error_ty_tokens, resolve_body) = resolve_body(error_ty, resolve_fn);
+    let resolve_fns = resolve_fns(
+        quote! { ::yew_link::LinkedActionResolve },
+        quote! { Self },
+        quote! {
+            ::core::result::Result<
+                <Self as ::yew_link::LinkedAction>::Output,
+                <Self as ::yew_link::LinkedAction>::Error,
+            >
+        },
+        &params,
+        resolve_body,
+    );
+
+    Ok(quote! {
+        impl #impl_generics ::yew_link::LinkedAction for #self_ty #ty_generics #where_clause {
+            type Output = #output_ty;
+            type Error = #error_ty_tokens;
+            const TYPE_KEY: &'static str =
+                ::core::concat!(::core::module_path!(), "::", ::core::stringify!(#self_ty));
+
+            #(#client_fns)*
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        impl #impl_generics ::yew_link::LinkedActionResolve for #self_ty #ty_generics #where_clause {
+            type Context = #context_ty;
+
+            #resolve_fns
+        }
+    })
+}
+
+/// The parameters of a `resolve` fn.
+struct ResolveParams<'a> {
+    /// The context of the server.
+    ctx: &'a Ident,
+    /// The input of a state or the action.
+    arg: &'a Ident,
+    /// The request context, if it is taken.
+    request: Option<&'a Ident>,
+}
+
+/// Checks the signature of `resolve_fn`, whose second parameter is a reference to the `arg`.
+fn resolve_params<'a>(resolve_fn: &'a ImplItemFn, arg: &str) -> syn::Result<ResolveParams<'a>> {
     if resolve_fn.sig.asyncness.is_none() {
         return Err(syn::Error::new_spanned(
             resolve_fn.sig.fn_token,
@@ -93,46 +313,84 @@ fn expand(impl_block: ItemImpl) -> syn::Result<proc_macro2::TokenStream> {
     }
 
     let params: Vec<_> = resolve_fn.sig.inputs.iter().collect();
-    if params.len() != 2 {
+    if !(2..=3).contains(&params.len()) {
         return Err(syn::Error::new_spanned(
             &resolve_fn.sig.inputs,
-            "`resolve` must take exactly two parameters: context and input references",
+            format!(
+                "`resolve` must take two or three parameters: context and {arg} references, and \
+                 optionally a request context reference"
+            ),
         ));
     }
 
-    let ctx_name = param_ident(params[0])?;
-    let input_name = param_ident(params[1])?;
+    Ok(ResolveParams {
+        ctx: param_ident(params[0])?,
+        arg: param_ident(params[1])?,
+        request: params.get(2).map(|m| param_ident(m)).transpose()?,
+    })
+}
 
+/// Returns the error type and the body of the generated resolve fns.
+///
+/// Without `type Error`, the error type is `yew_link::Never` and the body of `resolve_fn` is
+/// wrapped in `Ok(…)`.
+fn resolve_body(
+    error_ty: Option<&syn::Type>,
+    resolve_fn: &ImplItemFn,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
     let resolve_stmts = &resolve_fn.block.stmts;
 
-    let (error_ty_tokens, resolve_body) = match error_ty {
+    match error_ty {
         Some(ty) => (quote! { #ty }, quote! { #(#resolve_stmts)* }),
         None => (
             quote! { ::yew_link::Never },
             quote! { ::core::result::Result::Ok({ #(#resolve_stmts)* }) },
         ),
-    };
-
-    Ok(quote! {
-        impl #impl_generics ::yew_link::LinkedState for #self_ty #ty_generics #where_clause {
-            type Input = #input_ty;
-            type Error = #error_ty_tokens;
-            const TYPE_KEY: &'static str =
-                ::core::concat!(::core::module_path!(), "::", ::core::stringify!(#self_ty));
-        }
+    }
+}
 
-        #[cfg(not(target_arch = "wasm32"))]
-        impl #impl_generics ::yew_link::LinkedStateResolve for #self_ty #ty_generics #where_clause {
-            type Context = #context_ty;
+/// Generates the `resolve` fn of `resolve_trait`, and its `resolve_with_request` fn if the
+/// request context is taken.
+fn resolve_fns(
+    resolve_trait: proc_macro2::TokenStream,
+    arg_ty: proc_macro2::TokenStream,
+    output: proc_macro2::TokenStream,
+    params: &ResolveParams<'_>,
+    body: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let ResolveParams { ctx, arg, request } = params;
 
+    match request {
+        None => quote! {
             async fn resolve<'__yew_link>(
-                #ctx_name: &'__yew_link Self::Context,
-                #input_name: &'__yew_link <Self as ::yew_link::LinkedState>::Input,
-            ) -> ::core::result::Result<Self, <Self as ::yew_link::LinkedState>::Error> {
-                #resolve_body
+                #ctx: &'__yew_link Self::Context,
+                #arg: &'__yew_link #arg_ty,
+            ) -> #output {
+                #body
             }
-        }
-    })
+        },
+        Some(request) => quote! {
+            async fn resolve<'__yew_link>(
+                ctx: &'__yew_link Self::Context,
+                arg: &'__yew_link #arg_ty,
+            ) -> #output {
+                <Self as #resolve_trait>::resolve_with_request(
+                    ctx,
+                    arg,
+                    &::yew_link::RequestContext::default(),
+                )
+                .await
+            }
+
+            async fn resolve_with_request<'__yew_link>(
+                #ctx: &'__yew_link Self::Context,
+                #arg: &'__yew_link #arg_ty,
+                #request: &'__yew_link ::yew_link::RequestContext,
+            ) -> #output {
+                #body
+            }
+        },
+    }
 }
 
 fn param_ident(arg: &FnArg) -> syn::Result<&Ident> {
@@ -0,0 +1,14 @@
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+struct CreatePost;
+
+#[yew_link::linked_action]
+impl yew_link::LinkedAction for CreatePost {
+    type Context = ();
+    type Output = u32;
+
+    async fn resolve(_action: &CreatePost) -> u32 {
+        0
+    }
+}
+
+fn main() {}
@@ -0,0 +1,57 @@
+#![no_implicit_prelude]
+
+#[derive(::serde::Serialize, ::serde::Deserialize, ::std::clone::Clone)]
+struct CreatePost {
+    title: ::std::string::String,
+}
+
+#[::yew_link::linked_action]
+impl ::yew_link::LinkedAction for CreatePost {
+    type Context = ();
+    type Output = u32;
+
+    async fn resolve(_ctx: &(), action: &CreatePost) -> u32 {
+        action.title.len() as u32
+    }
+}
+
+#[derive(::serde::Serialize, ::serde::Deserialize, ::std::clone::Clone)]
+struct DeletePost {
+    id: u32,
+}
+
+#[derive(::serde::Serialize, ::serde::Deserialize, ::std::clone::Clone, ::std::fmt::Debug)]
+struct NotFound;
+
+impl ::std::fmt::Display for NotFound {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        f.write_str("not found")
+    }
+}
+
+#[::yew_link::linked_action]
+impl ::yew_link::LinkedAction for DeletePost {
+    type Context = ();
+    type Output = ();
+    type Error = NotFound;
+
+    async fn resolve(
+        _ctx: &(),
+        action: &DeletePost,
+        request: &::yew_link::RequestContext,
+    ) -> ::std::result::Result<(), NotFound> {
+        let _ = request;
+        match action.id {
+            0 => ::std::result::Result::Err(NotFound),
+            _ => ::std::result::Result::Ok(()),
+        }
+    }
+
+    fn on_success(&self, _output: &(), _client: &::yew_link::LinkClient) {}
+
+    fn error_status(_error: &NotFound) -> u16 {
+        404
+    }
+}
+
+fn main() {}
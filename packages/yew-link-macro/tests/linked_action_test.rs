@@ -0,0 +1,7 @@
+#[allow(dead_code)]
+#[rustversion::attr(stable(1.85.0), test)]
+fn tests() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/linked_action/*-pass.rs");
+    t.compile_fail("tests/linked_action/*-fail.rs");
+}
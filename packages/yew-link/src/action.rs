@@ -0,0 +1,290 @@
+use std::fmt;
+use std::rc::Rc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Arc;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use yew::prelude::*;
+
+use crate::{LinkClient, LinkError, OptimisticUpdate, RequestContext, Resolver};
+
+/// A mutation that is sent to the server and run there.
+///
+/// Implementors are the payload of the action and declare its `Output` and
+/// `Error` types. Actions are dispatched with [`use_linked_action`] through the
+/// same [`Resolver`](crate::Resolver) and endpoint as [`LinkedState`](crate::LinkedState)s.
+/// The run logic is provided separately via
+/// [`Resolver::register_action`](crate::Resolver::register_action) or the
+/// [`#[linked_action]`](crate::linked_action) macro.
+pub trait LinkedAction: Serialize + DeserializeOwned + Clone + 'static {
+    /// The value returned by a successful action.
+    type Output: Serialize + DeserializeOwned + Clone + 'static;
+
+    /// Application-level error returned by a failed action.
+    type Error: Serialize + DeserializeOwned + Clone + fmt::Debug + fmt::Display + 'static;
+
+    /// Stable wire-format key used to route requests between client and server.
+    ///
+    /// Generated automatically by [`#[linked_action]`](crate::linked_action), like
+    /// [`LinkedState::TYPE_KEY`](crate::LinkedState::TYPE_KEY).
+    const TYPE_KEY: &'static str;
+
+    /// Called on the client when the action is dispatched.
+    ///
+    /// Use [`LinkClient::set_optimistic`] to display the expected outcome of the action while it
+    /// is pending. The returned update is committed once the action has succeeded, and rolled
+    /// back if it fails.
+    fn optimistic(&self, _client: &LinkClient) -> OptimisticUpdate {
+        OptimisticUpdate::default()
+    }
+
+    /// Called on the client once the action has succeeded, before the
+    /// dispatching component re-renders.
+    ///
+    /// Use `client` to invalidate or update the linked states affected by the
+    /// action.
+    fn on_success(&self, _output: &Self::Output, _client: &LinkClient) {}
+
+    /// The HTTP status the bundled handlers answer with when the action fails with `error`.
+    ///
+    /// Defaults to `422 Unprocessable Entity`, like
+    /// [`LinkedState::error_status`](crate::LinkedState::error_status).
+    fn error_status(_error: &Self::Error) -> u16 {
+        422
+    }
+}
+
+/// Server-side extension of [`LinkedAction`] that provides a resolve function.
+///
+/// You normally don't implement this by hand — use the
+/// [`#[linked_action]`](crate::linked_action) attribute macro instead.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait LinkedActionResolve: LinkedAction {
+    type Context: Send + Sync + 'static;
+
+    fn resolve<'a>(
+        ctx: &'a Self::Context,
+        action: &'a Self,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>> + Send + 'a;
+
+    /// Runs the action with the [`RequestContext`] of the request that dispatched it.
+    ///
+    /// Used by [`Resolver::register_linked_action`]. Defaults to [`resolve`](Self::resolve),
+    /// like [`LinkedStateResolve::resolve_with_request`](crate::LinkedStateResolve::resolve_with_request).
+    fn resolve_with_request<'a>(
+        ctx: &'a Self::Context,
+        action: &'a Self,
+        request: &'a RequestContext,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>> + Send + 'a {
+        let _ = request;
+        Self::resolve(ctx, action)
+    }
+}
+
+impl Resolver {
+    /// Register a handler for the action `A`. The closure receives the action
+    /// and returns a future that produces `Result<A::Output, A::Error>`.
+    pub fn register_action<A, F, Fut>(self, f: F) -> Self
+    where
+        A: LinkedAction,
+        A::Output: Send,
+        A::Error: Send,
+        F: Fn(A) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<A::Output, A::Error>> + Send + 'static,
+    {
+        self.register_handler::<A, A::Output, A::Error, _, _>(
+            A::TYPE_KEY,
+            A::error_status,
+            move |action, _| f(action),
+        )
+    }
+
+    /// Register a handler for the action `A` that receives the [`RequestContext`] of the
+    /// request that dispatched it, e.g. to check that the user is allowed to run it.
+    pub fn register_action_with_request<A, F, Fut>(self, f: F) -> Self
+    where
+        A: LinkedAction,
+        A::Output: Send,
+        A::Error: Send,
+        F: Fn(A, RequestContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<A::Output, A::Error>> + Send + 'static,
+    {
+        self.register_handler::<A, A::Output, A::Error, _, _>(A::TYPE_KEY, A::error_status, f)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Resolver {
+    /// Register a handler for the action `A` using its [`LinkedActionResolve`] impl.
+    ///
+    /// The `ctx` is wrapped in an [`Arc`] internally so clones are cheap.
+    pub fn register_linked_action<A>(self, ctx: A::Context) -> Self
+    where
+        A: LinkedActionResolve + Send,
+        A::Output: Send,
+        A::Error: Send,
+    {
+        let ctx = Arc::new(ctx);
+        self.register_action_with_request::<A, _, _>(move |action, request| {
+            let ctx = ctx.clone();
+            async move { A::resolve_with_request(&*ctx, &action, &request).await }
+        })
+    }
+}
+
+/// Handle returned by [`use_linked_action`].
+///
+/// Provides a [`dispatch`](Self::dispatch) method to run the action, whether
+/// a dispatched action is pending, and the result of the latest action.
+#[derive(Clone)]
+pub struct LinkedActionHandle<A: LinkedAction> {
+    dispatch: Callback<A>,
+    pending: bool,
+    result: Option<Result<Rc<A::Output>, LinkError<A::Error>>>,
+}
+
+impl<A: LinkedAction> LinkedActionHandle<A> {
+    /// Sends `action` to the server.
+    ///
+    /// If an action is dispatched while another one is pending, only the
+    /// result of the latest one is kept.
+    ///
+    /// On the server this is a no-op.
+    pub fn dispatch(&self, action: A) {
+        self.dispatch.emit(action);
+    }
+
+    /// Returns a callback that dispatches the action it is called with.
+    pub fn callback(&self) -> Callback<A> {
+        self.dispatch.clone()
+    }
+
+    /// Returns `true` while a dispatched action has not completed.
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+
+    /// Returns the output of the latest action, if it has succeeded.
+    pub fn output(&self) -> Option<Rc<A::Output>> {
+        self.result.as_ref()?.as_ref().ok().cloned()
+    }
+
+    /// Returns the error of the latest action, if it has failed.
+    pub fn error(&self) -> Option<&LinkError<A::Error>> {
+        self.result.as_ref()?.as_ref().err()
+    }
+
+    /// Returns the result of the latest completed action, or `None` if no
+    /// action has completed yet.
+    pub fn as_result(&self) -> Option<&Result<Rc<A::Output>, LinkError<A::Error>>> {
+        self.result.as_ref()
+    }
+}
+
+#[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+struct ActionState<A: LinkedAction> {
+    id: usize,
+    pending: bool,
+    result: Option<Result<Rc<A::Output>, LinkError<A::Error>>>,
+}
+
+#[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+enum ActionMsg<A: LinkedAction> {
+    Start(usize),
+    Finish(usize, Result<Rc<A::Output>, LinkError<A::Error>>),
+}
+
+#[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+impl<A: LinkedAction> Reducible for ActionState<A> {
+    type Action = ActionMsg<A>;
+
+    fn reduce(self: Rc<Self>, msg: Self::Action) -> Rc<Self> {
+        match msg {
+            ActionMsg::Start(id) => Rc::new(Self {
+                id,
+                pending: true,
+                result: self.result.clone(),
+            }),
+            // The result of an action that has been superseded is discarded.
+            ActionMsg::Finish(id, _) if id != self.id => self,
+            ActionMsg::Finish(id, result) => Rc::new(Self {
+                id,
+                pending: false,
+                result: Some(result),
+            }),
+        }
+    }
+}
+
+/// Dispatch [`LinkedAction`]s to the server.
+///
+/// Returns a [`LinkedActionHandle`] with a [`dispatch`](LinkedActionHandle::dispatch)
+/// method, whether an action is pending, and the result of the latest action.
+/// Once an action succeeds, [`LinkedAction::on_success`] is called to update the
+/// linked states it affects.
+///
+/// # Panics
+///
+/// Panics if there is no ancestor [`LinkProvider`](crate::LinkProvider) in the
+/// component tree.
+#[hook]
+pub fn use_linked_action<A: LinkedAction>() -> LinkedActionHandle<A> {
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    {
+        let state = use_reducer(|| ActionState::<A> {
+            id: 0,
+            pending: false,
+            result: None,
+        });
+
+        let link_ctx = use_context::<crate::LinkContextInner>()
+            .expect("use_linked_action requires a LinkProvider");
+        let next_id = use_mut_ref(|| 0);
+        let dispatcher = state.dispatcher();
+
+        let dispatch = Callback::from(move |action: A| {
+            let id = {
+                let mut next_id = next_id.borrow_mut();
+                *next_id += 1;
+                *next_id
+            };
+            dispatcher.dispatch(ActionMsg::Start(id));
+
+            let client = LinkClient::new(link_ctx.clone());
+            let optimistic = action.optimistic(&client);
+
+            let link_ctx = link_ctx.clone();
+            let dispatcher = dispatcher.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let result: Result<A::Output, LinkError<A::Error>> =
+                    link_ctx.request_remote(A::TYPE_KEY, &action).await;
+
+                match result {
+                    Ok(ref output) => {
+                        optimistic.commit();
+                        action.on_success(output, &client);
+                    }
+                    Err(ref e) => {
+                        optimistic.rollback();
+                        link_ctx.report(A::TYPE_KEY, e);
+                    }
+                }
+                dispatcher.dispatch(ActionMsg::Finish(id, result.map(Rc::new)));
+            });
+        });
+
+        LinkedActionHandle {
+            dispatch,
+            pending: state.pending,
+            result: state.result.clone(),
+        }
+    }
+
+    #[cfg(not(all(not(feature = "ssr"), target_arch = "wasm32")))]
+    LinkedActionHandle {
+        dispatch: Callback::from(|_: A| {}),
+        pending: false,
+        result: None,
+    }
+}
@@ -0,0 +1,227 @@
+use yew::prelude::*;
+
+use crate::LinkedState;
+#[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+use crate::{LinkContextInner, LinkError, cache_key};
+
+/// A handle to the linked-state cache of the nearest [`LinkProvider`](crate::LinkProvider).
+///
+/// Obtained with [`use_link_client`], and passed to the methods of
+/// [`LinkedAction`](crate::LinkedAction) to update the linked states affected by an action.
+///
+/// On the server all methods are no-ops.
+#[derive(Clone, PartialEq)]
+pub struct LinkClient {
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    inner: LinkContextInner,
+}
+
+impl LinkClient {
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    pub(crate) fn new(inner: LinkContextInner) -> Self {
+        Self { inner }
+    }
+
+    /// Marks the cached `T` for `input` as stale.
+    ///
+    /// Components displaying it re-fetch it in the background and keep showing the stale value
+    /// until the fresh one arrives, like [`refresh`](crate::LinkedStateHandle::refresh). If no
+    /// component displays it, the entry is dropped and fetched again on next use.
+    pub fn invalidate<T: LinkedState>(&self, input: &T::Input) {
+        #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+        self.inner.invalidate::<T>(cache_key::<T>(input));
+        #[cfg(not(all(not(feature = "ssr"), target_arch = "wasm32")))]
+        let _ = input;
+    }
+
+    /// Marks every cached `T` as stale, whatever its input.
+    pub fn invalidate_all<T: LinkedState>(&self) {
+        self.invalidate_where::<T>(|_| true);
+    }
+
+    /// Marks the cached `T`s whose input matches `predicate` as stale.
+    pub fn invalidate_where<T: LinkedState>(&self, predicate: impl Fn(&T::Input) -> bool) {
+        #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+        {
+            for key in self.inner.keys_of::<T>(&predicate) {
+                self.inner.invalidate::<T>(key);
+            }
+            // The persisted values that haven't been read since the start aren't cached.
+            self.inner.unpersist_where::<T>(predicate);
+        }
+        #[cfg(not(all(not(feature = "ssr"), target_arch = "wasm32")))]
+        let _ = predicate;
+    }
+
+    /// Replaces the cached `T` for `input` with `value`.
+    ///
+    /// Components displaying it are re-rendered with the new value without a request.
+    pub fn set<T: LinkedState>(&self, input: &T::Input, value: T) {
+        #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+        {
+            let key = cache_key::<T>(input);
+            self.inner
+                .store::<T>(key.clone(), &Ok::<_, LinkError<T::Error>>(value));
+            self.inner.notify(&key);
+        }
+        #[cfg(not(all(not(feature = "ssr"), target_arch = "wasm32")))]
+        let _ = (input, value);
+    }
+
+    /// Replaces the cached `T` for `input` with `value` until the returned [`OptimisticUpdate`]
+    /// is dropped.
+    ///
+    /// Call [`OptimisticUpdate::commit`] once the change has been confirmed to keep the value.
+    /// Otherwise the previous value is restored, unless the entry has been updated in the
+    /// meantime. The value is only persisted once it is committed.
+    pub fn set_optimistic<T: LinkedState>(&self, input: &T::Input, value: T) -> OptimisticUpdate {
+        #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+        {
+            let key = cache_key::<T>(input);
+            let previous = self.inner.cache.borrow().peek(&key).cloned();
+            let written = self
+                .inner
+                .store_unpersisted::<T>(key.clone(), &Ok::<_, LinkError<T::Error>>(value));
+            self.inner.notify(&key);
+
+            let commit = {
+                let inner = self.inner.clone();
+                let key = key.clone();
+                let written = written.clone();
+                move || {
+                    let Some(written) = written else {
+                        return;
+                    };
+                    // Otherwise the entry has been updated, and persisted if needed.
+                    if inner.cache.borrow().peek(&key) == Some(&written) {
+                        inner.persist::<T>(&key, written);
+                    }
+                }
+            };
+            let inner = self.inner.clone();
+            OptimisticUpdate {
+                commits: vec![Box::new(commit)],
+                rollbacks: vec![Box::new(move || {
+                    let mut cache = inner.cache.borrow_mut();
+                    if cache.peek(&key) != written.as_ref() {
+                        return;
+                    }
+                    match previous {
+                        Some(m) => {
+                            cache.put(key.clone(), m);
+                        }
+                        None => {
+                            cache.pop(&key);
+                        }
+                    }
+                    drop(cache);
+                    inner.notify(&key);
+                })],
+            }
+        }
+        #[cfg(not(all(not(feature = "ssr"), target_arch = "wasm32")))]
+        {
+            let _ = (input, value);
+            OptimisticUpdate::default()
+        }
+    }
+
+    /// Fetches the `T` for `input` into the cache ahead of its use, e.g. before navigating to a
+    /// page that displays it.
+    ///
+    /// Nothing is fetched if the entry is cached or already being fetched. Components that
+    /// request it while it is being fetched share the request.
+    pub fn prefetch<T: LinkedState>(&self, input: T::Input) {
+        #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+        {
+            let key = cache_key::<T>(&input);
+            if self.inner.cache.borrow().contains(&key)
+                || self.inner.in_flight.borrow().contains_key(&key)
+            {
+                return;
+            }
+            self.inner.fetch::<T>(key, input, &T::options());
+        }
+        #[cfg(not(all(not(feature = "ssr"), target_arch = "wasm32")))]
+        let _ = input;
+    }
+}
+
+/// Values written optimistically to the linked-state cache.
+///
+/// Returned by [`LinkClient::set_optimistic`]. Dropping it rolls the written values back unless
+/// it has been [`commit`](Self::commit)ted.
+#[must_use = "dropping an optimistic update rolls it back immediately"]
+#[derive(Default)]
+pub struct OptimisticUpdate {
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    commits: Vec<Box<dyn FnOnce()>>,
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    rollbacks: Vec<Box<dyn FnOnce()>>,
+}
+
+impl OptimisticUpdate {
+    /// Combines two updates so they are committed or rolled back together.
+    pub fn and(self, other: Self) -> Self {
+        #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+        {
+            let mut this = self;
+            let mut other = other;
+            this.commits.append(&mut other.commits);
+            this.rollbacks.append(&mut other.rollbacks);
+            this
+        }
+        #[cfg(not(all(not(feature = "ssr"), target_arch = "wasm32")))]
+        {
+            let _ = other;
+            self
+        }
+    }
+
+    /// Keeps the written values, and persists them if their type is persisted.
+    pub fn commit(self) {
+        #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+        {
+            let mut this = self;
+            this.rollbacks.clear();
+            for commit in this.commits.drain(..) {
+                commit();
+            }
+        }
+    }
+
+    /// Restores the values that were replaced. Equivalent to dropping the update.
+    pub fn rollback(self) {}
+}
+
+#[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+impl Drop for OptimisticUpdate {
+    fn drop(&mut self) {
+        // Rolled back in reverse order, so combined updates of the same entry restore the
+        // oldest value.
+        for rollback in self.rollbacks.drain(..).rev() {
+            rollback();
+        }
+    }
+}
+
+/// Returns a [`LinkClient`] to invalidate, update, or prefetch linked states.
+///
+/// # Panics
+///
+/// Panics if there is no ancestor [`LinkProvider`](crate::LinkProvider) in the component tree.
+#[hook]
+pub fn use_link_client() -> LinkClient {
+    let link_ctx =
+        use_context::<crate::LinkContextInner>().expect("use_link_client requires a LinkProvider");
+
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    {
+        LinkClient::new(link_ctx)
+    }
+    #[cfg(not(all(not(feature = "ssr"), target_arch = "wasm32")))]
+    {
+        let _ = link_ctx;
+        LinkClient {}
+    }
+}
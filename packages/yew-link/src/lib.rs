@@ -7,10 +7,16 @@ use std::fmt;
 use std::hash::Hash;
 #[cfg(target_arch = "wasm32")]
 use std::num::NonZeroUsize;
+use std::panic::{self, AssertUnwindSafe};
 use std::pin::Pin;
 use std::rc::Rc;
 use std::sync::Arc;
 
+use futures::FutureExt;
+#[cfg(target_arch = "wasm32")]
+use futures::channel::oneshot;
+#[cfg(feature = "ssr")]
+use futures::future::{LocalBoxFuture, Shared};
 #[cfg(target_arch = "wasm32")]
 use lru::LruCache;
 use serde::Serialize;
@@ -19,7 +25,27 @@ use yew::prelude::*;
 #[cfg(target_arch = "wasm32")]
 use yew::suspense::Suspension;
 use yew::suspense::SuspensionResult;
-pub use yew_link_macro::linked_state;
+pub use yew_link_macro::{linked_action, linked_state};
+
+mod action;
+mod client;
+mod options;
+mod persistence;
+mod request;
+#[cfg(not(target_arch = "wasm32"))]
+mod server_cache;
+mod subscription;
+mod transport;
+
+pub use action::*;
+pub use client::*;
+pub use options::*;
+pub use persistence::*;
+pub use request::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use server_cache::*;
+pub use subscription::*;
+pub use transport::*;
 
 /// A type that can be resolved on the server and transferred to the client.
 ///
@@ -40,6 +66,32 @@ pub trait LinkedState: Serialize + DeserializeOwned + Clone + 'static {
     /// `LinkedState` manually (e.g. for generic types), set this to a
     /// string that is identical across server and client builds.
     const TYPE_KEY: &'static str;
+
+    /// The options used when this state is requested with [`use_linked_state`].
+    ///
+    /// Can be provided in a [`#[linked_state]`](linked_state) impl.
+    fn options() -> LinkOptions {
+        LinkOptions::default()
+    }
+
+    /// The HTTP status the bundled handlers answer with when resolving this state fails with
+    /// `error`, e.g. `404` or `403`.
+    ///
+    /// Defaults to `422 Unprocessable Entity`. Can be provided in a
+    /// [`#[linked_state]`](linked_state) impl.
+    fn error_status(_error: &Self::Error) -> u16 {
+        422
+    }
+
+    /// The version under which the values of this state are persisted by the `persist` storage
+    /// of [`LinkProvider`], or `None` if they aren't persisted.
+    ///
+    /// Values persisted with another version are dropped, so bump it when the serialized form
+    /// of the state changes. Defaults to `None`. Can be provided in a
+    /// [`#[linked_state]`](linked_state) impl.
+    fn persist_version() -> Option<u32> {
+        None
+    }
 }
 
 /// Server-side extension of [`LinkedState`] that provides a resolve function.
@@ -56,6 +108,20 @@ pub trait LinkedStateResolve: LinkedState {
         ctx: &'a Self::Context,
         input: &'a Self::Input,
     ) -> impl Future<Output = Result<Self, Self::Error>> + Send + 'a;
+
+    /// Resolves the state with the [`RequestContext`] of the request being resolved.
+    ///
+    /// Used by [`Resolver::register_linked`]. Defaults to [`resolve`](Self::resolve), which
+    /// ignores the request. The [`#[linked_state]`](linked_state) macro implements it when
+    /// `resolve` takes the request as a third parameter.
+    fn resolve_with_request<'a>(
+        ctx: &'a Self::Context,
+        input: &'a Self::Input,
+        request: &'a RequestContext,
+    ) -> impl Future<Output = Result<Self, Self::Error>> + Send + 'a {
+        let _ = request;
+        Self::resolve(ctx, input)
+    }
 }
 
 /// An uninhabited error type that implements `Serialize`/`Deserialize`.
@@ -76,23 +142,86 @@ impl fmt::Display for Never {
 ///
 /// Distinguishes application-level errors (from the resolve function) from
 /// infrastructure failures (network, serialization, missing resolver).
-#[derive(Serialize, serde::Deserialize, Clone, Debug)]
+#[derive(Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub enum LinkError<E> {
     /// The resolve function returned an application-level error.
     Resolve(E),
     /// Infrastructure failure (network, serialization, missing resolver).
-    Internal(String),
+    Internal(InternalError),
 }
 
 impl<E: fmt::Display> fmt::Display for LinkError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Resolve(e) => fmt::Display::fmt(e, f),
-            Self::Internal(s) => f.write_str(s),
+            Self::Internal(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+/// An infrastructure failure, reported as [`LinkError::Internal`].
+#[derive(Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum InternalError {
+    /// The request could not be sent to the endpoint, or its response could not be received.
+    Network(String),
+    /// A request or response could not be encoded or decoded, e.g. because the client and the
+    /// server disagree on a type.
+    Codec(String),
+    /// No resolver is registered on the server for the type with this `TYPE_KEY`.
+    NoResolver(String),
+    /// The server could not decode the input of the request.
+    InvalidInput(String),
+    /// The resolver panicked.
+    Panic,
+    /// yew-link is used without the `ssr` feature on a target other than wasm32.
+    Unsupported,
+}
+
+impl InternalError {
+    /// The HTTP status the bundled handlers answer with.
+    fn status(&self) -> u16 {
+        match self {
+            Self::NoResolver(_) => 404,
+            Self::InvalidInput(_) => 400,
+            Self::Network(_) | Self::Codec(_) | Self::Panic | Self::Unsupported => 500,
+        }
+    }
+
+    /// Whether a request failing with this error may succeed when it is retried.
+    ///
+    /// Only failures to reach the endpoint are, which includes the endpoint answering with
+    /// `502 Bad Gateway`, `503 Service Unavailable` or `504 Gateway Timeout`. The other errors
+    /// fail the same way every time.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn is_retryable(&self) -> bool {
+        matches!(self, Self::Network(_))
+    }
+}
+
+impl fmt::Display for InternalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Network(e) => write!(f, "network error: {e}"),
+            Self::Codec(e) => write!(f, "codec error: {e}"),
+            Self::NoResolver(type_key) => write!(f, "no resolver registered for {type_key}"),
+            Self::InvalidInput(e) => write!(f, "failed to deserialize input: {e}"),
+            Self::Panic => f.write_str("the resolver panicked"),
+            Self::Unsupported => f.write_str(
+                "yew-link requires the `ssr` feature (server) or a wasm32 target (client)",
+            ),
         }
     }
 }
 
+/// A failed request of the client, passed to the `on_error` callback of [`LinkProvider`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinkErrorEvent {
+    /// The `TYPE_KEY` of the linked state or action.
+    pub type_key: &'static str,
+    /// The error. Application-level errors are serialized to JSON.
+    pub error: LinkError<serde_json::Value>,
+}
+
 /// Handle returned by [`use_linked_state`].
 ///
 /// Provides access to the resolved data, a [`refresh`](Self::refresh)
@@ -151,17 +280,152 @@ pub struct LinkRequest {
 }
 
 #[doc(hidden)]
-#[derive(Serialize, serde::Deserialize)]
+#[derive(Clone, Serialize, serde::Deserialize)]
 pub struct LinkResponse {
     #[serde(default)]
     ok: Option<serde_json::Value>,
     #[serde(default)]
     error: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    internal: Option<InternalError>,
+    /// The status chosen by [`LinkedState::error_status`], only known on the server.
+    #[serde(skip)]
+    error_status: Option<u16>,
 }
 
-type ResolveBoxFuture =
-    Pin<Box<dyn Future<Output = Result<serde_json::Value, serde_json::Value>> + Send>>;
-type ResolverFn = Box<dyn Fn(serde_json::Value) -> ResolveBoxFuture + Send + Sync>;
+impl LinkResponse {
+    fn ok(val: serde_json::Value) -> Self {
+        Self {
+            ok: Some(val),
+            error: None,
+            internal: None,
+            error_status: None,
+        }
+    }
+
+    fn error(err_val: serde_json::Value, status: u16) -> Self {
+        Self {
+            ok: None,
+            error: Some(err_val),
+            internal: None,
+            error_status: Some(status),
+        }
+    }
+
+    fn internal(e: InternalError) -> Self {
+        Self {
+            ok: None,
+            error: None,
+            internal: Some(e),
+            error_status: None,
+        }
+    }
+
+    /// The HTTP status of the response.
+    pub fn status(&self) -> u16 {
+        match (&self.ok, &self.internal) {
+            (Some(_), _) => 200,
+            (None, Some(e)) => e.status(),
+            (None, None) => self.error_status.unwrap_or(422),
+        }
+    }
+
+    /// Decodes the value or the error of the response.
+    #[cfg(any(feature = "ssr", target_arch = "wasm32"))]
+    fn to_result<O, E>(&self) -> Result<O, LinkError<E>>
+    where
+        O: DeserializeOwned,
+        E: DeserializeOwned,
+    {
+        let codec_error =
+            |e: serde_json::Error| LinkError::Internal(InternalError::Codec(e.to_string()));
+        match self {
+            Self { ok: Some(val), .. } => serde::Deserialize::deserialize(val).map_err(codec_error),
+            Self {
+                internal: Some(e), ..
+            } => Err(LinkError::Internal(e.clone())),
+            Self {
+                error: Some(err_val),
+                ..
+            } => Err(LinkError::Resolve(
+                serde::Deserialize::deserialize(err_val).map_err(codec_error)?,
+            )),
+            _ => Err(LinkError::Internal(InternalError::Codec(
+                "the response has neither a value nor an error".into(),
+            ))),
+        }
+    }
+}
+
+impl From<InternalError> for LinkResponse {
+    fn from(e: InternalError) -> Self {
+        Self::internal(e)
+    }
+}
+
+/// Query of a subscription request to the link endpoint.
+#[doc(hidden)]
+#[derive(Serialize, serde::Deserialize)]
+pub struct LinkSubscriptionQuery {
+    type_key: String,
+    /// The JSON-encoded input.
+    input: String,
+}
+
+impl LinkSubscriptionQuery {
+    /// Decodes the query into the request it encodes.
+    pub fn into_request(self) -> Result<LinkRequest, InternalError> {
+        let input = serde_json::from_str(&self.input)
+            .map_err(|e| InternalError::InvalidInput(e.to_string()))?;
+        Ok(LinkRequest {
+            type_key: self.type_key,
+            input,
+        })
+    }
+}
+
+/// Body of a response of the link endpoint, matching the [`LinkPayload`] of the request.
+#[doc(hidden)]
+#[derive(Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum LinkReply {
+    Single(LinkResponse),
+    Batch(Vec<LinkResponse>),
+}
+
+impl LinkReply {
+    /// The HTTP status of the reply: the status of a single response, or `200 OK` for a batch,
+    /// whose responses may fail independently.
+    pub fn status(&self) -> u16 {
+        match self {
+            Self::Single(resp) => resp.status(),
+            Self::Batch(_) => 200,
+        }
+    }
+}
+
+/// Body of a request to the link endpoint.
+///
+/// The client sends the requests made within one tick as a single batch, which is answered with
+/// one [`LinkResponse`] per request, in order.
+#[doc(hidden)]
+#[derive(Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum LinkPayload {
+    Single(LinkRequest),
+    Batch(Vec<LinkRequest>),
+}
+
+type ResolveBoxFuture = Pin<Box<dyn Future<Output = LinkResponse> + Send>>;
+type ResolverFn = Box<dyn Fn(serde_json::Value, RequestContext) -> ResolveBoxFuture + Send + Sync>;
+type SubscribeFn = Box<
+    dyn Fn(
+            serde_json::Value,
+            RequestContext,
+        ) -> Result<futures::stream::BoxStream<'static, serde_json::Value>, InternalError>
+        + Send
+        + Sync,
+>;
 
 /// Registry of resolve functions, keyed by [`std::any::type_name`].
 ///
@@ -169,13 +433,26 @@ type ResolverFn = Box<dyn Fn(serde_json::Value) -> ResolveBoxFuture + Send + Syn
 /// axum handler when the `axum` feature is enabled.
 pub struct Resolver {
     handlers: HashMap<&'static str, ResolverFn>,
+    subscriptions: HashMap<&'static str, SubscribeFn>,
+    #[cfg(not(target_arch = "wasm32"))]
+    cache: ServerCache,
+    #[cfg(not(target_arch = "wasm32"))]
+    cache_policies: HashMap<&'static str, server_cache::CachePolicy>,
+    #[cfg(not(target_arch = "wasm32"))]
+    cache_vary: HashMap<&'static str, server_cache::VaryFn>,
 }
 
 impl fmt::Debug for Resolver {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Resolver")
-            .field("types", &self.handlers.keys().collect::<Vec<_>>())
-            .finish()
+        let mut f = f.debug_struct("Resolver");
+        f.field("types", &self.handlers.keys().collect::<Vec<_>>())
+            .field(
+                "subscriptions",
+                &self.subscriptions.keys().collect::<Vec<_>>(),
+            );
+        #[cfg(not(target_arch = "wasm32"))]
+        f.field("cached", &self.cache_policies.keys().collect::<Vec<_>>());
+        f.finish()
     }
 }
 
@@ -183,57 +460,159 @@ impl Resolver {
     pub fn new() -> Self {
         Self {
             handlers: HashMap::new(),
+            subscriptions: HashMap::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            cache: ServerCache::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            cache_policies: HashMap::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            cache_vary: HashMap::new(),
         }
     }
 
     /// Register a resolver for `T`. The closure receives `T::Input` and returns
     /// a future that produces `Result<T, T::Error>`.
-    pub fn register<T, F, Fut>(mut self, f: F) -> Self
+    pub fn register<T, F, Fut>(self, f: F) -> Self
     where
         T: LinkedState + Send,
         T::Error: Send,
         F: Fn(T::Input) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<T, T::Error>> + Send + 'static,
     {
-        self.handlers.insert(
+        self.register_handler::<T::Input, T, T::Error, _, _>(
             T::TYPE_KEY,
-            Box::new(move |input_json: serde_json::Value| {
-                let input: T::Input = match serde_json::from_value(input_json) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        return Box::pin(async move {
-                            Err(serde_json::Value::String(format!(
-                                "failed to deserialize input: {e}"
-                            )))
-                        });
-                    }
-                };
-                let fut = f(input);
-                Box::pin(async move {
-                    match fut.await {
-                        Ok(val) => serde_json::to_value(&val)
-                            .map_err(|e| serde_json::Value::String(e.to_string())),
-                        Err(e) => Err(serde_json::to_value(&e).unwrap_or_else(|ser_err| {
-                            serde_json::Value::String(format!(
-                                "{e}: (serialization failed: {ser_err})"
-                            ))
-                        })),
-                    }
-                })
-            }),
+            T::error_status,
+            move |input, _| f(input),
+        )
+    }
+
+    /// Register a resolver for `T` that receives the [`RequestContext`] of the
+    /// request being resolved, e.g. to read the authenticated user.
+    pub fn register_with_request<T, F, Fut>(self, f: F) -> Self
+    where
+        T: LinkedState + Send,
+        T::Error: Send,
+        F: Fn(T::Input, RequestContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<T, T::Error>> + Send + 'static,
+    {
+        self.register_handler::<T::Input, T, T::Error, _, _>(T::TYPE_KEY, T::error_status, f)
+    }
+
+    /// Register a handler under `type_key`, decoding its input and encoding its
+    /// result as JSON. Errors are answered with the HTTP status returned by `status`.
+    fn register_handler<I, O, E, F, Fut>(
+        mut self,
+        type_key: &'static str,
+        status: fn(&E) -> u16,
+        f: F,
+    ) -> Self
+    where
+        I: DeserializeOwned,
+        O: Serialize + Send,
+        E: Serialize + fmt::Display + Send,
+        F: Fn(I, RequestContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<O, E>> + Send + 'static,
+    {
+        self.handlers.insert(
+            type_key,
+            Box::new(
+                move |input_json: serde_json::Value, request: RequestContext| {
+                    let input: I = match serde_json::from_value(input_json) {
+                        Ok(v) => v,
+                        Err(e) => {
+                            let resp =
+                                LinkResponse::internal(InternalError::InvalidInput(e.to_string()));
+                            return Box::pin(async move { resp });
+                        }
+                    };
+                    let Ok(fut) = panic::catch_unwind(AssertUnwindSafe(|| f(input, request)))
+                    else {
+                        return Box::pin(async { LinkResponse::internal(InternalError::Panic) });
+                    };
+                    Box::pin(async move {
+                        match AssertUnwindSafe(fut).catch_unwind().await {
+                            Ok(Ok(val)) => match serde_json::to_value(&val) {
+                                Ok(val) => LinkResponse::ok(val),
+                                Err(e) => {
+                                    LinkResponse::internal(InternalError::Codec(e.to_string()))
+                                }
+                            },
+                            Ok(Err(e)) => match serde_json::to_value(&e) {
+                                Ok(err_val) => LinkResponse::error(err_val, status(&e)),
+                                Err(ser_err) => LinkResponse::internal(InternalError::Codec(
+                                    format!("{e}: (serialization failed: {ser_err})"),
+                                )),
+                            },
+                            Err(_) => LinkResponse::internal(InternalError::Panic),
+                        }
+                    })
+                },
+            ),
         );
         self
     }
 
-    /// Resolve a [`LinkRequest`].
-    pub async fn resolve_request(
+    /// Resolve a [`LinkRequest`] with an empty [`RequestContext`].
+    pub async fn resolve_request(&self, req: &LinkRequest) -> LinkResponse {
+        self.resolve_request_with(req, &RequestContext::default())
+            .await
+    }
+
+    /// Resolve a [`LinkRequest`] made by the request described by `request`.
+    ///
+    /// Panics of the resolver are caught and answered with [`InternalError::Panic`]. Types
+    /// enabled with `Resolver::cache` are answered from the [`ServerCache`] while their entry for
+    /// the vary key of `request` is fresh.
+    pub async fn resolve_request_with(
         &self,
         req: &LinkRequest,
-    ) -> Result<serde_json::Value, serde_json::Value> {
-        let handler = self.handlers.get(req.type_key.as_str()).ok_or_else(|| {
-            serde_json::Value::String(format!("no resolver registered for {}", req.type_key))
-        })?;
-        handler(req.input.clone()).await
+        request: &RequestContext,
+    ) -> LinkResponse {
+        let Some(handler) = self.handlers.get(req.type_key.as_str()) else {
+            return LinkResponse::internal(InternalError::NoResolver(req.type_key.clone()));
+        };
+        let resolve = || handler(req.input.clone(), request.clone());
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(policy) = self.cache_policies.get(req.type_key.as_str()) {
+            let vary = match self.cache_vary.get(req.type_key.as_str()) {
+                Some(vary) => vary(request),
+                None => Some(String::new()),
+            };
+            if let Some(vary) = vary {
+                return self.cache.get_or_resolve(req, vary, policy, resolve).await;
+            }
+        }
+        resolve().await
+    }
+
+    /// Resolve a single request or a batch of requests.
+    pub async fn resolve_payload(
+        &self,
+        payload: LinkPayload,
+        request: &RequestContext,
+    ) -> LinkReply {
+        match payload {
+            LinkPayload::Single(req) => {
+                LinkReply::Single(self.resolve_request_with(&req, request).await)
+            }
+            LinkPayload::Batch(reqs) => LinkReply::Batch(self.resolve_batch(&reqs, request).await),
+        }
+    }
+
+    /// Resolve a batch of [`LinkRequest`]s concurrently.
+    ///
+    /// The responses are in the order of the requests.
+    pub async fn resolve_batch(
+        &self,
+        reqs: &[LinkRequest],
+        request: &RequestContext,
+    ) -> Vec<LinkResponse> {
+        futures::future::join_all(
+            reqs.iter()
+                .map(|req| self.resolve_request_with(req, request)),
+        )
+        .await
     }
 }
 
@@ -255,9 +634,9 @@ impl Resolver {
         T::Error: Send,
     {
         let ctx = Arc::new(ctx);
-        self.register::<T, _, _>(move |input| {
+        self.register_with_request::<T, _, _>(move |input, request| {
             let ctx = ctx.clone();
-            async move { T::resolve(&*ctx, &input).await }
+            async move { T::resolve_with_request(&*ctx, &input, &request).await }
         })
     }
 }
@@ -285,14 +664,14 @@ impl PartialEq for CacheKey {
 
 impl Eq for CacheKey {}
 
-#[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+#[cfg(any(feature = "ssr", target_arch = "wasm32"))]
 fn eq_inputs<I: PartialEq + 'static>(a: &dyn Any, b: &dyn Any) -> bool {
     a.downcast_ref::<I>()
         .zip(b.downcast_ref::<I>())
         .is_some_and(|(a, b)| a == b)
 }
 
-#[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+#[cfg(any(feature = "ssr", target_arch = "wasm32"))]
 fn cache_key<T: LinkedState>(input: &T::Input) -> CacheKey {
     use std::hash::Hasher;
     let mut hasher = std::collections::hash_map::DefaultHasher::new();
@@ -310,37 +689,118 @@ type Cache = Rc<RefCell<LruCache<CacheKey, serde_json::Value>>>;
 #[cfg(not(target_arch = "wasm32"))]
 type Cache = Rc<RefCell<HashMap<CacheKey, serde_json::Value>>>;
 
+/// Resolutions made while rendering on the server, shared by the components requesting the
+/// same state.
+#[cfg(feature = "ssr")]
+type Resolved = Rc<RefCell<HashMap<CacheKey, Shared<LocalBoxFuture<'static, Rc<LinkResponse>>>>>>;
+
+/// When the cached values were fetched, in milliseconds since the epoch.
+#[cfg(target_arch = "wasm32")]
+type FetchedAt = Rc<RefCell<LruCache<CacheKey, f64>>>;
+
 #[cfg(target_arch = "wasm32")]
 type InFlight = Rc<RefCell<HashMap<CacheKey, Suspension>>>;
 
 #[cfg(target_arch = "wasm32")]
 type Refreshing = Rc<RefCell<HashSet<CacheKey>>>;
 
+/// Requests waiting to be sent in the next batch.
+#[cfg(target_arch = "wasm32")]
+type Pending = Rc<RefCell<Vec<(LinkRequest, oneshot::Sender<BatchedResponse>)>>>;
+
+#[cfg(target_arch = "wasm32")]
+type BatchedResponse = Result<LinkResponse, InternalError>;
+
+/// A server-sent events connection streaming the updates of a cache entry.
+#[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+struct LiveSubscription {
+    /// The number of mounted components subscribed to the entry.
+    count: usize,
+    source: web_sys::EventSource,
+    _on_message: wasm_bindgen::closure::Closure<dyn FnMut(web_sys::MessageEvent)>,
+}
+
+#[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+impl Drop for LiveSubscription {
+    fn drop(&mut self) {
+        self.source.close();
+    }
+}
+
+#[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+type Live = Rc<RefCell<HashMap<CacheKey, LiveSubscription>>>;
+
+/// Re-render callbacks of the mounted components, by the cache entry they display.
+#[cfg(target_arch = "wasm32")]
+type Listeners = Rc<RefCell<HashMap<CacheKey, Vec<(usize, Callback<()>)>>>>;
+
 #[derive(Clone)]
 struct LinkContextInner {
     cache: Cache,
     #[cfg(target_arch = "wasm32")]
+    fetched_at: FetchedAt,
+    #[cfg(target_arch = "wasm32")]
     in_flight: InFlight,
     #[cfg(target_arch = "wasm32")]
     refreshing: Refreshing,
+    #[cfg(target_arch = "wasm32")]
+    listeners: Listeners,
+    #[cfg(target_arch = "wasm32")]
+    pending: Pending,
+    #[cfg(target_arch = "wasm32")]
+    batch: bool,
+    #[cfg(target_arch = "wasm32")]
+    transport: Rc<dyn LinkTransport>,
+    #[cfg(target_arch = "wasm32")]
+    codec: LinkCodec,
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    live: Live,
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    on_error: Option<Callback<LinkErrorEvent>>,
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    persisted: Option<PersistedCache>,
     endpoint: AttrValue,
     #[cfg(feature = "ssr")]
     resolver: Option<Arc<Resolver>>,
+    #[cfg(feature = "ssr")]
+    request: RequestContext,
+    #[cfg(feature = "ssr")]
+    resolved: Resolved,
 }
 
 impl PartialEq for LinkContextInner {
     fn eq(&self, other: &Self) -> bool {
-        Rc::ptr_eq(&self.cache, &other.cache) && self.endpoint == other.endpoint && {
-            #[cfg(target_arch = "wasm32")]
-            {
-                Rc::ptr_eq(&self.in_flight, &other.in_flight)
-                    && Rc::ptr_eq(&self.refreshing, &other.refreshing)
+        Rc::ptr_eq(&self.cache, &other.cache)
+            && self.endpoint == other.endpoint
+            && {
+                #[cfg(target_arch = "wasm32")]
+                {
+                    Rc::ptr_eq(&self.fetched_at, &other.fetched_at)
+                        && Rc::ptr_eq(&self.in_flight, &other.in_flight)
+                        && Rc::ptr_eq(&self.refreshing, &other.refreshing)
+                        && Rc::ptr_eq(&self.listeners, &other.listeners)
+                        && Rc::ptr_eq(&self.pending, &other.pending)
+                        && self.batch == other.batch
+                        && Rc::ptr_eq(&self.transport, &other.transport)
+                        && self.codec == other.codec
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    true
+                }
             }
-            #[cfg(not(target_arch = "wasm32"))]
-            {
-                true
+            && {
+                #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+                {
+                    Rc::ptr_eq(&self.live, &other.live)
+                        && self.on_error == other.on_error
+                        && self.persisted == other.persisted
+                }
+                #[cfg(not(all(not(feature = "ssr"), target_arch = "wasm32")))]
+                {
+                    true
+                }
             }
-        }
     }
 }
 
@@ -349,37 +809,445 @@ impl LinkContextInner {
     async fn fetch_remote<T: LinkedState>(
         &self,
         input: &T::Input,
+        options: &LinkOptions,
     ) -> Result<T, LinkError<T::Error>> {
-        use gloo_net::http::Request;
+        let mut attempt = 0;
+        loop {
+            match self.request_remote(T::TYPE_KEY, input).await {
+                Err(LinkError::Internal(ref e)) if e.is_retryable() && attempt < options.retry => {
+                    attempt += 1;
+                    yew::platform::time::sleep(options.retry_delay(attempt)).await;
+                }
+                result => {
+                    if let Err(ref e) = result {
+                        self.report(T::TYPE_KEY, e);
+                    }
+                    return result;
+                }
+            }
+        }
+    }
 
-        let req_body = LinkRequest {
-            type_key: T::TYPE_KEY.to_string(),
-            input: serde_json::to_value(input).map_err(|e| LinkError::Internal(e.to_string()))?,
+    /// Passes a failed request to the `on_error` callback of the provider.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn report<E: Serialize>(&self, type_key: &'static str, error: &LinkError<E>) {
+        let Some(ref on_error) = self.on_error else {
+            return;
         };
-        let resp = Request::post(self.endpoint.as_ref())
-            .json(&req_body)
-            .map_err(|e| LinkError::Internal(e.to_string()))?
-            .send()
-            .await
-            .map_err(|e| LinkError::Internal(e.to_string()))?;
+        let error = match error {
+            LinkError::Resolve(e) => {
+                LinkError::Resolve(serde_json::to_value(e).unwrap_or_default())
+            }
+            LinkError::Internal(e) => LinkError::Internal(e.clone()),
+        };
+        on_error.emit(LinkErrorEvent { type_key, error });
+    }
+
+    /// Sends `input` to the handler registered under `type_key` on the endpoint.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    async fn request_remote<I, O, E>(&self, type_key: &str, input: &I) -> Result<O, LinkError<E>>
+    where
+        I: Serialize,
+        O: DeserializeOwned,
+        E: DeserializeOwned,
+    {
+        let req = LinkRequest {
+            type_key: type_key.to_string(),
+            input: serde_json::to_value(input)
+                .map_err(|e| LinkError::Internal(InternalError::Codec(e.to_string())))?,
+        };
+        let link_resp = if self.batch {
+            self.enqueue(req).await
+        } else {
+            self.post(&LinkPayload::Single(req)).await
+        }
+        .map_err(LinkError::Internal)?;
+
+        link_resp.to_result()
+    }
 
-        let link_resp: LinkResponse = resp
-            .json()
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    async fn post<R: DeserializeOwned>(&self, payload: &LinkPayload) -> Result<R, InternalError> {
+        let body = self
+            .transport
+            .send(TransportRequest {
+                endpoint: self.endpoint.clone(),
+                content_type: self.codec.content_type(),
+                body: self.codec.encode(payload).map_err(InternalError::Codec)?,
+            })
             .await
-            .map_err(|e| LinkError::Internal(e.to_string()))?;
+            .map_err(InternalError::Network)?;
+
+        self.codec.decode(&body).map_err(InternalError::Codec)
+    }
+
+    /// Adds `req` to the next batch, which is sent once the requests made in the current tick
+    /// have been collected.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn enqueue(&self, req: LinkRequest) -> impl Future<Output = BatchedResponse> {
+        let (tx, rx) = oneshot::channel();
+        let mut pending = self.pending.borrow_mut();
+        pending.push((req, tx));
+        if pending.len() == 1 {
+            let link_ctx = self.clone();
+            wasm_bindgen_futures::spawn_local(async move { link_ctx.flush().await });
+        }
+
+        async move {
+            rx.await.unwrap_or_else(|_| {
+                Err(InternalError::Network("batched request was dropped".into()))
+            })
+        }
+    }
+
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    async fn flush(&self) {
+        let (mut reqs, senders): (Vec<_>, Vec<_>) = std::mem::take(&mut *self.pending.borrow_mut())
+            .into_iter()
+            .unzip();
+
+        // A single request is sent as is, so endpoints that don't support batches keep working
+        // as long as requests aren't made concurrently.
+        let resps: Result<Vec<LinkResponse>, InternalError> = match reqs.len() {
+            1 => self
+                .post(&LinkPayload::Single(reqs.remove(0)))
+                .await
+                .map(|m| vec![m]),
+            _ => self.post(&LinkPayload::Batch(reqs)).await,
+        };
+
+        match resps {
+            Ok(resps) if resps.len() == senders.len() => {
+                for (tx, resp) in senders.into_iter().zip(resps) {
+                    let _ = tx.send(Ok(resp));
+                }
+            }
+            Ok(_) => {
+                for tx in senders {
+                    let _ = tx.send(Err(InternalError::Codec(
+                        "batch response length mismatch".into(),
+                    )));
+                }
+            }
+            Err(e) => {
+                for tx in senders {
+                    let _ = tx.send(Err(e.clone()));
+                }
+            }
+        }
+    }
+
+    /// Caches `result` unless it is an infrastructure failure, which is retried on next use.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn store<T: LinkedState>(&self, key: CacheKey, result: &Result<T, LinkError<T::Error>>) {
+        if let Some(json_val) = self.store_unpersisted(key.clone(), result) {
+            if result.is_ok() {
+                self.persist::<T>(&key, json_val);
+            }
+        }
+    }
+
+    /// Caches `result` like [`store`](Self::store) without persisting it, and returns the
+    /// cached value.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn store_unpersisted<T: LinkedState>(
+        &self,
+        key: CacheKey,
+        result: &Result<T, LinkError<T::Error>>,
+    ) -> Option<serde_json::Value> {
+        let should_cache = match result {
+            Ok(_) | Err(LinkError::Resolve(_)) => true,
+            Err(LinkError::Internal(_)) => false,
+        };
+        if !should_cache {
+            return None;
+        }
+        let json_val = serde_json::to_value(result).ok()?;
+        self.fetched_at
+            .borrow_mut()
+            .put(key.clone(), js_sys::Date::now());
+        self.cache.borrow_mut().put(key, json_val.clone());
+        Some(json_val)
+    }
+
+    /// Persists `json_val`, the cached value of `key`, if `T` is persisted.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn persist<T: LinkedState>(&self, key: &CacheKey, json_val: serde_json::Value) {
+        let (Some(persisted), Some(version)) = (&self.persisted, T::persist_version()) else {
+            return;
+        };
+        persisted.take_rehydrated(key);
+        let fetched_at = self
+            .fetched_at
+            .borrow()
+            .peek(key)
+            .copied()
+            .unwrap_or_else(js_sys::Date::now);
+        if let Some(input) = key
+            .input
+            .downcast_ref::<T::Input>()
+            .and_then(|m| serde_json::to_value(m).ok())
+        {
+            persisted.put(T::TYPE_KEY, input, version, json_val, fetched_at);
+        }
+    }
+
+    /// Drops the persisted values of `T` whose input matches `predicate`.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn unpersist_where<T: LinkedState>(&self, predicate: impl Fn(&T::Input) -> bool) {
+        let (Some(persisted), Some(_)) = (&self.persisted, T::persist_version()) else {
+            return;
+        };
+        persisted.remove_where(T::TYPE_KEY, |input| {
+            serde_json::from_value::<T::Input>(input.clone()).is_ok_and(|m| predicate(&m))
+        });
+    }
+
+    /// Reads the value persisted for `key` into the cache if it isn't cached, suspending until
+    /// the persisted cache is loaded.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn rehydrate<T: LinkedState>(&self, key: &CacheKey) -> Result<(), Suspension> {
+        let (Some(persisted), Some(version)) = (&self.persisted, T::persist_version()) else {
+            return Ok(());
+        };
+        if self.cache.borrow().contains(key) {
+            return Ok(());
+        }
+        let Some(input) = key
+            .input
+            .downcast_ref::<T::Input>()
+            .and_then(|m| serde_json::to_value(m).ok())
+        else {
+            return Ok(());
+        };
+
+        if let Some((json_val, fetched_at)) = persisted.get(T::TYPE_KEY, &input, version)? {
+            self.fetched_at.borrow_mut().put(key.clone(), fetched_at);
+            self.cache.borrow_mut().put(key.clone(), json_val);
+            persisted.mark_rehydrated(key.clone());
+        }
+        Ok(())
+    }
+
+    /// Returns whether the value cached for `key` was read from the persisted cache and hasn't
+    /// been re-fetched yet.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn take_rehydrated(&self, key: &CacheKey) -> bool {
+        self.persisted
+            .as_ref()
+            .is_some_and(|m| m.take_rehydrated(key))
+    }
+
+    /// Returns whether the value cached for `key` was fetched more than `stale_time` ago.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn is_stale(&self, key: &CacheKey, stale_time: std::time::Duration) -> bool {
+        match self.fetched_at.borrow().peek(key) {
+            Some(m) => js_sys::Date::now() - m > stale_time.as_secs_f64() * 1000.0,
+            None => true,
+        }
+    }
+
+    /// Re-renders the mounted components that display `key`.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn notify(&self, key: &CacheKey) {
+        let listeners: Vec<_> = match self.listeners.borrow().get(key) {
+            Some(m) => m.iter().map(|(_, cb)| cb.clone()).collect(),
+            None => return,
+        };
+        for cb in listeners {
+            cb.emit(());
+        }
+    }
+
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn subscribe(&self, key: CacheKey, cb: Callback<()>) -> usize {
+        thread_local! {
+            static NEXT_ID: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+        }
+        let id = NEXT_ID.with(|m| m.replace(m.get() + 1));
+        self.listeners
+            .borrow_mut()
+            .entry(key)
+            .or_default()
+            .push((id, cb));
+        id
+    }
 
-        match link_resp.ok {
-            Some(val) => {
-                serde_json::from_value(val).map_err(|e| LinkError::Internal(e.to_string()))
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn unsubscribe(&self, key: &CacheKey, id: usize) {
+        let mut listeners = self.listeners.borrow_mut();
+        if let Some(m) = listeners.get_mut(key) {
+            m.retain(|(i, _)| *i != id);
+            if m.is_empty() {
+                listeners.remove(key);
             }
-            None => match link_resp.error {
-                Some(err_val) => {
-                    let e: T::Error = serde_json::from_value(err_val)
-                        .map_err(|e| LinkError::Internal(e.to_string()))?;
-                    Err(LinkError::Resolve(e))
+        }
+    }
+
+    /// Re-fetches `key` in the background, keeping the stale value visible.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn revalidate<T: LinkedState>(&self, key: CacheKey, options: &LinkOptions) {
+        let Some(input) = key.input.downcast_ref::<T::Input>().cloned() else {
+            return;
+        };
+        if !self.refreshing.borrow_mut().insert(key.clone()) {
+            return;
+        }
+        self.notify(&key);
+
+        let link_ctx = self.clone();
+        let options = options.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let result: Result<T, LinkError<T::Error>> =
+                link_ctx.fetch_remote::<T>(&input, &options).await;
+
+            link_ctx.refreshing.borrow_mut().remove(&key);
+            // Failed attempts also count as fetches, so stale entries aren't re-fetched on every
+            // render while the endpoint is unreachable.
+            link_ctx
+                .fetched_at
+                .borrow_mut()
+                .put(key.clone(), js_sys::Date::now());
+            link_ctx.store(key.clone(), &result);
+            link_ctx.notify(&key);
+        });
+    }
+
+    /// Fetches `input` into the cache. Components waiting for `key` share the request through
+    /// the returned suspension.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn fetch<T: LinkedState>(
+        &self,
+        key: CacheKey,
+        input: T::Input,
+        options: &LinkOptions,
+    ) -> Suspension {
+        let sus = Suspension::from_future({
+            let link_ctx = self.clone();
+            let key = key.clone();
+            let options = options.clone();
+            async move {
+                let result: Result<T, LinkError<T::Error>> =
+                    link_ctx.fetch_remote::<T>(&input, &options).await;
+
+                link_ctx.in_flight.borrow_mut().remove(&key);
+                link_ctx.store(key, &result);
+            }
+        });
+
+        self.in_flight.borrow_mut().insert(key, sus.clone());
+        sus
+    }
+
+    /// Returns the cached or displayed keys of `T` whose input matches `predicate`.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn keys_of<T: LinkedState>(&self, predicate: impl Fn(&T::Input) -> bool) -> Vec<CacheKey> {
+        let matches = |key: &CacheKey| {
+            key.type_id == TypeId::of::<T>()
+                && key.input.downcast_ref::<T::Input>().is_some_and(&predicate)
+        };
+
+        let mut keys: HashSet<CacheKey> = self
+            .cache
+            .borrow()
+            .iter()
+            .map(|(key, _)| key)
+            .filter(|key| matches(key))
+            .cloned()
+            .collect();
+        keys.extend(
+            self.listeners
+                .borrow()
+                .keys()
+                .filter(|key| matches(key))
+                .cloned(),
+        );
+        keys.into_iter().collect()
+    }
+
+    /// Streams the updates of `key` into the cache until the matching
+    /// [`unsubscribe_live`](Self::unsubscribe_live).
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn subscribe_live<T: LinkedState>(&self, key: CacheKey, input: &T::Input) {
+        use wasm_bindgen::prelude::*;
+
+        if let Some(m) = self.live.borrow_mut().get_mut(&key) {
+            m.count += 1;
+            return;
+        }
+
+        let Ok(input) = serde_json::to_string(input) else {
+            return;
+        };
+        let separator = if self.endpoint.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        let url = format!(
+            "{}{separator}type_key={}&input={}",
+            self.endpoint,
+            js_sys::encode_uri_component(T::TYPE_KEY),
+            js_sys::encode_uri_component(&input),
+        );
+        let Ok(source) = web_sys::EventSource::new(&url) else {
+            return;
+        };
+
+        let on_message = {
+            let link_ctx = self.clone();
+            let key = key.clone();
+            Closure::<dyn FnMut(web_sys::MessageEvent)>::new(move |e: web_sys::MessageEvent| {
+                let Some(data) = e.data().as_string() else {
+                    return;
+                };
+                if let Ok(val) = serde_json::from_str::<T>(&data) {
+                    link_ctx.store::<T>(key.clone(), &Ok(val));
+                    link_ctx.notify(&key);
                 }
-                None => Err(LinkError::Internal("unknown error".into())),
+            })
+        };
+        source.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+
+        self.live.borrow_mut().insert(
+            key,
+            LiveSubscription {
+                count: 1,
+                source,
+                _on_message: on_message,
             },
+        );
+    }
+
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn unsubscribe_live(&self, key: &CacheKey) {
+        let mut live = self.live.borrow_mut();
+        if let Some(m) = live.get_mut(key) {
+            m.count -= 1;
+            if m.count == 0 {
+                live.remove(key);
+            }
+        }
+    }
+
+    /// Marks `key` as stale: displayed entries are re-fetched, others are dropped. Its persisted
+    /// value is dropped either way.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    fn invalidate<T: LinkedState>(&self, key: CacheKey) {
+        if let (Some(persisted), Some(_)) = (&self.persisted, T::persist_version()) {
+            if let Some(input) = key
+                .input
+                .downcast_ref::<T::Input>()
+                .and_then(|m| serde_json::to_value(m).ok())
+            {
+                persisted.remove(T::TYPE_KEY, &input);
+            }
+        }
+
+        if self.listeners.borrow().contains_key(&key) {
+            self.revalidate::<T>(key, &T::options());
+        } else {
+            self.cache.borrow_mut().pop(&key);
         }
     }
 
@@ -394,16 +1262,24 @@ impl LinkContextInner {
             .expect("resolver not set on server-side LinkProvider");
         let req = LinkRequest {
             type_key: T::TYPE_KEY.to_string(),
-            input: serde_json::to_value(input).map_err(|e| LinkError::Internal(e.to_string()))?,
+            input: serde_json::to_value(input)
+                .map_err(|e| LinkError::Internal(InternalError::Codec(e.to_string())))?,
         };
-        match resolver.resolve_request(&req).await {
-            Ok(val) => serde_json::from_value(val).map_err(|e| LinkError::Internal(e.to_string())),
-            Err(err_val) => {
-                let e: T::Error = serde_json::from_value(err_val)
-                    .map_err(|e| LinkError::Internal(e.to_string()))?;
-                Err(LinkError::Resolve(e))
-            }
-        }
+
+        // Components requesting the same state while the page is rendered share its resolution.
+        let resolution = self
+            .resolved
+            .borrow_mut()
+            .entry(cache_key::<T>(input))
+            .or_insert_with(|| {
+                let resolver = Arc::clone(resolver);
+                let request = self.request.clone();
+                async move { Rc::new(resolver.resolve_request_with(&req, &request).await) }
+                    .boxed_local()
+                    .shared()
+            })
+            .clone();
+        resolution.await.to_result()
     }
 }
 
@@ -442,9 +1318,34 @@ pub struct LinkProviderProps {
     /// Server-side resolver. Ignored on wasm32 targets.
     #[prop_or_default]
     pub resolver: Option<ResolverProp>,
+    /// The request being rendered on the server, passed to the resolvers. Ignored on wasm32
+    /// targets.
+    #[prop_or_default]
+    pub request: RequestContext,
     /// Maximum number of entries in the linked-state cache. Defaults to 64.
     #[prop_or(64)]
     pub cache_capacity: usize,
+    /// Whether the requests made by the client within one tick are sent to the endpoint as a
+    /// single batch. Defaults to `true`.
+    ///
+    /// Disable it if the endpoint is served by a handler that only accepts single requests.
+    #[prop_or(true)]
+    pub batch: bool,
+    /// Sends the requests of the client. Defaults to [`HttpTransport`].
+    #[prop_or_default]
+    pub transport: Option<LinkTransportProp>,
+    /// Format of the requests sent by the client. Defaults to [`LinkCodec::Json`].
+    #[prop_or_default]
+    pub codec: LinkCodec,
+    /// Called when a linked state or action requested by the client fails, after the retries
+    /// configured in its [`LinkOptions`], e.g. to show a notification. Ignored on the server.
+    #[prop_or_default]
+    pub on_error: Option<Callback<LinkErrorEvent>>,
+    /// Persists the values of the states with a [`LinkedState::persist_version`] across page
+    /// loads, e.g. in [`LocalStorage`]. The storage is read when the provider is first rendered.
+    /// Ignored on the server.
+    #[prop_or_default]
+    pub persist: Option<LinkStorageProp>,
 }
 
 /// Provides linked-state resolution context to descendant components.
@@ -454,26 +1355,73 @@ pub struct LinkProviderProps {
 #[component]
 pub fn LinkProvider(props: &LinkProviderProps) -> Html {
     #[cfg(target_arch = "wasm32")]
-    let cache: Cache = {
-        let cap = NonZeroUsize::new(props.cache_capacity).unwrap_or(NonZeroUsize::MIN);
-        (*use_ref(|| Rc::new(RefCell::new(LruCache::new(cap))))).clone()
-    };
+    let cap = NonZeroUsize::new(props.cache_capacity).unwrap_or(NonZeroUsize::MIN);
+    #[cfg(target_arch = "wasm32")]
+    let cache: Cache = (*use_ref(|| Rc::new(RefCell::new(LruCache::new(cap))))).clone();
+    #[cfg(target_arch = "wasm32")]
+    let fetched_at: FetchedAt = (*use_ref(|| Rc::new(RefCell::new(LruCache::new(cap))))).clone();
     #[cfg(not(target_arch = "wasm32"))]
     let cache: Cache = (*use_ref(|| Rc::new(RefCell::new(HashMap::new())))).clone();
     #[cfg(target_arch = "wasm32")]
     let in_flight: InFlight = (*use_ref(|| Rc::new(RefCell::new(HashMap::new())))).clone();
     #[cfg(target_arch = "wasm32")]
     let refreshing: Refreshing = (*use_ref(|| Rc::new(RefCell::new(HashSet::new())))).clone();
+    #[cfg(target_arch = "wasm32")]
+    let listeners: Listeners = (*use_ref(|| Rc::new(RefCell::new(HashMap::new())))).clone();
+    #[cfg(target_arch = "wasm32")]
+    let transport: Rc<dyn LinkTransport> = {
+        let default = use_ref(|| Rc::new(HttpTransport::new()) as Rc<dyn LinkTransport>);
+        match &props.transport {
+            Some(m) => m.0.clone(),
+            None => (*default).clone(),
+        }
+    };
+    #[cfg(target_arch = "wasm32")]
+    let pending: Pending = (*use_ref(|| Rc::new(RefCell::new(Vec::new())))).clone();
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    let live: Live = (*use_ref(|| Rc::new(RefCell::new(HashMap::new())))).clone();
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    let persisted: Option<PersistedCache> = (*use_ref(|| {
+        props
+            .persist
+            .as_ref()
+            .map(|m| PersistedCache::new(m.0.clone(), props.cache_capacity))
+    }))
+    .clone();
+    #[cfg(feature = "ssr")]
+    let resolved: Resolved = (*use_ref(|| Rc::new(RefCell::new(HashMap::new())))).clone();
 
     let ctx = LinkContextInner {
         cache,
         #[cfg(target_arch = "wasm32")]
+        fetched_at,
+        #[cfg(target_arch = "wasm32")]
         in_flight,
         #[cfg(target_arch = "wasm32")]
         refreshing,
+        #[cfg(target_arch = "wasm32")]
+        listeners,
+        #[cfg(target_arch = "wasm32")]
+        pending,
+        #[cfg(target_arch = "wasm32")]
+        batch: props.batch,
+        #[cfg(target_arch = "wasm32")]
+        transport,
+        #[cfg(target_arch = "wasm32")]
+        codec: props.codec,
+        #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+        live,
+        #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+        on_error: props.on_error.clone(),
+        #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+        persisted,
         endpoint: props.endpoint.clone(),
         #[cfg(feature = "ssr")]
         resolver: props.resolver.as_ref().map(|r| Arc::clone(&r.0)),
+        #[cfg(feature = "ssr")]
+        request: props.request.clone(),
+        #[cfg(feature = "ssr")]
+        resolved,
     };
 
     html! {
@@ -507,6 +1455,25 @@ pub fn LinkProvider(props: &LinkProviderProps) -> Html {
 /// Panics if there is no ancestor [`LinkProvider`] in the component tree.
 #[hook]
 pub fn use_linked_state<T: LinkedState>(input: T::Input) -> SuspensionResult<LinkedStateHandle<T>> {
+    use_linked_state_with::<T>(input, T::options())
+}
+
+/// Fetch a [`LinkedState`] value with the given [`LinkOptions`] instead of
+/// [`LinkedState::options`].
+///
+/// See [`use_linked_state`].
+///
+/// # Panics
+///
+/// Panics if there is no ancestor [`LinkProvider`] in the component tree.
+#[hook]
+pub fn use_linked_state_with<T: LinkedState>(
+    input: T::Input,
+    options: LinkOptions,
+) -> SuspensionResult<LinkedStateHandle<T>> {
+    #[cfg(not(all(not(feature = "ssr"), target_arch = "wasm32")))]
+    let _ = options;
+
     #[cfg(any(feature = "ssr", target_arch = "wasm32"))]
     let link_ctx =
         use_context::<LinkContextInner>().expect("use_linked_state requires a LinkProvider");
@@ -543,9 +1510,7 @@ pub fn use_linked_state<T: LinkedState>(input: T::Input) -> SuspensionResult<Lin
     {
         let _ = input;
         Ok(LinkedStateHandle {
-            result: Err(LinkError::Internal(
-                "yew-link requires the `ssr` feature (server) or a wasm32 target (client)".into(),
-            )),
+            result: Err(LinkError::Internal(InternalError::Unsupported)),
             refresh: Callback::from(|_: ()| {}),
             refreshing: false,
         })
@@ -563,69 +1528,40 @@ pub fn use_linked_state<T: LinkedState>(input: T::Input) -> SuspensionResult<Lin
                 let mut cache = link_ctx.cache.borrow_mut();
                 if cache.peek(&key).is_none() {
                     cache.put(key.clone(), json_val);
+                    link_ctx
+                        .fetched_at
+                        .borrow_mut()
+                        .put(key.clone(), js_sys::Date::now());
                 }
             }
         }
 
-        let force_update = use_force_update();
-        let has_refreshed = use_ref(|| std::cell::Cell::new(false));
-
-        let refresh = {
-            let cache = link_ctx.cache.clone();
-            let refreshing = link_ctx.refreshing.clone();
-            let key = key.clone();
+        {
+            let force_update = use_force_update();
             let link_ctx = link_ctx.clone();
-            let input = input.clone();
-            let force_update = force_update.clone();
-            let has_refreshed = has_refreshed.clone();
-            Callback::from(move |()| {
-                refreshing.borrow_mut().insert(key.clone());
-                has_refreshed.set(true);
-
-                let link_ctx = link_ctx.clone();
+            use_effect_with(key.clone(), move |key| {
+                let id = link_ctx.subscribe(
+                    key.clone(),
+                    Callback::from(move |()| force_update.force_update()),
+                );
                 let key = key.clone();
-                let cache = cache.clone();
-                let refreshing = refreshing.clone();
-                let inner_force_update = force_update.clone();
-                let input = input.clone();
-                wasm_bindgen_futures::spawn_local(async move {
-                    let result: Result<T, LinkError<T::Error>> =
-                        link_ctx.fetch_remote::<T>(&input).await;
-
-                    refreshing.borrow_mut().remove(&key);
-
-                    let should_cache = match &result {
-                        Ok(_) | Err(LinkError::Resolve(_)) => true,
-                        Err(LinkError::Internal(_)) => false,
-                    };
-                    if should_cache {
-                        if let Ok(json_val) = serde_json::to_value(&result) {
-                            cache.borrow_mut().put(key, json_val);
-                        }
-                    }
+                move || link_ctx.unsubscribe(&key, id)
+            });
+        }
 
-                    inner_force_update.force_update();
-                });
+        options::use_refetch::<T>(link_ctx.clone(), key.clone(), options.clone());
 
-                force_update.force_update();
-            })
+        let refresh = {
+            let link_ctx = link_ctx.clone();
+            let key = key.clone();
+            let options = options.clone();
+            Callback::from(move |()| link_ctx.revalidate::<T>(key.clone(), &options))
         };
 
-        if !has_refreshed.get() {
-            if let Some(result) = prepared {
-                return Ok(LinkedStateHandle {
-                    result: match result.as_ref() {
-                        Ok(val) => Ok(Rc::new(val.clone())),
-                        Err(e) => Err(e.clone()),
-                    },
-                    refresh,
-                    refreshing: false,
-                });
-            }
-        }
-
         let is_refreshing = link_ctx.refreshing.borrow().contains(&key);
 
+        link_ctx.rehydrate::<T>(&key)?;
+
         if let Some(cached_val) = link_ctx.cache.borrow_mut().get(&key).cloned() {
             if let Ok(result) = serde_json::from_value::<Prepared<T, T::Error>>(cached_val) {
                 return Ok(LinkedStateHandle {
@@ -651,34 +1587,219 @@ pub fn use_linked_state<T: LinkedState>(input: T::Input) -> SuspensionResult<Lin
             }
         }
 
-        let sus = Suspension::from_future({
-            let link_ctx = link_ctx.clone();
-            let key = key.clone();
+        Err(link_ctx.fetch::<T>(key, input, &options))
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), any(feature = "axum", feature = "actix")))]
+mod services;
+
+#[cfg(all(not(target_arch = "wasm32"), any(feature = "axum", feature = "actix")))]
+pub use services::*;
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Clone, Serialize, serde::Deserialize)]
+    struct Doubled(u32);
+
+    impl LinkedState for Doubled {
+        type Input = u32;
+        type Error = String;
+
+        const TYPE_KEY: &'static str = "Doubled";
+
+        fn error_status(_error: &String) -> u16 {
+            404
+        }
+    }
+
+    #[derive(Clone, Serialize, serde::Deserialize)]
+    struct Panics;
+
+    impl LinkedState for Panics {
+        type Input = ();
+        type Error = Never;
+
+        const TYPE_KEY: &'static str = "Panics";
+    }
+
+    async fn resolve_panics((): ()) -> Result<Panics, Never> {
+        panic!("the resolver panicked")
+    }
+
+    fn resolver() -> Resolver {
+        Resolver::new().register::<Doubled, _, _>(|input| async move {
+            // Later requests are resolved first.
+            tokio::time::sleep(Duration::from_millis(10u64.saturating_sub(input.into()))).await;
+            match input {
+                0 => Err("zero".to_owned()),
+                n => Ok(Doubled(n * 2)),
+            }
+        })
+    }
+
+    fn request(type_key: &str, input: serde_json::Value) -> LinkRequest {
+        LinkRequest {
+            type_key: type_key.to_owned(),
+            input,
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_responses_are_in_request_order() {
+        let reqs: Vec<_> = (1..=3).map(|m| request("Doubled", json!(m))).collect();
+
+        let resps = resolver()
+            .resolve_batch(&reqs, &RequestContext::default())
+            .await;
+
+        let values: Vec<_> = resps.into_iter().map(|m| m.ok).collect();
+        assert_eq!(values, [Some(json!(2)), Some(json!(4)), Some(json!(6))]);
+    }
+
+    #[tokio::test]
+    async fn response_status() {
+        let resolver = resolver();
+        let status = |req| {
+            let resolver = &resolver;
             async move {
-                let result: Result<T, LinkError<T::Error>> =
-                    link_ctx.fetch_remote::<T>(&input).await;
+                resolver
+                    .resolve_request_with(&req, &RequestContext::default())
+                    .await
+                    .status()
+            }
+        };
 
-                link_ctx.in_flight.borrow_mut().remove(&key);
+        assert_eq!(status(request("Doubled", json!(1))).await, 200);
+        // The status chosen by `LinkedState::error_status`.
+        assert_eq!(status(request("Doubled", json!(0))).await, 404);
+        assert_eq!(status(request("Doubled", json!("one"))).await, 400);
+        assert_eq!(status(request("Missing", json!(1))).await, 404);
+    }
 
-                let should_cache = match &result {
-                    Ok(_) | Err(LinkError::Resolve(_)) => true,
-                    Err(LinkError::Internal(_)) => false,
-                };
-                if should_cache {
-                    if let Ok(json_val) = serde_json::to_value(&result) {
-                        link_ctx.cache.borrow_mut().put(key, json_val);
-                    }
+    #[test]
+    fn reply_status() {
+        let single = LinkReply::Single(LinkResponse::internal(InternalError::InvalidInput(
+            "expected u32".to_owned(),
+        )));
+        assert_eq!(single.status(), 400);
+
+        let single = LinkReply::Single(LinkResponse::error(json!("forbidden"), 403));
+        assert_eq!(single.status(), 403);
+
+        // Batched responses fail independently.
+        let batch = LinkReply::Batch(vec![
+            LinkResponse::ok(json!(1)),
+            LinkResponse::internal(InternalError::Panic),
+        ]);
+        assert_eq!(batch.status(), 200);
+
+        // The status chosen on the server is not sent to the client.
+        let resp: LinkResponse = serde_json::from_value(json!({ "error": "forbidden" })).unwrap();
+        assert_eq!(resp.status(), 422);
+    }
+
+    #[tokio::test]
+    async fn internal_errors() {
+        let resolver = resolver().register::<Panics, _, _>(resolve_panics);
+        let internal = |req| {
+            let resolver = &resolver;
+            async move {
+                resolver
+                    .resolve_request_with(&req, &RequestContext::default())
+                    .await
+                    .internal
+            }
+        };
+
+        assert_eq!(internal(request("Doubled", json!(1))).await, None);
+        assert_eq!(internal(request("Doubled", json!(0))).await, None);
+        assert!(matches!(
+            internal(request("Doubled", json!("one"))).await,
+            Some(InternalError::InvalidInput(_))
+        ));
+        assert_eq!(
+            internal(request("Missing", json!(1))).await,
+            Some(InternalError::NoResolver("Missing".to_owned()))
+        );
+        assert_eq!(
+            internal(request("Panics", json!(null))).await,
+            Some(InternalError::Panic)
+        );
+        assert_eq!(InternalError::Panic.status(), 500);
+    }
+
+    #[cfg(feature = "ssr")]
+    #[tokio::test]
+    async fn rendering_shares_the_resolutions_of_a_state() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let resolutions = Arc::new(AtomicU32::new(0));
+        let resolver = Resolver::new().register::<Doubled, _, _>({
+            let resolutions = resolutions.clone();
+            move |input| {
+                resolutions.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    tokio::time::sleep(Duration::from_millis(1)).await;
+                    Ok(Doubled(input * 2))
                 }
             }
         });
+        let ctx = LinkContextInner {
+            cache: Rc::default(),
+            endpoint: AttrValue::default(),
+            resolver: Some(Arc::new(resolver)),
+            request: RequestContext::default(),
+            resolved: Rc::default(),
+        };
 
-        link_ctx.in_flight.borrow_mut().insert(key, sus.clone());
-        Err(sus)
+        let (first, second, other) = futures::join!(
+            ctx.resolve_local::<Doubled>(&1),
+            ctx.resolve_local::<Doubled>(&1),
+            ctx.resolve_local::<Doubled>(&2),
+        );
+        assert_eq!(first.map(|m| m.0), Ok(2));
+        assert_eq!(second.map(|m| m.0), Ok(2));
+        assert_eq!(other.map(|m| m.0), Ok(4));
+        assert_eq!(resolutions.load(Ordering::SeqCst), 2);
+
+        // Later components of the same render reuse the resolution.
+        assert_eq!(ctx.resolve_local::<Doubled>(&1).await.map(|m| m.0), Ok(2));
+        assert_eq!(resolutions.load(Ordering::SeqCst), 2);
     }
-}
 
-#[cfg(all(not(target_arch = "wasm32"), any(feature = "axum", feature = "actix")))]
-mod services;
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn responses_decode_into_link_errors() {
+        assert_eq!(LinkResponse::ok(json!(2)).to_result::<u32, String>(), Ok(2));
+        assert_eq!(
+            LinkResponse::error(json!("zero"), 404).to_result::<u32, String>(),
+            Err(LinkError::Resolve("zero".to_owned()))
+        );
+        assert_eq!(
+            LinkResponse::internal(InternalError::NoResolver("Doubled".to_owned()))
+                .to_result::<u32, String>(),
+            Err(LinkError::Internal(InternalError::NoResolver(
+                "Doubled".to_owned()
+            )))
+        );
 
-#[cfg(all(not(target_arch = "wasm32"), any(feature = "axum", feature = "actix")))]
-pub use services::*;
+        // The internal error is sent to the client.
+        let resp: LinkResponse = serde_json::from_value(json!({ "internal": "Panic" })).unwrap();
+        assert_eq!(
+            resp.to_result::<u32, String>(),
+            Err(LinkError::Internal(InternalError::Panic))
+        );
+
+        assert!(matches!(
+            LinkResponse::ok(json!("two")).to_result::<u32, String>(),
+            Err(LinkError::Internal(InternalError::Codec(_)))
+        ));
+    }
+}
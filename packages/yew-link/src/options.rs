@@ -0,0 +1,180 @@
+use std::time::Duration;
+
+/// Options controlling when a [`LinkedState`](crate::LinkedState) is re-fetched.
+///
+/// Set them for every use of a type with [`LinkedState::options`](crate::LinkedState::options),
+/// or for a single use with [`use_linked_state_with`](crate::use_linked_state_with). All
+/// re-fetches happen in the background like [`refresh`](crate::LinkedStateHandle::refresh):
+/// the previous value stays visible until the fresh one arrives.
+///
+/// On the server the options are ignored.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use yew_link::LinkOptions;
+///
+/// let options = LinkOptions {
+///     stale_time: Some(Duration::from_secs(30)),
+///     refetch_on_focus: true,
+///     retry: 3,
+///     ..LinkOptions::default()
+/// };
+/// # let _ = options;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkOptions {
+    /// How long a fetched value stays fresh. A component displaying a stale value re-fetches it
+    /// when it is rendered.
+    ///
+    /// Defaults to `None`: values never become stale over time.
+    pub stale_time: Option<Duration>,
+    /// Re-fetches the value at this interval while a component displays it.
+    ///
+    /// Defaults to `None`.
+    pub refetch_interval: Option<Duration>,
+    /// Re-fetches the displayed value when the window regains focus, unless it is still fresh.
+    ///
+    /// Defaults to `false`.
+    pub refetch_on_focus: bool,
+    /// Re-fetches the displayed value when the browser reconnects to the network, unless it is
+    /// still fresh.
+    ///
+    /// Defaults to `false`.
+    pub refetch_on_reconnect: bool,
+    /// How many times a fetch failing to reach the endpoint, with
+    /// [`InternalError::Network`](crate::InternalError::Network), is retried.
+    ///
+    /// Defaults to `0`.
+    pub retry: u32,
+    /// The delay before the first retry. It doubles with every further retry.
+    ///
+    /// Defaults to 1 second.
+    pub retry_delay: Duration,
+}
+
+impl Default for LinkOptions {
+    fn default() -> Self {
+        Self {
+            stale_time: None,
+            refetch_interval: None,
+            refetch_on_focus: false,
+            refetch_on_reconnect: false,
+            retry: 0,
+            retry_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+impl LinkOptions {
+    /// The delay before the retry following `attempt` failed attempts.
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    pub(crate) fn retry_delay(&self, attempt: u32) -> Duration {
+        self.retry_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+    }
+}
+
+#[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+mod feat_client {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use wasm_bindgen::prelude::*;
+    use yew::prelude::*;
+
+    use super::LinkOptions;
+    use crate::{CacheKey, LinkContextInner, LinkedState};
+
+    /// Re-fetches `key` when it becomes stale, at the refetch interval, and on focus and
+    /// reconnection, as configured by `options`.
+    #[hook]
+    pub(crate) fn use_refetch<T: LinkedState>(
+        link_ctx: LinkContextInner,
+        key: CacheKey,
+        options: LinkOptions,
+    ) {
+        {
+            let link_ctx = link_ctx.clone();
+            let key = key.clone();
+            let options = options.clone();
+            use_effect(move || {
+                // Values read from the persisted cache are displayed until they are re-fetched.
+                if link_ctx.take_rehydrated(&key) {
+                    link_ctx.revalidate::<T>(key, &options);
+                } else if let Some(stale_time) = options.stale_time {
+                    if link_ctx.cache.borrow().contains(&key) && link_ctx.is_stale(&key, stale_time)
+                    {
+                        link_ctx.revalidate::<T>(key, &options);
+                    }
+                }
+            });
+        }
+
+        {
+            let link_ctx = link_ctx.clone();
+            use_effect_with((key.clone(), options.clone()), move |(key, options)| {
+                let cancelled = Rc::new(Cell::new(false));
+                if let Some(interval) = options.refetch_interval {
+                    let cancelled = cancelled.clone();
+                    let key = key.clone();
+                    let options = options.clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        loop {
+                            yew::platform::time::sleep(interval).await;
+                            if cancelled.get() {
+                                break;
+                            }
+                            link_ctx.revalidate::<T>(key.clone(), &options);
+                        }
+                    });
+                }
+
+                move || cancelled.set(true)
+            });
+        }
+
+        use_effect_with((key, options), move |(key, options)| {
+            let events = [
+                ("focus", options.refetch_on_focus),
+                ("online", options.refetch_on_reconnect),
+            ];
+            let window = web_sys::window().expect("failed to obtain window");
+
+            let listeners: Vec<_> = events
+                .into_iter()
+                .filter(|(_, enabled)| *enabled)
+                .map(|(event, _)| {
+                    let link_ctx = link_ctx.clone();
+                    let key = key.clone();
+                    let options = options.clone();
+                    let listener = Closure::<dyn Fn()>::new(move || {
+                        let fresh = options
+                            .stale_time
+                            .is_some_and(|m| !link_ctx.is_stale(&key, m));
+                        if !fresh {
+                            link_ctx.revalidate::<T>(key.clone(), &options);
+                        }
+                    });
+                    let _ = window
+                        .add_event_listener_with_callback(event, listener.as_ref().unchecked_ref());
+                    (event, listener)
+                })
+                .collect();
+
+            move || {
+                for (event, listener) in listeners {
+                    let _ = window.remove_event_listener_with_callback(
+                        event,
+                        listener.as_ref().unchecked_ref(),
+                    );
+                }
+            }
+        });
+    }
+}
+
+#[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+pub(crate) use feat_client::use_refetch;
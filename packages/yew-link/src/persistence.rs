@@ -0,0 +1,277 @@
+use std::fmt;
+use std::rc::Rc;
+
+use futures::future::LocalBoxFuture;
+
+/// Stores the persisted linked-state cache of the client.
+///
+/// Pass an implementation to the `persist` prop of [`LinkProvider`](crate::LinkProvider) to keep
+/// the values of the types with a [`LinkedState::persist_version`](crate::LinkedState::persist_version)
+/// across page loads. They are displayed on the next start, even offline, and re-fetched in the
+/// background.
+///
+/// [`LocalStorage`] stores the cache in `localStorage`. Implement this trait to store it
+/// elsewhere, e.g. in IndexedDB.
+pub trait LinkStorage {
+    /// Loads the data saved by [`save`](Self::save), if any.
+    fn load(&self) -> LocalBoxFuture<'static, Option<String>>;
+
+    /// Saves `data`, replacing the previous data.
+    fn save(&self, data: String) -> LocalBoxFuture<'static, ()>;
+}
+
+/// Stores the cache in `localStorage` under a key.
+#[derive(Debug, Clone)]
+pub struct LocalStorage {
+    key: String,
+}
+
+impl LocalStorage {
+    /// Creates a storage saving the cache under `key`.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into() }
+    }
+}
+
+impl LinkStorage for LocalStorage {
+    fn load(&self) -> LocalBoxFuture<'static, Option<String>> {
+        #[cfg(target_arch = "wasm32")]
+        let data = web_sys::window()
+            .and_then(|m| m.local_storage().ok().flatten())
+            .and_then(|m| m.get_item(&self.key).ok().flatten());
+        #[cfg(not(target_arch = "wasm32"))]
+        let data = {
+            let _ = &self.key;
+            None
+        };
+
+        Box::pin(async move { data })
+    }
+
+    fn save(&self, data: String) -> LocalBoxFuture<'static, ()> {
+        #[cfg(target_arch = "wasm32")]
+        if let Some(storage) = web_sys::window().and_then(|m| m.local_storage().ok().flatten()) {
+            // Fails if the storage is full, in which case the cache isn't persisted.
+            let _ = storage.set_item(&self.key, &data);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = data;
+
+        Box::pin(async {})
+    }
+}
+
+/// Wrapper so a [`LinkStorage`] can be passed as a component prop.
+#[derive(Clone)]
+pub struct LinkStorageProp(pub Rc<dyn LinkStorage>);
+
+impl fmt::Debug for LinkStorageProp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LinkStorageProp").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for LinkStorageProp {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T: LinkStorage + 'static> From<T> for LinkStorageProp {
+    fn from(storage: T) -> Self {
+        Self(Rc::new(storage))
+    }
+}
+
+#[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+mod feat_client {
+    use std::cell::{Cell, RefCell};
+    use std::collections::{HashMap, HashSet};
+    use std::rc::Rc;
+
+    use serde::{Deserialize, Serialize};
+    use yew::suspense::Suspension;
+
+    use super::LinkStorage;
+    use crate::CacheKey;
+
+    /// A persisted value, with the version of its type when it was saved.
+    #[derive(Serialize, Deserialize)]
+    struct Entry {
+        type_key: String,
+        input: serde_json::Value,
+        version: u32,
+        value: serde_json::Value,
+        /// When the value was fetched, in milliseconds since the epoch.
+        fetched_at: f64,
+    }
+
+    /// The persisted cache, loaded from a [`LinkStorage`] on startup and saved after every
+    /// change.
+    ///
+    /// Entries are kept until they are replaced, even if their type hasn't been used since the
+    /// start, so they aren't lost when the cache is saved.
+    #[derive(Clone)]
+    pub(crate) struct PersistedCache {
+        storage: Rc<dyn LinkStorage>,
+        capacity: usize,
+        entries: Rc<RefCell<HashMap<(String, String), Entry>>>,
+        loading: Suspension,
+        /// The cached keys whose value has been read from the storage and not re-fetched yet.
+        rehydrated: Rc<RefCell<HashSet<CacheKey>>>,
+        save_scheduled: Rc<Cell<bool>>,
+    }
+
+    impl PersistedCache {
+        pub(crate) fn new(storage: Rc<dyn LinkStorage>, capacity: usize) -> Self {
+            let entries: Rc<RefCell<HashMap<_, Entry>>> = Rc::default();
+            let loading = Suspension::from_future({
+                let storage = storage.clone();
+                let entries = entries.clone();
+                async move {
+                    let loaded: Vec<Entry> = match storage.load().await {
+                        Some(data) => serde_json::from_str(&data).unwrap_or_default(),
+                        None => Vec::new(),
+                    };
+                    entries.borrow_mut().extend(
+                        loaded
+                            .into_iter()
+                            .map(|m| ((m.type_key.clone(), m.input.to_string()), m)),
+                    );
+                }
+            });
+
+            Self {
+                storage,
+                capacity,
+                entries,
+                loading,
+                rehydrated: Rc::default(),
+                save_scheduled: Rc::default(),
+            }
+        }
+
+        /// Returns the value persisted for `input` with `version`, and when it was fetched.
+        ///
+        /// Suspends until the cache is loaded. Values persisted with another version are
+        /// dropped.
+        pub(crate) fn get(
+            &self,
+            type_key: &str,
+            input: &serde_json::Value,
+            version: u32,
+        ) -> Result<Option<(serde_json::Value, f64)>, Suspension> {
+            if !self.loading.resumed() {
+                return Err(self.loading.clone());
+            }
+
+            let key = (type_key.to_string(), input.to_string());
+            match self.entries.borrow().get(&key) {
+                Some(m) if m.version == version => {
+                    return Ok(Some((m.value.clone(), m.fetched_at)));
+                }
+                Some(_) => {}
+                None => return Ok(None),
+            }
+
+            self.entries.borrow_mut().remove(&key);
+            self.schedule_save();
+            Ok(None)
+        }
+
+        /// Persists `value`, dropping the oldest values beyond the capacity of the cache.
+        pub(crate) fn put(
+            &self,
+            type_key: &str,
+            input: serde_json::Value,
+            version: u32,
+            value: serde_json::Value,
+            fetched_at: f64,
+        ) {
+            let mut entries = self.entries.borrow_mut();
+            entries.insert(
+                (type_key.to_string(), input.to_string()),
+                Entry {
+                    type_key: type_key.to_string(),
+                    input,
+                    version,
+                    value,
+                    fetched_at,
+                },
+            );
+            while entries.len() > self.capacity {
+                let Some(oldest) = entries
+                    .iter()
+                    .min_by(|(_, a), (_, b)| a.fetched_at.total_cmp(&b.fetched_at))
+                    .map(|(key, _)| key.clone())
+                else {
+                    break;
+                };
+                entries.remove(&oldest);
+            }
+            drop(entries);
+            self.schedule_save();
+        }
+
+        /// Drops the value persisted for `input`.
+        pub(crate) fn remove(&self, type_key: &str, input: &serde_json::Value) {
+            self.entries
+                .borrow_mut()
+                .remove(&(type_key.to_string(), input.to_string()));
+            self.schedule_save();
+        }
+
+        /// Drops the values of `type_key` whose input matches `predicate`.
+        pub(crate) fn remove_where(
+            &self,
+            type_key: &str,
+            predicate: impl Fn(&serde_json::Value) -> bool,
+        ) {
+            self.entries
+                .borrow_mut()
+                .retain(|_, m| m.type_key != type_key || !predicate(&m.input));
+            self.schedule_save();
+        }
+
+        pub(crate) fn mark_rehydrated(&self, key: CacheKey) {
+            self.rehydrated.borrow_mut().insert(key);
+        }
+
+        /// Returns whether `key` was read from the storage and hasn't been re-fetched yet.
+        pub(crate) fn take_rehydrated(&self, key: &CacheKey) -> bool {
+            self.rehydrated.borrow_mut().remove(key)
+        }
+
+        /// Saves the cache once the changes made in the current tick have been collected.
+        fn schedule_save(&self) {
+            if self.save_scheduled.replace(true) {
+                return;
+            }
+
+            let this = self.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                // Saving before the cache is loaded would erase the values that aren't loaded
+                // yet.
+                this.loading.clone().await;
+                this.save_scheduled.set(false);
+
+                let data = {
+                    let entries = this.entries.borrow();
+                    serde_json::to_string(&entries.values().collect::<Vec<_>>())
+                };
+                if let Ok(data) = data {
+                    this.storage.save(data).await;
+                }
+            });
+        }
+    }
+
+    impl PartialEq for PersistedCache {
+        fn eq(&self, other: &Self) -> bool {
+            Rc::ptr_eq(&self.entries, &other.entries)
+        }
+    }
+}
+
+#[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+pub(crate) use feat_client::PersistedCache;
@@ -0,0 +1,165 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Data of the request being resolved, such as its headers or the authenticated user.
+///
+/// The bundled handlers create it from the headers of the incoming request, on top of a
+/// `RequestContext` inserted in the request extensions by a middleware, if any. During
+/// server-side rendering, it is passed to the `request` prop of
+/// [`LinkProvider`](crate::LinkProvider).
+///
+/// Resolvers receive it through [`Resolver::register_with_request`](crate::Resolver::register_with_request)
+/// or [`LinkedStateResolve::resolve_with_request`](crate::LinkedStateResolve::resolve_with_request).
+///
+/// Cloning is cheap.
+///
+/// # Example
+///
+/// ```
+/// use yew_link::RequestContext;
+///
+/// struct User {
+///     name: String,
+/// }
+///
+/// let req = RequestContext::new()
+///     .with_header("Cookie", "session=abc; theme=dark")
+///     .with(User {
+///         name: "Ferris".into(),
+///     });
+///
+/// assert_eq!(req.cookie("session"), Some("abc"));
+/// assert_eq!(req.get::<User>().unwrap().name, "Ferris");
+/// ```
+#[derive(Clone, Default)]
+pub struct RequestContext {
+    inner: Arc<Inner>,
+}
+
+#[derive(Clone, Default)]
+struct Inner {
+    /// Header names are lowercase.
+    headers: Vec<(String, String)>,
+    extensions: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl RequestContext {
+    /// Creates an empty context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a header. Header names are case-insensitive.
+    pub fn with_header(mut self, name: impl AsRef<str>, value: impl Into<String>) -> Self {
+        Arc::make_mut(&mut self.inner)
+            .headers
+            .push((name.as_ref().to_ascii_lowercase(), value.into()));
+        self
+    }
+
+    /// Adds a value, replacing the previous value of the same type.
+    pub fn with<T: Send + Sync + 'static>(mut self, value: T) -> Self {
+        Arc::make_mut(&mut self.inner)
+            .extensions
+            .insert(TypeId::of::<T>(), Arc::new(value));
+        self
+    }
+
+    /// Returns the first value of the header `name`.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers(name).next()
+    }
+
+    /// Returns all the values of the header `name`.
+    pub fn headers<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.inner
+            .headers
+            .iter()
+            .filter(move |(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Returns the value of the cookie `name`.
+    pub fn cookie(&self, name: &str) -> Option<&str> {
+        self.headers("cookie")
+            .flat_map(|m| m.split(';'))
+            .filter_map(|m| m.trim().split_once('='))
+            .find(|(n, _)| *n == name)
+            .map(|(_, v)| v)
+    }
+
+    /// Returns the value of type `T`.
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.inner
+            .extensions
+            .get(&TypeId::of::<T>())
+            .and_then(|m| m.downcast_ref())
+    }
+}
+
+impl fmt::Debug for RequestContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestContext")
+            .field(
+                "headers",
+                &self
+                    .inner
+                    .headers
+                    .iter()
+                    .map(|(n, _)| n)
+                    .collect::<Vec<_>>(),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for RequestContext {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn headers_are_case_insensitive() {
+        let req = RequestContext::new()
+            .with_header("Accept-Language", "fr")
+            .with_header("accept-language", "en");
+
+        assert_eq!(req.header("ACCEPT-LANGUAGE"), Some("fr"));
+        assert_eq!(
+            req.headers("Accept-Language").collect::<Vec<_>>(),
+            ["fr", "en"]
+        );
+        assert_eq!(req.header("Authorization"), None);
+    }
+
+    #[test]
+    fn cookies() {
+        let req = RequestContext::new()
+            .with_header("Cookie", "session=abc; theme=dark")
+            .with_header("Cookie", "lang=fr;empty=");
+
+        assert_eq!(req.cookie("session"), Some("abc"));
+        assert_eq!(req.cookie("theme"), Some("dark"));
+        assert_eq!(req.cookie("lang"), Some("fr"));
+        assert_eq!(req.cookie("empty"), Some(""));
+        assert_eq!(req.cookie("sess"), None);
+    }
+
+    #[test]
+    fn extensions_replace_values_of_the_same_type() {
+        let req = RequestContext::new().with(1u32).with("user");
+        let replaced = req.clone().with(2u32);
+
+        assert_eq!(req.get::<u32>(), Some(&1));
+        assert_eq!(replaced.get::<u32>(), Some(&2));
+        assert_eq!(replaced.get::<&str>(), Some(&"user"));
+        assert_eq!(replaced.get::<u64>(), None);
+    }
+}
@@ -0,0 +1,376 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use futures::FutureExt;
+use futures::future::{BoxFuture, Shared};
+
+use crate::{LinkRequest, LinkResponse, LinkedState, RequestContext, ResolveBoxFuture, Resolver};
+
+/// A cache of resolved linked states shared by the requests served by a [`Resolver`].
+///
+/// Only the types enabled with [`Resolver::cache`] or [`Resolver::cache_tagged`] are cached,
+/// and only their successful resolutions. Entries expire after the TTL of their type, or when
+/// they are invalidated. Concurrent requests for a value that isn't cached share its resolution.
+///
+/// Cloning is cheap and the clones share the entries, so a handle can be stored in the context
+/// of a [`LinkedAction`](crate::LinkedAction) to invalidate the states it changes.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use serde::{Deserialize, Serialize};
+/// use yew_link::{LinkedState, Never, Resolver, ServerCache};
+///
+/// #[derive(Clone, Debug, Serialize, Deserialize)]
+/// struct Post {
+///     title: String,
+/// }
+///
+/// impl LinkedState for Post {
+///     type Error = Never;
+///     type Input = u32;
+///
+///     const TYPE_KEY: &'static str = "Post";
+/// }
+///
+/// let cache = ServerCache::new();
+/// let resolver = Resolver::new()
+///     .with_cache(cache.clone())
+///     .register::<Post, _, _>(|_id| async {
+///         Ok(Post {
+///             title: String::new(),
+///         })
+///     })
+///     .cache_tagged::<Post, _>(Duration::from_secs(60), |id| vec![format!("post:{id}")]);
+///
+/// // After the post 1 has been edited:
+/// cache.invalidate_tag("post:1");
+/// # let _ = resolver;
+/// ```
+#[derive(Clone, Default)]
+pub struct ServerCache {
+    entries: Arc<Mutex<HashMap<Key, Entry>>>,
+    in_flight: Arc<Mutex<HashMap<Key, Shared<BoxFuture<'static, LinkResponse>>>>>,
+}
+
+/// The `TYPE_KEY`, the JSON-encoded input and the vary key of an entry.
+type Key = (String, String, String);
+
+struct Entry {
+    value: serde_json::Value,
+    expires_at: Instant,
+    tags: Vec<String>,
+}
+
+/// How the values of a type are cached.
+pub(crate) struct CachePolicy {
+    ttl: Duration,
+    tags: Box<dyn Fn(&serde_json::Value) -> Vec<String> + Send + Sync>,
+}
+
+/// Returns the vary key of a request, see [`Resolver::vary_cache`].
+pub(crate) type VaryFn = Box<dyn Fn(&RequestContext) -> Option<String> + Send + Sync>;
+
+impl ServerCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drops the entries tagged with `tag`.
+    pub fn invalidate_tag(&self, tag: &str) {
+        self.entries()
+            .retain(|_, entry| !entry.tags.iter().any(|m| m == tag));
+    }
+
+    /// Drops the entries of `T` for `input`, whatever their vary key.
+    pub fn invalidate<T: LinkedState>(&self, input: &T::Input) {
+        if let Ok(input) = serde_json::to_value(input) {
+            let input = input.to_string();
+            self.entries()
+                .retain(|(type_key, m, _), _| type_key != T::TYPE_KEY || *m != input);
+        }
+    }
+
+    /// Drops every entry of `T`, whatever its input.
+    pub fn invalidate_all<T: LinkedState>(&self) {
+        self.entries()
+            .retain(|(type_key, ..), _| type_key != T::TYPE_KEY);
+    }
+
+    /// Drops every entry.
+    pub fn clear(&self) {
+        self.entries().clear();
+    }
+
+    fn entries(&self) -> MutexGuard<'_, HashMap<Key, Entry>> {
+        // The entries stay consistent if a thread panics while holding the lock.
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn in_flight(&self) -> MutexGuard<'_, HashMap<Key, Shared<BoxFuture<'static, LinkResponse>>>> {
+        self.in_flight.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns the cached response to `req` for the vary key `vary`, or resolves it with
+    /// `resolve` and caches the response if it succeeded.
+    ///
+    /// Requests made while `req` is being resolved share its resolution.
+    pub(crate) async fn get_or_resolve(
+        &self,
+        req: &LinkRequest,
+        vary: String,
+        policy: &CachePolicy,
+        resolve: impl FnOnce() -> ResolveBoxFuture,
+    ) -> LinkResponse {
+        let key = (req.type_key.clone(), req.input.to_string(), vary);
+        let now = Instant::now();
+        if let Some(entry) = self.entries().get(&key) {
+            if entry.expires_at > now {
+                return LinkResponse::ok(entry.value.clone());
+            }
+        }
+
+        let resolution = self
+            .in_flight()
+            .entry(key.clone())
+            .or_insert_with(|| {
+                let cache = self.clone();
+                let fut = resolve();
+                let ttl = policy.ttl;
+                let tags = (policy.tags)(&req.input);
+                async move {
+                    let resp = fut.await;
+                    if let Some(ref value) = resp.ok {
+                        let now = Instant::now();
+                        let mut entries = cache.entries();
+                        entries.retain(|_, entry| entry.expires_at > now);
+                        entries.insert(
+                            key.clone(),
+                            Entry {
+                                value: value.clone(),
+                                expires_at: now + ttl,
+                                tags,
+                            },
+                        );
+                    }
+                    cache.in_flight().remove(&key);
+                    resp
+                }
+                .boxed()
+                .shared()
+            })
+            .clone();
+        resolution.await
+    }
+}
+
+impl fmt::Debug for ServerCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ServerCache")
+            .field("entries", &self.entries().len())
+            .finish()
+    }
+}
+
+impl Resolver {
+    /// Use `cache` to store the values of the types enabled with [`cache`](Self::cache) or
+    /// [`cache_tagged`](Self::cache_tagged), e.g. to share it between resolvers or invalidate
+    /// it from actions.
+    ///
+    /// Each resolver has its own empty cache by default.
+    pub fn with_cache(mut self, cache: ServerCache) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Returns the cache of this resolver.
+    pub fn server_cache(&self) -> &ServerCache {
+        &self.cache
+    }
+
+    /// Cache the values resolved for `T` for `ttl`, across requests.
+    ///
+    /// The cached values are shared by all the requests. Use [`vary_cache`](Self::vary_cache)
+    /// for values depending on the [`RequestContext`], e.g. on the authenticated user.
+    pub fn cache<T: LinkedState>(self, ttl: Duration) -> Self {
+        self.cache_tagged::<T, _>(ttl, |_| Vec::new())
+    }
+
+    /// Cache the values resolved for `T` for `ttl`, tagged with the tags returned by `tags` for
+    /// their input so they can be dropped with [`ServerCache::invalidate_tag`].
+    pub fn cache_tagged<T, F>(mut self, ttl: Duration, tags: F) -> Self
+    where
+        T: LinkedState,
+        F: Fn(&T::Input) -> Vec<String> + Send + Sync + 'static,
+    {
+        self.cache_policies.insert(
+            T::TYPE_KEY,
+            CachePolicy {
+                ttl,
+                tags: Box::new(move |input_json| {
+                    serde::Deserialize::deserialize(input_json)
+                        .map(|input| tags(&input))
+                        .unwrap_or_default()
+                }),
+            },
+        );
+        self
+    }
+    /// Cache the values of `T` separately for each key returned by `vary` for the request
+    /// being resolved, e.g. the session cookie. Requests for which it returns `None` are
+    /// resolved without the cache.
+    ///
+    /// Only used if `T` is cached with [`cache`](Self::cache) or
+    /// [`cache_tagged`](Self::cache_tagged).
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use serde::{Deserialize, Serialize};
+    /// # use yew_link::{LinkedState, Never, Resolver};
+    /// # #[derive(Clone, Debug, Serialize, Deserialize)]
+    /// # struct Feed;
+    /// # impl LinkedState for Feed {
+    /// #     type Error = Never;
+    /// #     type Input = ();
+    /// #     const TYPE_KEY: &'static str = "Feed";
+    /// # }
+    /// let resolver = Resolver::new()
+    ///     .register_with_request::<Feed, _, _>(|(), _request| async { Ok(Feed) })
+    ///     .cache::<Feed>(Duration::from_secs(60))
+    ///     .vary_cache::<Feed, _>(|request| request.cookie("session").map(str::to_owned));
+    /// # let _ = resolver;
+    /// ```
+    pub fn vary_cache<T, F>(mut self, vary: F) -> Self
+    where
+        T: LinkedState,
+        F: Fn(&RequestContext) -> Option<String> + Send + Sync + 'static,
+    {
+        self.cache_vary.insert(T::TYPE_KEY, Box::new(vary));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use super::*;
+    use crate::Never;
+
+    #[derive(Clone, Serialize, Deserialize)]
+    struct Counted(u32);
+
+    impl LinkedState for Counted {
+        type Input = u32;
+        type Error = Never;
+
+        const TYPE_KEY: &'static str = "Counted";
+    }
+
+    /// A resolver answering with the number of times it has resolved `Counted`.
+    fn resolver() -> Resolver {
+        let resolutions = Arc::new(AtomicU32::new(0));
+        Resolver::new().register::<Counted, _, _>(move |_| {
+            let resolutions = resolutions.clone();
+            async move {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                Ok(Counted(resolutions.fetch_add(1, Ordering::SeqCst) + 1))
+            }
+        })
+    }
+
+    fn link_request(input: u32) -> LinkRequest {
+        LinkRequest {
+            type_key: Counted::TYPE_KEY.to_owned(),
+            input: json!(input),
+        }
+    }
+
+    async fn resolve(resolver: &Resolver, input: u32, request: &RequestContext) -> u32 {
+        let resp = resolver
+            .resolve_request_with(&link_request(input), request)
+            .await;
+        serde_json::from_value(resp.ok.unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn entries_expire() {
+        let resolver = resolver().cache::<Counted>(Duration::from_millis(20));
+        let request = RequestContext::new();
+
+        assert_eq!(resolve(&resolver, 1, &request).await, 1);
+        assert_eq!(resolve(&resolver, 1, &request).await, 1);
+        assert_eq!(resolve(&resolver, 2, &request).await, 2);
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(resolve(&resolver, 1, &request).await, 3);
+    }
+
+    #[tokio::test]
+    async fn invalidation() {
+        let resolver = resolver().cache_tagged::<Counted, _>(Duration::from_secs(60), |m| {
+            vec![format!("counted:{m}"), "counted".to_owned()]
+        });
+        let cache = resolver.server_cache().clone();
+        let request = RequestContext::new();
+
+        assert_eq!(resolve(&resolver, 1, &request).await, 1);
+        assert_eq!(resolve(&resolver, 2, &request).await, 2);
+
+        cache.invalidate_tag("counted:1");
+        assert_eq!(resolve(&resolver, 1, &request).await, 3);
+        assert_eq!(resolve(&resolver, 2, &request).await, 2);
+
+        cache.invalidate::<Counted>(&2);
+        assert_eq!(resolve(&resolver, 2, &request).await, 4);
+
+        cache.invalidate_tag("counted");
+        assert_eq!(resolve(&resolver, 1, &request).await, 5);
+        assert_eq!(resolve(&resolver, 2, &request).await, 6);
+    }
+
+    #[tokio::test]
+    async fn concurrent_requests_share_the_resolution() {
+        let resolver = resolver().cache::<Counted>(Duration::from_secs(60));
+        let request = RequestContext::new();
+
+        let (first, second, other) = futures::join!(
+            resolve(&resolver, 1, &request),
+            resolve(&resolver, 1, &request),
+            resolve(&resolver, 2, &request),
+        );
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_eq!(resolve(&resolver, 3, &request).await, 3);
+    }
+
+    #[tokio::test]
+    async fn entries_vary_with_the_request() {
+        let resolver = resolver()
+            .cache::<Counted>(Duration::from_secs(60))
+            .vary_cache::<Counted, _>(|request| request.cookie("session").map(str::to_owned));
+        let alice = RequestContext::new().with_header("Cookie", "session=alice");
+        let bob = RequestContext::new().with_header("Cookie", "session=bob");
+        let anonymous = RequestContext::new();
+
+        assert_eq!(resolve(&resolver, 1, &alice).await, 1);
+        assert_eq!(resolve(&resolver, 1, &bob).await, 2);
+        assert_eq!(resolve(&resolver, 1, &alice).await, 1);
+
+        // Requests without a vary key aren't cached.
+        assert_eq!(resolve(&resolver, 1, &anonymous).await, 3);
+        assert_eq!(resolve(&resolver, 1, &anonymous).await, 4);
+
+        resolver.server_cache().invalidate::<Counted>(&1);
+        assert_eq!(resolve(&resolver, 1, &alice).await, 5);
+        assert_eq!(resolve(&resolver, 1, &bob).await, 6);
+    }
+}
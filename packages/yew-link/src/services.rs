@@ -1,15 +1,32 @@
 #[cfg(feature = "axum")]
 pub mod axum {
+    use std::convert::Infallible;
     use std::sync::Arc;
 
     use axum::Json;
-    use axum::extract::State;
-    use axum::http::StatusCode;
-    use axum::response::IntoResponse;
+    use axum::body::Bytes;
+    use axum::extract::{Query, State};
+    use axum::http::{Extensions, HeaderMap, StatusCode, header};
+    use axum::response::sse::{Event, KeepAlive, Sse};
+    use axum::response::{IntoResponse, Response};
+    use futures::StreamExt;
 
-    use crate::{LinkRequest, LinkResponse, Resolver};
+    use crate::{
+        LinkCodec, LinkPayload, LinkResponse, LinkSubscriptionQuery, RequestContext, Resolver,
+    };
 
-    /// Axum handler that resolves [`LinkRequest`]s.
+    /// Axum handler that resolves [`LinkRequest`](crate::LinkRequest)s.
+    ///
+    /// The requests of a batch are resolved concurrently. The body is decoded with the
+    /// [`LinkCodec`] matching its `Content-Type`, and the response is encoded with the same one.
+    ///
+    /// Resolvers receive the headers of the request in a [`RequestContext`], on top of the one
+    /// inserted in the request extensions by a middleware, if any.
+    ///
+    /// A single request is answered with `200 OK` if it succeeds, with the status chosen by
+    /// [`LinkedState::error_status`](crate::LinkedState::error_status) if its resolver fails,
+    /// and with `400`, `404` or `500` on an [`InternalError`](crate::InternalError). A batch is
+    /// always answered with `200 OK`.
     ///
     /// ```
     /// use std::sync::Arc;
@@ -46,35 +63,109 @@ pub mod axum {
     /// ```
     pub async fn linked_state_handler(
         State(resolver): State<Arc<Resolver>>,
-        Json(req): Json<LinkRequest>,
-    ) -> impl IntoResponse {
-        match resolver.resolve_request(&req).await {
-            Ok(val) => (
-                StatusCode::OK,
-                Json(LinkResponse {
-                    ok: Some(val),
-                    error: None,
-                }),
-            ),
-            Err(err_val) => (
-                StatusCode::UNPROCESSABLE_ENTITY,
-                Json(LinkResponse {
-                    ok: None,
-                    error: Some(err_val),
-                }),
-            ),
+        headers: HeaderMap,
+        extensions: Extensions,
+        body: Bytes,
+    ) -> Response {
+        let content_type = headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|m| m.to_str().ok());
+        let Some(codec) = LinkCodec::from_content_type(content_type) else {
+            return StatusCode::UNSUPPORTED_MEDIA_TYPE.into_response();
+        };
+        let payload: LinkPayload = match codec.decode(&body) {
+            Ok(m) => m,
+            Err(e) => return (StatusCode::BAD_REQUEST, e).into_response(),
+        };
+
+        let request = request_context(&headers, &extensions);
+        let reply = resolver.resolve_payload(payload, &request).await;
+        let status = StatusCode::from_u16(reply.status()).unwrap_or(StatusCode::OK);
+        match codec.encode(&reply) {
+            Ok(body) => {
+                (status, [(header::CONTENT_TYPE, codec.content_type())], body).into_response()
+            }
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
+        }
+    }
+
+    /// Axum handler that streams the updates of a linked state as server-sent events.
+    ///
+    /// Subscriptions receive a [`RequestContext`] like the resolvers of
+    /// [`linked_state_handler`]. Serve it on the `GET` method of the same endpoint:
+    ///
+    /// ```ignore
+    /// axum::Router::new().route(
+    ///     "/api/link",
+    ///     axum::routing::post(linked_state_handler)
+    ///         .get(linked_subscription_handler)
+    ///         .with_state(resolver),
+    /// )
+    /// ```
+    pub async fn linked_subscription_handler(
+        State(resolver): State<Arc<Resolver>>,
+        headers: HeaderMap,
+        extensions: Extensions,
+        Query(query): Query<LinkSubscriptionQuery>,
+    ) -> Response {
+        let request = request_context(&headers, &extensions);
+        match query
+            .into_request()
+            .and_then(|req| resolver.subscribe_request_with(&req, &request))
+        {
+            Ok(stream) => Sse::new(
+                stream.map(|val| Ok::<_, Infallible>(Event::default().data(val.to_string()))),
+            )
+            .keep_alive(KeepAlive::default())
+            .into_response(),
+            Err(e) => {
+                let resp = LinkResponse::from(e);
+                let status = StatusCode::from_u16(resp.status())
+                    .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+                (status, Json(resp)).into_response()
+            }
         }
     }
+
+    /// Creates the [`RequestContext`] of a request from its headers, on top of the one inserted
+    /// in its extensions by a middleware, if any.
+    fn request_context(headers: &HeaderMap, extensions: &Extensions) -> RequestContext {
+        headers
+            .iter()
+            .filter_map(|(name, value)| Some((name, value.to_str().ok()?)))
+            .fold(
+                extensions
+                    .get::<RequestContext>()
+                    .cloned()
+                    .unwrap_or_default(),
+                |request, (name, value)| request.with_header(name, value),
+            )
+    }
 }
 
 #[cfg(feature = "actix")]
 pub mod actix {
-    use actix_web::HttpResponse;
-    use actix_web::web::{Data, Json};
+    use actix_web::http::{StatusCode, header};
+    use actix_web::web::{Bytes, Data, Query};
+    use actix_web::{HttpMessage, HttpRequest, HttpResponse};
+    use futures::StreamExt;
 
-    use crate::{LinkRequest, LinkResponse, Resolver};
+    use crate::{
+        LinkCodec, LinkPayload, LinkResponse, LinkSubscriptionQuery, RequestContext, Resolver,
+    };
 
-    /// Actix handler that resolves [`LinkRequest`]s.
+    /// Actix handler that resolves [`LinkRequest`](crate::LinkRequest)s.
+    ///
+    /// The requests of a batch are resolved concurrently. The body is decoded with the
+    /// [`LinkCodec`] matching its `Content-Type`, and the response is encoded with the same one.
+    ///
+    /// Resolvers receive the headers of the request in a [`RequestContext`], on top of the one
+    /// inserted in the request extensions by a middleware, if any.
+    ///
+    /// A single request is answered with `200 OK` if it succeeds, with the status chosen by
+    /// [`LinkedState::error_status`](crate::LinkedState::error_status) if its resolver fails,
+    /// and with `400`, `404` or `500` on an [`InternalError`](crate::InternalError). A batch is
+    /// always answered with `200 OK`.
     ///
     /// ```no_run
     /// use actix_web::web::{Data, post};
@@ -117,18 +208,83 @@ pub mod actix {
     /// ```
     pub async fn linked_state_handler(
         resolver: Data<Resolver>,
-        Json(req): Json<LinkRequest>,
+        req: HttpRequest,
+        body: Bytes,
     ) -> HttpResponse {
-        match resolver.resolve_request(&req).await {
-            Ok(val) => HttpResponse::Ok().json(LinkResponse {
-                ok: Some(val),
-                error: None,
-            }),
+        let content_type = req
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|m| m.to_str().ok());
+        let Some(codec) = LinkCodec::from_content_type(content_type) else {
+            return HttpResponse::UnsupportedMediaType().finish();
+        };
+        let payload: LinkPayload = match codec.decode(&body) {
+            Ok(m) => m,
+            Err(e) => return HttpResponse::BadRequest().body(e),
+        };
 
-            Err(err_val) => HttpResponse::UnprocessableEntity().json(LinkResponse {
-                ok: None,
-                error: Some(err_val),
-            }),
+        let request = request_context(&req);
+        let reply = resolver.resolve_payload(payload, &request).await;
+        let status = StatusCode::from_u16(reply.status()).unwrap_or(StatusCode::OK);
+        match codec.encode(&reply) {
+            Ok(body) => HttpResponse::build(status)
+                .content_type(codec.content_type())
+                .body(body),
+            Err(e) => HttpResponse::InternalServerError().body(e),
         }
     }
+
+    /// Actix handler that streams the updates of a linked state as server-sent events.
+    ///
+    /// Subscriptions receive a [`RequestContext`] like the resolvers of
+    /// [`linked_state_handler`]. Serve it on the `GET` method of the same endpoint:
+    ///
+    /// ```ignore
+    /// App::new().app_data(resolver.clone()).service(
+    ///     web::resource("/api/link")
+    ///         .route(web::post().to(linked_state_handler))
+    ///         .route(web::get().to(linked_subscription_handler)),
+    /// )
+    /// ```
+    pub async fn linked_subscription_handler(
+        resolver: Data<Resolver>,
+        req: HttpRequest,
+        Query(query): Query<LinkSubscriptionQuery>,
+    ) -> HttpResponse {
+        let request = request_context(&req);
+        match query
+            .into_request()
+            .and_then(|req| resolver.subscribe_request_with(&req, &request))
+        {
+            Ok(stream) => {
+                HttpResponse::Ok()
+                    .content_type("text/event-stream")
+                    .insert_header((header::CACHE_CONTROL, "no-cache"))
+                    .streaming(stream.map(|val| {
+                        Ok::<_, actix_web::Error>(Bytes::from(format!("data: {val}\n\n")))
+                    }))
+            }
+            Err(e) => {
+                let resp = LinkResponse::from(e);
+                let status = StatusCode::from_u16(resp.status())
+                    .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+                HttpResponse::build(status).json(resp)
+            }
+        }
+    }
+
+    /// Creates the [`RequestContext`] of a request from its headers, on top of the one inserted
+    /// in its extensions by a middleware, if any.
+    fn request_context(req: &HttpRequest) -> RequestContext {
+        req.headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name, value.to_str().ok()?)))
+            .fold(
+                req.extensions()
+                    .get::<RequestContext>()
+                    .cloned()
+                    .unwrap_or_default(),
+                |request, (name, value)| request.with_header(name, value),
+            )
+    }
 }
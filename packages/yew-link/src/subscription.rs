@@ -0,0 +1,221 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Arc;
+
+use futures::StreamExt;
+use futures::stream::{self, BoxStream, Stream};
+use yew::prelude::*;
+use yew::suspense::SuspensionResult;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::LinkedStateResolve;
+use crate::{
+    InternalError, LinkRequest, LinkedState, LinkedStateHandle, RequestContext, Resolver,
+    use_linked_state,
+};
+
+/// Server-side extension of [`LinkedStateResolve`] that streams updates of a state.
+///
+/// Implement it, or add a `fn subscribe` to a [`#[linked_state]`](crate::linked_state) impl,
+/// to keep the values displayed with [`use_linked_subscription`] live.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait LinkedStateSubscribe: LinkedStateResolve {
+    /// Returns a stream of the new values of the state for `input`.
+    ///
+    /// The stream is polled for as long as a client is subscribed. Values that have changed
+    /// since the state was resolved are only displayed if the stream yields them, so it should
+    /// start with the current value if it may have changed in the meantime.
+    fn subscribe(
+        ctx: &Self::Context,
+        input: &Self::Input,
+    ) -> impl Stream<Item = Self> + Send + 'static;
+
+    /// Returns a stream of the new values of the state for `input`, with the
+    /// [`RequestContext`] of the subscription request.
+    ///
+    /// Used by [`Resolver::register_linked_subscription`]. Defaults to
+    /// [`subscribe`](Self::subscribe), which ignores the request. The
+    /// [`#[linked_state]`](crate::linked_state) macro implements it when `subscribe` takes the
+    /// request as a third parameter.
+    fn subscribe_with_request(
+        ctx: &Self::Context,
+        input: &Self::Input,
+        request: &RequestContext,
+    ) -> impl Stream<Item = Self> + Send + 'static {
+        let _ = request;
+        Self::subscribe(ctx, input)
+    }
+}
+
+impl Resolver {
+    /// Register a stream of updates for `T`. The closure receives `T::Input` and returns a
+    /// stream of the new values of `T`.
+    ///
+    /// The values are served by `linked_subscription_handler`, alongside the resolver
+    /// registered for `T`.
+    pub fn register_subscription<T, F, S>(self, f: F) -> Self
+    where
+        T: LinkedState,
+        F: Fn(T::Input) -> S + Send + Sync + 'static,
+        S: Stream<Item = T> + Send + 'static,
+    {
+        self.register_subscription_with_request::<T, _, _>(move |input, _| f(input))
+    }
+
+    /// Register a stream of updates for `T` that receives the [`RequestContext`] of the
+    /// subscription request, e.g. to only stream the updates the authenticated user may see.
+    pub fn register_subscription_with_request<T, F, S>(mut self, f: F) -> Self
+    where
+        T: LinkedState,
+        F: Fn(T::Input, RequestContext) -> S + Send + Sync + 'static,
+        S: Stream<Item = T> + Send + 'static,
+    {
+        self.subscriptions.insert(
+            T::TYPE_KEY,
+            Box::new(
+                move |input_json: serde_json::Value, request: RequestContext| {
+                    let input: T::Input = serde_json::from_value(input_json)
+                        .map_err(|e| InternalError::InvalidInput(e.to_string()))?;
+                    Ok(f(input, request)
+                        .filter_map(|val| async move { serde_json::to_value(&val).ok() })
+                        .boxed())
+                },
+            ),
+        );
+        self
+    }
+
+    /// Subscribe to the updates of the state requested by a [`LinkRequest`] with an empty
+    /// [`RequestContext`].
+    pub fn subscribe_request(
+        &self,
+        req: &LinkRequest,
+    ) -> Result<BoxStream<'static, serde_json::Value>, InternalError> {
+        self.subscribe_request_with(req, &RequestContext::default())
+    }
+
+    /// Subscribe to the updates of the state requested by a [`LinkRequest`] made by the
+    /// request described by `request`.
+    pub fn subscribe_request_with(
+        &self,
+        req: &LinkRequest,
+        request: &RequestContext,
+    ) -> Result<BoxStream<'static, serde_json::Value>, InternalError> {
+        let subscribe = self
+            .subscriptions
+            .get(req.type_key.as_str())
+            .ok_or_else(|| InternalError::NoResolver(req.type_key.clone()))?;
+        // The connection is kept open once the stream has ended, as clients would otherwise
+        // reconnect and subscribe again.
+        subscribe(req.input.clone(), request.clone()).map(|s| s.chain(stream::pending()).boxed())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Resolver {
+    /// Register a resolver and a stream of updates for `T` using its [`LinkedStateResolve`]
+    /// and [`LinkedStateSubscribe`] impls.
+    ///
+    /// The `ctx` is wrapped in an [`Arc`] internally and shared by both.
+    pub fn register_linked_subscription<T>(self, ctx: T::Context) -> Self
+    where
+        T: LinkedStateSubscribe + Send + 'static,
+        T::Input: Send,
+        T::Error: Send,
+    {
+        let ctx = Arc::new(ctx);
+        let resolve_ctx = ctx.clone();
+        self.register_with_request::<T, _, _>(move |input, request| {
+            let ctx = resolve_ctx.clone();
+            async move { T::resolve_with_request(&*ctx, &input, &request).await }
+        })
+        .register_subscription_with_request::<T, _, _>(move |input, request| {
+            T::subscribe_with_request(&ctx, &input, &request)
+        })
+    }
+}
+
+/// Fetch a [`LinkedState`] value and keep it up to date with the updates streamed by the server.
+///
+/// Behaves like [`use_linked_state`]: the first value is resolved on the server and embedded
+/// in the SSR HTML for hydration. Once the component is rendered on the client, it subscribes
+/// to the stream registered for `T` with
+/// [`Resolver::register_subscription`] through server-sent events from the provider's
+/// `endpoint`, and every new value replaces the cached one.
+///
+/// Components subscribing to the same `(T, Input)` share a single connection, which is closed
+/// once the last of them is unmounted.
+///
+/// # Panics
+///
+/// Panics if there is no ancestor [`LinkProvider`](crate::LinkProvider) in the component tree.
+#[hook]
+pub fn use_linked_subscription<T: LinkedState>(
+    input: T::Input,
+) -> SuspensionResult<LinkedStateHandle<T>> {
+    #[cfg(all(not(feature = "ssr"), target_arch = "wasm32"))]
+    {
+        let link_ctx = use_context::<crate::LinkContextInner>()
+            .expect("use_linked_subscription requires a LinkProvider");
+        let input = input.clone();
+        use_effect_with(crate::cache_key::<T>(&input), move |key| {
+            link_ctx.subscribe_live::<T>(key.clone(), &input);
+            let key = key.clone();
+            move || link_ctx.unsubscribe_live(&key)
+        });
+    }
+
+    use_linked_state::<T>(input)
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Clone, Serialize, Deserialize)]
+    struct Greeting(String);
+
+    impl LinkedState for Greeting {
+        type Input = String;
+        type Error = crate::Never;
+
+        const TYPE_KEY: &'static str = "Greeting";
+    }
+
+    fn request(input: &str) -> LinkRequest {
+        LinkRequest {
+            type_key: Greeting::TYPE_KEY.to_owned(),
+            input: json!(input),
+        }
+    }
+
+    #[tokio::test]
+    async fn subscriptions_receive_the_request_context() {
+        let resolver = Resolver::new().register_subscription_with_request::<Greeting, _, _>(
+            |name, request| {
+                let lang = request.header("Accept-Language").unwrap_or("en").to_owned();
+                stream::iter([Greeting(format!("{name} ({lang})"))])
+            },
+        );
+
+        let mut updates = resolver
+            .subscribe_request_with(
+                &request("Ferris"),
+                &RequestContext::new().with_header("accept-language", "fr"),
+            )
+            .unwrap();
+        assert_eq!(updates.next().await, Some(json!("Ferris (fr)")));
+
+        let mut updates = resolver.subscribe_request(&request("Ferris")).unwrap();
+        assert_eq!(updates.next().await, Some(json!("Ferris (en)")));
+    }
+
+    #[test]
+    fn unknown_subscriptions_are_rejected() {
+        let result = Resolver::new().subscribe_request(&request("Ferris"));
+
+        assert!(matches!(result, Err(InternalError::NoResolver(key)) if key == "Greeting"));
+    }
+}
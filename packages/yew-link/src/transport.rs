@@ -0,0 +1,296 @@
+use std::fmt;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use futures::future::LocalBoxFuture;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use yew::AttrValue;
+
+use crate::{LinkPayload, RequestContext, Resolver};
+
+/// The format of the bodies exchanged with the link endpoint.
+///
+/// The server handlers pick the codec from the `Content-Type` of the request and answer with
+/// the same one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LinkCodec {
+    /// JSON, readable and supported by every endpoint.
+    #[default]
+    Json,
+    /// CBOR, a more compact binary format for large payloads. Requires the `cbor` feature.
+    #[cfg(feature = "cbor")]
+    Cbor,
+}
+
+impl LinkCodec {
+    /// The `Content-Type` of the bodies encoded with this codec.
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            #[cfg(feature = "cbor")]
+            Self::Cbor => "application/cbor",
+        }
+    }
+
+    /// Returns the codec matching a `Content-Type`, ignoring its parameters.
+    ///
+    /// Bodies without a `Content-Type` are decoded as JSON. Returns `None` if the content type
+    /// is not supported.
+    pub fn from_content_type(content_type: Option<&str>) -> Option<Self> {
+        let Some(content_type) = content_type else {
+            return Some(Self::Json);
+        };
+        match content_type.split(';').next().unwrap_or_default().trim() {
+            "application/json" => Some(Self::Json),
+            #[cfg(feature = "cbor")]
+            "application/cbor" => Some(Self::Cbor),
+            _ => None,
+        }
+    }
+
+    /// Encodes `value` with this codec.
+    pub fn encode<T: Serialize + ?Sized>(self, value: &T) -> Result<Vec<u8>, String> {
+        match self {
+            Self::Json => serde_json::to_vec(value).map_err(|e| e.to_string()),
+            #[cfg(feature = "cbor")]
+            Self::Cbor => {
+                let mut body = Vec::new();
+                ciborium::into_writer(value, &mut body).map_err(|e| e.to_string())?;
+                Ok(body)
+            }
+        }
+    }
+
+    /// Decodes a `T` encoded with this codec.
+    pub fn decode<T: DeserializeOwned>(self, body: &[u8]) -> Result<T, String> {
+        match self {
+            Self::Json => serde_json::from_slice(body).map_err(|e| e.to_string()),
+            #[cfg(feature = "cbor")]
+            Self::Cbor => ciborium::from_reader(body).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// A request to the link endpoint, sent by a [`LinkTransport`].
+#[derive(Debug, Clone)]
+pub struct TransportRequest {
+    /// The `endpoint` of the [`LinkProvider`](crate::LinkProvider).
+    pub endpoint: AttrValue,
+    /// The `Content-Type` of the body.
+    pub content_type: &'static str,
+    /// The encoded requests.
+    pub body: Vec<u8>,
+}
+
+/// Sends the requests of the client to the link endpoint.
+///
+/// Pass an implementation to the `transport` prop of [`LinkProvider`](crate::LinkProvider) to
+/// add headers, use a custom fetch implementation, or resolve requests in process in tests with
+/// [`ResolverTransport`]. Requests are sent with [`HttpTransport`] by default.
+///
+/// Subscriptions made with [`use_linked_subscription`](crate::use_linked_subscription) are
+/// always streamed from the endpoint with server-sent events.
+pub trait LinkTransport {
+    /// Sends `request` and returns the body of the response.
+    fn send(&self, request: TransportRequest) -> LocalBoxFuture<'static, Result<Vec<u8>, String>>;
+}
+
+/// Sends requests with `POST` using the Fetch API.
+#[derive(Debug, Clone, Default)]
+pub struct HttpTransport {
+    headers: Vec<(String, String)>,
+}
+
+impl HttpTransport {
+    /// Creates a transport without additional headers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a header to every request, e.g. an `Authorization` header.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl LinkTransport for HttpTransport {
+    fn send(&self, request: TransportRequest) -> LocalBoxFuture<'static, Result<Vec<u8>, String>> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            use gloo_net::http::Request;
+
+            let mut builder = Request::post(request.endpoint.as_ref())
+                .header("Content-Type", request.content_type)
+                .header("Accept", request.content_type);
+            for (name, value) in &self.headers {
+                builder = builder.header(name, value);
+            }
+
+            Box::pin(async move {
+                let body = js_sys::Uint8Array::from(request.body.as_slice());
+                let resp = builder
+                    .body(body)
+                    .map_err(|e| e.to_string())?
+                    .send()
+                    .await
+                    .map_err(|e| e.to_string())?;
+
+                // Proxies answer with these when the endpoint can't be reached.
+                if matches!(resp.status(), 502..=504) {
+                    return Err(format!(
+                        "the endpoint answered with {} {}",
+                        resp.status(),
+                        resp.status_text()
+                    ));
+                }
+
+                resp.binary().await.map_err(|e| e.to_string())
+            })
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = (request, &self.headers);
+            Box::pin(async { Err("HttpTransport is only available on wasm32 targets".into()) })
+        }
+    }
+}
+
+/// Resolves requests in process with a [`Resolver`], without a server.
+///
+/// Useful to test components that use linked states.
+#[derive(Debug, Clone)]
+pub struct ResolverTransport {
+    resolver: Arc<Resolver>,
+    request: RequestContext,
+}
+
+impl ResolverTransport {
+    pub fn new(resolver: impl Into<Arc<Resolver>>) -> Self {
+        Self {
+            resolver: resolver.into(),
+            request: RequestContext::default(),
+        }
+    }
+
+    /// Sets the [`RequestContext`] passed to the resolvers, e.g. to test components as an
+    /// authenticated user.
+    pub fn with_request(mut self, request: RequestContext) -> Self {
+        self.request = request;
+        self
+    }
+}
+
+impl LinkTransport for ResolverTransport {
+    fn send(&self, request: TransportRequest) -> LocalBoxFuture<'static, Result<Vec<u8>, String>> {
+        let resolver = self.resolver.clone();
+        let ctx = self.request.clone();
+        Box::pin(async move {
+            let codec = LinkCodec::from_content_type(Some(request.content_type))
+                .ok_or_else(|| format!("unsupported content type: {}", request.content_type))?;
+            let payload: LinkPayload = codec.decode(&request.body)?;
+            codec.encode(&resolver.resolve_payload(payload, &ctx).await)
+        })
+    }
+}
+
+/// Wrapper so a [`LinkTransport`] can be passed as a component prop.
+#[derive(Clone)]
+pub struct LinkTransportProp(pub Rc<dyn LinkTransport>);
+
+impl fmt::Debug for LinkTransportProp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LinkTransportProp").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for LinkTransportProp {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T: LinkTransport + 'static> From<T> for LinkTransportProp {
+    fn from(transport: T) -> Self {
+        Self(Rc::new(transport))
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::LinkResponse;
+
+    fn payload() -> LinkPayload {
+        serde_json::from_value(json!([
+            { "type_key": "Post", "input": 1 },
+            { "type_key": "Comments", "input": { "post": 1, "page": [0, 20] } },
+        ]))
+        .unwrap()
+    }
+
+    fn round_trip(codec: LinkCodec) {
+        let body = codec.encode(&payload()).unwrap();
+        let decoded: LinkPayload = codec.decode(&body).unwrap();
+
+        assert!(matches!(decoded, LinkPayload::Batch(ref m) if m.len() == 2));
+        assert_eq!(
+            serde_json::to_value(&decoded).unwrap(),
+            serde_json::to_value(payload()).unwrap()
+        );
+    }
+
+    #[test]
+    fn json_round_trip() {
+        round_trip(LinkCodec::Json);
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn cbor_round_trip() {
+        round_trip(LinkCodec::Cbor);
+    }
+
+    #[test]
+    fn codec_from_content_type() {
+        assert_eq!(LinkCodec::from_content_type(None), Some(LinkCodec::Json));
+        assert_eq!(
+            LinkCodec::from_content_type(Some("application/json")),
+            Some(LinkCodec::Json)
+        );
+        assert_eq!(
+            LinkCodec::from_content_type(Some(" application/json ; charset=utf-8")),
+            Some(LinkCodec::Json)
+        );
+        assert_eq!(LinkCodec::from_content_type(Some("text/plain")), None);
+        #[cfg(feature = "cbor")]
+        assert_eq!(
+            LinkCodec::from_content_type(Some("application/cbor")),
+            Some(LinkCodec::Cbor)
+        );
+        #[cfg(not(feature = "cbor"))]
+        assert_eq!(LinkCodec::from_content_type(Some("application/cbor")), None);
+    }
+
+    #[tokio::test]
+    async fn resolver_transport_answers_with_the_codec_of_the_request() {
+        let codec = LinkCodec::default();
+        let transport = ResolverTransport::new(Resolver::new());
+
+        let body = transport
+            .send(TransportRequest {
+                endpoint: "/link".into(),
+                content_type: codec.content_type(),
+                body: codec.encode(&payload()).unwrap(),
+            })
+            .await
+            .unwrap();
+
+        let resps: Vec<LinkResponse> = codec.decode(&body).unwrap();
+        assert_eq!(resps.len(), 2);
+        assert!(resps.iter().all(|m| m.status() == 404));
+    }
+}
@@ -3,6 +3,7 @@ use std::convert::TryFrom;
 
 use proc_macro2::{Ident, Span};
 use quote::{format_ident, quote, quote_spanned};
+use syn::ext::IdentExt;
 use syn::parse::Result;
 use syn::spanned::Spanned;
 use syn::{
@@ -117,6 +118,19 @@ impl PropField {
         }
     }
 
+    /// Records the name of the field in `changed` if it differs between `self` and `other`
+    pub fn to_changed_check(&self) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        let name_str = name.unraw().to_string();
+        let extra_attrs = &self.extra_attrs;
+        quote! {
+            #( #extra_attrs )*
+            if (&::yew::html::FieldEq(&self.#name, &other.#name)).field_changed() {
+                changed.push(#name_str);
+            }
+        }
+    }
+
     /// Wrap all required props in `Option`
     pub fn to_field_def(&self) -> proc_macro2::TokenStream {
         let ty = &self.ty;
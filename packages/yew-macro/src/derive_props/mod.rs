@@ -199,6 +199,7 @@ impl ToTokens for DerivePropsInput {
 
         // The properties trait has a `builder` method which creates the props builder
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let changed_checks = prop_fields.iter().map(|pf| pf.to_changed_check());
         let properties = quote! {
             impl #impl_generics ::yew::html::Properties for #props_name #ty_generics #where_clause {
                 type Builder = #builder_name<#generic_args>;
@@ -208,6 +209,25 @@ impl ToTokens for DerivePropsInput {
                         wrapped: ::std::boxed::Box::new(::std::default::Default::default()),
                     }
                 }
+
+                // Only generated with the `devtools` feature of `yew`.
+                ::yew::__devtools! {
+                    fn __debug_props(&self) -> ::std::option::Option<::std::string::String> {
+                        #[allow(unused_imports)]
+                        use ::yew::html::{ViaDebug as _, ViaNoDebug as _};
+                        (&::yew::html::DebugProps(self)).debug_props()
+                    }
+
+                    #[allow(unused_variables)]
+                    fn __changed_fields(&self, other: &Self) -> ::std::vec::Vec<&'static str> {
+                        #[allow(unused_imports)]
+                        use ::yew::html::{ViaPartialEq as _, ViaNoPartialEq as _};
+                        #[allow(unused_mut)]
+                        let mut changed = ::std::vec::Vec::new();
+                        #( #changed_checks )*
+                        changed
+                    }
+                }
             }
         };
         tokens.extend(properties);
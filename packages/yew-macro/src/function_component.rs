@@ -315,6 +315,11 @@ impl FunctionComponent {
                     ::yew::functional::FunctionComponent::<Self>::rendered(&self.function_component)
                 }
 
+                #[inline]
+                fn layout_rendered(&mut self, _ctx: &::yew::html::Context<Self>, _first_render: ::std::primitive::bool) {
+                    ::yew::functional::FunctionComponent::<Self>::layout_rendered(&self.function_component)
+                }
+
                 #[inline]
                 fn destroy(&mut self, _ctx: &::yew::html::Context<Self>) {
                     ::yew::functional::FunctionComponent::<Self>::destroy(&self.function_component)
@@ -37,52 +37,30 @@ impl BodyRewriter {
 }
 
 impl VisitMut for BodyRewriter {
-    fn visit_expr_call_mut(&mut self, i: &mut ExprCall) {
-        let ctx_ident = &self.ctx_ident;
-
-        // Only rewrite hook calls.
-        if let Expr::Path(m) = &*i.func {
-            if let Some(m) = m.path.segments.last().as_ref().map(|m| &m.ident) {
-                if m.to_string().starts_with("use_") {
-                    if self.is_branched() {
-                        emit_error!(
-                            m,
-                            "hooks cannot be called at this position.";
-                            help = "move hooks to the top-level of your function.";
-                            note = "see: https://yew.rs/docs/next/concepts/function-components/hooks"
-                        );
-                    } else {
-                        *i = parse_quote_spanned! { i.span() => ::yew::functional::Hook::run(#i, #ctx_ident) };
-                    }
-
-                    return;
-                }
-            }
-        }
-
-        visit_mut::visit_expr_call_mut(self, i);
-    }
-
     fn visit_expr_mut(&mut self, i: &mut Expr) {
         let ctx_ident = &self.ctx_ident;
 
-        match &mut *i {
-            Expr::Macro(m) => {
-                if let Some(ident) = m.mac.path.segments.last().as_ref().map(|m| &m.ident) {
-                    if ident.to_string().starts_with("use_") {
-                        if self.is_branched() {
-                            emit_error!(
-                                ident,
-                                "hooks cannot be called at this position.";
-                                help = "move hooks to the top-level of your function.";
-                                note = "see: https://yew.rs/docs/next/concepts/function-components/hooks"
-                            );
-                        } else {
-                            *i = parse_quote_spanned! { i.span() => ::yew::functional::Hook::run(#i, #ctx_ident) };
-                        }
-                    } else {
-                        visit_mut::visit_expr_macro_mut(self, m);
-                    }
+        // Only rewrite hook calls.
+        let hook_ident = match &*i {
+            Expr::Call(ExprCall { func, .. }) => match &**func {
+                Expr::Path(m) => m.path.segments.last().map(|m| &m.ident),
+                _ => None,
+            },
+            Expr::Macro(m) => m.mac.path.segments.last().map(|m| &m.ident),
+            _ => None,
+        };
+
+        match hook_ident {
+            Some(ident) if ident.to_string().starts_with("use_") => {
+                if self.is_branched() {
+                    emit_error!(
+                        ident,
+                        "hooks cannot be called at this position.";
+                        help = "move hooks to the top-level of your function.";
+                        note = "see: https://yew.rs/docs/next/concepts/function-components/hooks"
+                    );
+                } else {
+                    *i = parse_quote_spanned! { i.span() => ::yew::__hook_run!(#i, #ctx_ident) };
                 }
             }
             _ => visit_mut::visit_expr_mut(self, i),
@@ -23,7 +23,75 @@ use syn::parse_macro_input;
 ///     NotFound,
 /// }
 /// ```
-#[proc_macro_derive(Routable, attributes(at, not_found))]
+///
+/// # Nested routes
+///
+/// A variant can hold a route matching the rest of the path in a field marked with
+/// `#[nested]`. The paths of the nested route are relative to the path of the variant, and
+/// the nested route is rendered by an `Outlet` in the component rendering the variant.
+///
+/// ```
+/// # use yew_router::Routable;
+/// #[derive(Debug, Clone, PartialEq, Routable)]
+/// enum Routes {
+///     #[at("/")]
+///     Home,
+///     #[at("/settings")]
+///     Settings(#[nested] SettingsRoutes),
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq, Routable)]
+/// enum SettingsRoutes {
+///     #[at("/")]
+///     Profile,
+///     #[at("/theme")]
+///     Theme,
+/// }
+///
+/// assert_eq!(
+///     Routes::recognize("/settings/theme"),
+///     Some(Routes::Settings(SettingsRoutes::Theme))
+/// );
+/// assert_eq!(Routes::Settings(SettingsRoutes::Profile).to_path(), "/settings");
+/// ```
+///
+/// # Query parameters
+///
+/// A variant can hold the query string in a field marked with `#[query]`, whose type
+/// implements `Serialize` and `Deserialize`. The route only matches if the query string can be
+/// deserialized into it.
+///
+/// ```
+/// # use yew_router::Routable;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// struct SearchQuery {
+///     q: String,
+///     page: Option<u32>,
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq, Routable)]
+/// enum Routes {
+///     #[at("/search")]
+///     Search {
+///         #[query]
+///         query: SearchQuery,
+///     },
+/// }
+///
+/// let route = Routes::Search {
+///     query: SearchQuery {
+///         q: "yew".to_string(),
+///         page: Some(2),
+///     },
+/// };
+/// assert_eq!(route.to_path(), "/search?q=yew&page=2");
+/// assert_eq!(
+///     Routes::recognize_with_query("/search", "?q=yew&page=2"),
+///     Some(route)
+/// );
+/// ```
+#[proc_macro_derive(Routable, attributes(at, not_found, nested, query))]
 pub fn routable_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as Routable);
     routable_derive_impl(input).into()
@@ -3,10 +3,15 @@ use quote::quote;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Data, DeriveInput, Fields, Ident, LitStr, Variant};
+use syn::{Data, DeriveInput, Field, Fields, Ident, LitStr, Member, Type, Variant};
 
 const AT_ATTR_IDENT: &str = "at";
 const NOT_FOUND_ATTR_IDENT: &str = "not_found";
+const NESTED_ATTR_IDENT: &str = "nested";
+const QUERY_ATTR_IDENT: &str = "query";
+/// The wildcard capturing the path of a nested route. Must match the one read by
+/// `yew_router::__macro::nested_path`.
+const NESTED_PARAM: &str = "__nested";
 
 /// Extract parameter names from a matchit-style route pattern.
 /// E.g. `"/posts/{id}"` → `["id"]`, `"/files/{*path}"` → `["path"]`.
@@ -30,10 +35,38 @@ fn extract_route_params(route: &str) -> Vec<String> {
 pub struct Routable {
     ident: Ident,
     ats: Vec<LitStr>,
+    nested: Vec<Option<NestedField>>,
+    queries: Vec<Option<Ident>>,
     variants: Punctuated<Variant, syn::token::Comma>,
     not_found_route: Option<Ident>,
 }
 
+/// A field marked with `#[nested]`, holding the route matched by the rest of the path.
+struct NestedField {
+    member: Member,
+    ty: Type,
+}
+
+fn has_attr(field: &Field, ident: &str) -> bool {
+    field.attrs.iter().any(|attr| attr.path().is_ident(ident))
+}
+
+fn is_nested(field: &Field) -> bool {
+    has_attr(field, NESTED_ATTR_IDENT)
+}
+
+/// Whether `field` is captured from the path, as opposed to a `#[nested]` or `#[query]` field.
+fn is_path_param(field: &Field) -> bool {
+    !is_nested(field) && !has_attr(field, QUERY_ATTR_IDENT)
+}
+
+/// The route matching the paths below `at`, captured in [`NESTED_PARAM`].
+fn nested_at(at: &LitStr) -> LitStr {
+    let val = at.value();
+    let sep = if val.ends_with('/') { "" } else { "/" };
+    LitStr::new(&format!("{val}{sep}{{*{NESTED_PARAM}}}"), at.span())
+}
+
 impl Parse for Routable {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let DeriveInput { ident, data, .. } = input.parse()?;
@@ -54,32 +87,99 @@ impl Parse for Routable {
             }
         };
 
-        let (not_found_route, ats) = parse_variants_attributes(&data.variants)?;
+        let (not_found_route, ats, nested, queries) = parse_variants_attributes(&data.variants)?;
 
         Ok(Self {
             ident,
             variants: data.variants,
             ats,
+            nested,
+            queries,
             not_found_route,
         })
     }
 }
 
+fn parse_nested_field(variant: &Variant) -> syn::Result<Option<NestedField>> {
+    let nested_fields = variant
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| is_nested(field))
+        .collect::<Vec<_>>();
+
+    if matches!(variant.fields, Fields::Unnamed(_))
+        && (variant.fields.len() != 1 || nested_fields.is_empty())
+    {
+        return Err(syn::Error::new(
+            variant.fields.span(),
+            format!(
+                "only named fields are supported, except for a single #[{NESTED_ATTR_IDENT}] field"
+            ),
+        ));
+    }
+
+    match nested_fields.as_slice() {
+        [] => Ok(None),
+        [(i, field)] => Ok(Some(NestedField {
+            member: match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed((*i).into()),
+            },
+            ty: field.ty.clone(),
+        })),
+        _ => Err(syn::Error::new_spanned(
+            {
+                let fields = nested_fields.iter().map(|(_, field)| field);
+                quote! { #(#fields)* }
+            },
+            format!("only one field can be #[{NESTED_ATTR_IDENT}]"),
+        )),
+    }
+}
+
+fn parse_query_field(variant: &Variant) -> syn::Result<Option<Ident>> {
+    let query_fields = variant
+        .fields
+        .iter()
+        .filter(|field| has_attr(field, QUERY_ATTR_IDENT))
+        .collect::<Vec<_>>();
+
+    match query_fields.as_slice() {
+        [] => Ok(None),
+        [field] if is_nested(field) => Err(syn::Error::new_spanned(
+            field,
+            format!("a field cannot be both #[{NESTED_ATTR_IDENT}] and #[{QUERY_ATTR_IDENT}]"),
+        )),
+        // only nested fields may be unnamed
+        [field] => Ok(field.ident.clone()),
+        _ => Err(syn::Error::new_spanned(
+            quote! { #(#query_fields)* },
+            format!("only one field can be #[{QUERY_ATTR_IDENT}]"),
+        )),
+    }
+}
+
+type VariantsAttributes = (
+    Option<Ident>,
+    Vec<LitStr>,
+    Vec<Option<NestedField>>,
+    Vec<Option<Ident>>,
+);
+
 fn parse_variants_attributes(
     variants: &Punctuated<Variant, syn::token::Comma>,
-) -> syn::Result<(Option<Ident>, Vec<LitStr>)> {
+) -> syn::Result<VariantsAttributes> {
     let mut not_founds = vec![];
     let mut ats: Vec<LitStr> = vec![];
+    let mut nested = vec![];
+    let mut queries = vec![];
 
     let mut not_found_attrs = vec![];
 
     for variant in variants.iter() {
-        if let Fields::Unnamed(ref field) = variant.fields {
-            return Err(syn::Error::new(
-                field.span(),
-                "only named fields are supported",
-            ));
-        }
+        let nested_field = parse_nested_field(variant)?;
+        let query_field = parse_query_field(variant)?;
 
         let attrs = &variant.attrs;
         let at_attrs = attrs
@@ -143,17 +243,21 @@ fn parse_variants_attributes(
             }
         }
 
+        if nested_field.is_some() && val.contains("{*") {
+            return Err(syn::Error::new_spanned(
+                &lit,
+                format!("a route with a #[{NESTED_ATTR_IDENT}] field cannot capture a wildcard"),
+            ));
+        }
+
         let route_params = extract_route_params(&val);
         if !route_params.is_empty() {
-            let field_names: std::collections::HashSet<String> = match &variant.fields {
-                Fields::Named(fields) => fields
-                    .named
-                    .iter()
-                    .filter_map(|f| f.ident.as_ref().map(|i| i.to_string()))
-                    .collect(),
-                Fields::Unit => std::collections::HashSet::new(),
-                Fields::Unnamed(_) => unreachable!(),
-            };
+            let field_names: std::collections::HashSet<String> = variant
+                .fields
+                .iter()
+                .filter(|f| is_path_param(f))
+                .filter_map(|f| f.ident.as_ref().map(|i| i.to_string()))
+                .collect();
 
             for param in &route_params {
                 if !field_names.contains(param) {
@@ -170,6 +274,8 @@ fn parse_variants_attributes(
         }
 
         ats.push(lit);
+        nested.push(nested_field);
+        queries.push(query_field);
 
         for attr in attrs.iter() {
             if attr.path().is_ident(NOT_FOUND_ATTR_IDENT) {
@@ -186,31 +292,49 @@ fn parse_variants_attributes(
         ));
     }
 
-    Ok((not_founds.into_iter().next(), ats))
+    Ok((not_founds.into_iter().next(), ats, nested, queries))
 }
 
 impl Routable {
     fn build_from_path(&self) -> TokenStream {
         let from_path_matches = self.variants.iter().enumerate().map(|(i, variant)| {
             let ident = &variant.ident;
+            let nested = self.nested.get(i).unwrap();
             let right = match &variant.fields {
                 Fields::Unit => quote! { Self::#ident },
-                Fields::Named(field) => {
-                    let fields = field.named.iter().map(|it| {
-                        // named fields have idents
+                fields => {
+                    let fields = fields.iter().filter(|it| is_path_param(it)).map(|it| {
+                        // only nested fields may be unnamed
                         it.ident.as_ref().unwrap()
                     });
+                    let nested = nested.as_ref().map(|NestedField { member, ty }| {
+                        quote! {
+                            #member: <#ty as ::yew_router::Routable>::recognize_with_query(
+                                &::yew_router::__macro::nested_path(params),
+                                query,
+                            )?,
+                        }
+                    });
+                    let query = self.queries.get(i).unwrap().as_ref().map(|field| {
+                        quote! { #field: ::yew_router::__macro::from_query(query)?, }
+                    });
                     quote! { Self::#ident { #(#fields: {
                         let param = params.get(stringify!(#fields))?;
                         let param = &*::yew_router::__macro::decode_for_url(param).ok()?;
                         let param = param.parse().ok()?;
                         param
-                    },)* } }
+                    },)* #nested #query } }
                 }
-                Fields::Unnamed(_) => unreachable!(), // already checked
             };
 
             let left = self.ats.get(i).unwrap();
+            let left = match nested {
+                Some(_) => {
+                    let nested_left = nested_at(left);
+                    quote! { #left | #nested_left }
+                }
+                None => quote! { #left },
+            };
             quote! {
                 #left => ::std::option::Option::Some(#right)
             }
@@ -218,6 +342,14 @@ impl Routable {
 
         quote! {
             fn from_path(path: &str, params: &::std::collections::HashMap<&str, &str>) -> ::std::option::Option<Self> {
+                Self::from_path_with_query(path, params, "")
+            }
+
+            fn from_path_with_query(
+                path: &str,
+                params: &::std::collections::HashMap<&str, &str>,
+                query: &str,
+            ) -> ::std::option::Option<Self> {
                 match path {
                     #(#from_path_matches),*,
                     _ => ::std::option::Option::None,
@@ -233,10 +365,10 @@ impl Routable {
 
             match &variant.fields {
                 Fields::Unit => quote! { Self::#ident => ::std::string::ToString::to_string(#right) },
-                Fields::Named(field) => {
-                    let fields = field
-                        .named
+                fields => {
+                    let fields = fields
                         .iter()
+                        .filter(|it| is_path_param(it))
                         .map(|it| it.ident.as_ref().unwrap())
                         .collect::<Vec<_>>();
 
@@ -263,11 +395,26 @@ impl Routable {
                         }
                     });
 
+                    let mut path = quote! { ::std::format!(#right, #(#field_encodings),*) };
+                    let mut bindings = quote! { #(#fields,)* };
+                    if let Some(NestedField { member, .. }) = self.nested.get(i).unwrap() {
+                        path = quote! {
+                            ::yew_router::__macro::compose_nested_path(
+                                #path,
+                                &::yew_router::Routable::to_path(__nested),
+                            )
+                        };
+                        bindings.extend(quote! { #member: __nested, });
+                    }
+                    if let Some(field) = self.queries.get(i).unwrap() {
+                        path = quote! { ::yew_router::__macro::append_query(#path, #field) };
+                        bindings.extend(quote! { #field, });
+                    }
+
                     quote! {
-                        Self::#ident { #(#fields),* } => ::std::format!(#right, #(#field_encodings),*)
+                        Self::#ident { #bindings } => #path
                     }
                 }
-                Fields::Unnamed(_) => unreachable!(), // already checked
             }
         });
 
@@ -279,11 +426,42 @@ impl Routable {
             }
         }
     }
+
+    fn build_nested_route(&self) -> TokenStream {
+        if self.nested.iter().all(Option::is_none) {
+            return TokenStream::new();
+        }
+
+        let nested_route_matches =
+            self.variants
+                .iter()
+                .zip(&self.nested)
+                .map(|(variant, nested)| {
+                    let ident = &variant.ident;
+                    match nested {
+                        Some(NestedField { member, .. }) => quote! {
+                            Self::#ident { #member: __nested, .. } => ::std::option::Option::Some(
+                                ::std::rc::Rc::new(::std::clone::Clone::clone(__nested)),
+                            )
+                        },
+                        None => quote! { Self::#ident { .. } => ::std::option::Option::None },
+                    }
+                });
+
+        quote! {
+            fn nested_route(&self) -> ::std::option::Option<::std::rc::Rc<dyn ::std::any::Any>> {
+                match self {
+                    #(#nested_route_matches),*,
+                }
+            }
+        }
+    }
 }
 
 pub fn routable_derive_impl(input: Routable) -> TokenStream {
     let Routable {
         ats,
+        nested,
         not_found_route,
         ident,
         ..
@@ -291,6 +469,15 @@ pub fn routable_derive_impl(input: Routable) -> TokenStream {
 
     let from_path = input.build_from_path();
     let to_path = input.build_to_path();
+    let nested_route = input.build_nested_route();
+
+    let routes = ats.iter().zip(nested).map(|(at, nested)| match nested {
+        Some(_) => {
+            let nested_at = nested_at(at);
+            quote! { #at, #nested_at }
+        }
+        None => quote! { #at },
+    });
 
     let maybe_not_found_route = match not_found_route {
         Some(route) => quote! { ::std::option::Option::Some(Self::#route) },
@@ -315,9 +502,10 @@ pub fn routable_derive_impl(input: Routable) -> TokenStream {
         impl ::yew_router::Routable for #ident {
             #from_path
             #to_path
+            #nested_route
 
             fn routes() -> ::std::vec::Vec<&'static str> {
-                ::std::vec![#(#ats),*]
+                ::std::vec![#(#routes),*]
             }
 
             fn not_found_route() -> ::std::option::Option<Self> {
@@ -325,10 +513,16 @@ pub fn routable_derive_impl(input: Routable) -> TokenStream {
             }
 
             fn recognize(pathname: &str) -> ::std::option::Option<Self> {
+                Self::recognize_with_query(pathname, "")
+            }
+
+            fn recognize_with_query(pathname: &str, query: &str) -> ::std::option::Option<Self> {
                 ::std::thread_local! {
                     static ROUTER: ::yew_router::__macro::Router = ::yew_router::__macro::build_router::<#ident>();
                 }
-                ROUTER.with(|router| ::yew_router::__macro::recognize_with_router(router, pathname))
+                ROUTER.with(|router| {
+                    ::yew_router::__macro::recognize_with_router(router, pathname, query)
+                })
             }
         }
 
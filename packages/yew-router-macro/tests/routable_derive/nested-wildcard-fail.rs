@@ -0,0 +1,19 @@
+#[derive(yew_router::Routable, Debug, Clone, PartialEq)]
+enum Routes {
+    #[at("/")]
+    Home,
+    #[at("/files/{*path}")]
+    Files {
+        path: String,
+        #[nested]
+        nested: FileRoutes,
+    },
+}
+
+#[derive(yew_router::Routable, Debug, Clone, PartialEq)]
+enum FileRoutes {
+    #[at("/")]
+    View,
+}
+
+fn main() {}
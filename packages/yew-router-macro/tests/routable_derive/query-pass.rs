@@ -0,0 +1,36 @@
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+struct Query {
+    q: String,
+}
+
+#[derive(Debug, PartialEq, Clone, yew_router::Routable)]
+enum Routes {
+    #[at("/")]
+    Home,
+    #[at("/search")]
+    Search {
+        #[query]
+        query: Query,
+    },
+    #[at("/posts/{id}")]
+    Post {
+        id: u32,
+        #[query]
+        query: Query,
+    },
+    #[at("/nested")]
+    Nested(#[nested] NestedRoutes),
+}
+
+#[derive(Debug, PartialEq, Clone, yew_router::Routable)]
+enum NestedRoutes {
+    #[at("/")]
+    Home,
+    #[at("/search")]
+    Search {
+        #[query]
+        query: Query,
+    },
+}
+
+fn main() {}
@@ -21,4 +21,18 @@ enum MoreRoutes {
     CatchAll { all: ::std::string::String },
 }
 
+#[derive(Debug, PartialEq, Clone, ::yew_router::Routable)]
+enum NestedRoutes {
+    #[at("/")]
+    Home,
+    #[at("/more")]
+    More(#[nested] MoreRoutes),
+    #[at("/users/{id}/")]
+    User {
+        id: u32,
+        #[nested]
+        routes: Routes,
+    },
+}
+
 fn main() {}
@@ -0,0 +1,237 @@
+//! Blocking navigations, e.g. to ask for confirmation before leaving a page with unsaved
+//! changes.
+//!
+//! See [`use_blocker`](crate::hooks::use_blocker).
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::history::{AnyHistory, History};
+
+/// A navigation between two locations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transition {
+    /// The path of the current location, without the basename.
+    pub current: String,
+    /// The path of the next location, without the basename.
+    pub next: String,
+}
+
+/// The state of a [`Blocker`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockerState {
+    /// No navigation is blocked.
+    Unblocked,
+    /// A navigation is blocked until it is resumed with [`Blocker::proceed`] or cancelled with
+    /// [`Blocker::reset`].
+    Blocked(Transition),
+}
+
+/// A handle returned by [`use_blocker`](crate::hooks::use_blocker).
+#[derive(Clone, PartialEq)]
+pub struct Blocker {
+    pub(crate) state: BlockerState,
+    pub(crate) setter: UseStateSetter<BlockerState>,
+    pub(crate) blockers: Option<Blockers>,
+}
+
+impl Blocker {
+    /// Returns the state of this blocker.
+    pub fn state(&self) -> &BlockerState {
+        &self.state
+    }
+
+    /// Returns whether a navigation is blocked.
+    pub fn is_blocked(&self) -> bool {
+        matches!(self.state, BlockerState::Blocked(_))
+    }
+
+    /// Resumes the blocked navigation, if any.
+    pub fn proceed(&self) {
+        self.setter.set(BlockerState::Unblocked);
+        if let Some(blockers) = &self.blockers {
+            blockers.proceed();
+        }
+    }
+
+    /// Cancels the blocked navigation, if any.
+    pub fn reset(&self) {
+        self.setter.set(BlockerState::Unblocked);
+        if let Some(blockers) = &self.blockers {
+            blockers.reset();
+        }
+    }
+}
+
+impl fmt::Debug for Blocker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Blocker")
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+/// How the next back or forward navigation is handled.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum PopState {
+    /// Blocked if a blocker asks for it.
+    #[default]
+    Check,
+    /// Resumed by [`Blocker::proceed`].
+    Bypass,
+    /// Returning to the current location after a blocked navigation.
+    Ignore,
+}
+
+pub(crate) type Predicate = Rc<RefCell<Option<Rc<dyn Fn(&Transition) -> bool>>>>;
+
+/// The blockers registered in a router, and the session history they watch.
+#[derive(Clone, Default)]
+pub(crate) struct Blockers {
+    inner: Rc<RefCell<Inner>>,
+}
+
+#[derive(Default)]
+struct Inner {
+    next_id: usize,
+    blockers: Vec<(usize, Predicate, UseStateSetter<BlockerState>)>,
+    /// Resumes the blocked navigation.
+    pending: Option<Box<dyn FnOnce()>>,
+    pop: PopState,
+    /// The path of the current location, without the basename.
+    current: String,
+}
+
+impl Blockers {
+    pub(crate) fn register(
+        &self,
+        predicate: Predicate,
+        setter: UseStateSetter<BlockerState>,
+    ) -> usize {
+        let mut inner = self.inner.borrow_mut();
+        let id = inner.next_id;
+        inner.next_id += 1;
+        inner.blockers.push((id, predicate, setter));
+        id
+    }
+
+    pub(crate) fn unregister(&self, id: usize) {
+        self.inner
+            .borrow_mut()
+            .blockers
+            .retain(|(blocker_id, ..)| *blocker_id != id);
+    }
+
+    /// Starts watching the session history from the location whose path without the basename
+    /// is `path`.
+    pub(crate) fn reset_history(&self, path: String) {
+        let mut inner = self.inner.borrow_mut();
+        inner.current = path;
+        inner.pop = PopState::Check;
+        inner.pending = None;
+    }
+
+    /// Returns the state setter of the first blocker blocking `transition`.
+    fn blocker_for(&self, transition: &Transition) -> Option<UseStateSetter<BlockerState>> {
+        let blockers = self.inner.borrow().blockers.clone();
+        // The predicates are called without borrowing the blockers, as they may navigate.
+        blockers.into_iter().find_map(|(_, predicate, setter)| {
+            let predicate = predicate.borrow().clone()?;
+            predicate(transition).then_some(setter)
+        })
+    }
+
+    /// Navigates to `next` with `f`, unless a blocker blocks it.
+    pub(crate) fn navigate(&self, next: String, f: impl FnOnce() + 'static) {
+        let transition = Transition {
+            current: self.inner.borrow().current.clone(),
+            next,
+        };
+        match self.blocker_for(&transition) {
+            Some(setter) => {
+                self.inner.borrow_mut().pending = Some(Box::new(f));
+                setter.set(BlockerState::Blocked(transition));
+            }
+            None => f(),
+        }
+    }
+
+    fn proceed(&self) {
+        let pending = self.inner.borrow_mut().pending.take();
+        if let Some(pending) = pending {
+            pending();
+        }
+    }
+
+    fn reset(&self) {
+        self.inner.borrow_mut().pending = None;
+    }
+
+    /// Records the change of the location of `history` to the location whose path without the
+    /// basename is `path`, `delta` entries away from the current entry for back and forward
+    /// navigations, or `None` for the navigations of the navigator.
+    ///
+    /// Back and forward navigations are blocked by returning to the previous location, in which
+    /// case `false` is returned and the location must not be rendered.
+    pub(crate) fn location_changed(
+        &self,
+        history: &AnyHistory,
+        delta: Option<isize>,
+        path: String,
+    ) -> bool {
+        let mut inner = self.inner.borrow_mut();
+        if let Some(delta) = delta {
+            let pop = std::mem::take(&mut inner.pop);
+            if pop == PopState::Ignore {
+                return false;
+            }
+
+            // Going to the same entry can't be reverted.
+            if pop == PopState::Check && delta != 0 {
+                let transition = Transition {
+                    current: inner.current.clone(),
+                    next: path.clone(),
+                };
+                drop(inner);
+                if let Some(setter) = self.blocker_for(&transition) {
+                    let mut inner = self.inner.borrow_mut();
+                    inner.pop = PopState::Ignore;
+                    inner.pending = Some(Box::new({
+                        let this = self.clone();
+                        let history = history.clone();
+                        move || {
+                            this.inner.borrow_mut().pop = PopState::Bypass;
+                            history.go(delta);
+                        }
+                    }));
+                    drop(inner);
+
+                    let history = history.clone();
+                    yew::platform::spawn_local(async move { history.go(-delta) });
+                    setter.set(BlockerState::Blocked(transition));
+                    return false;
+                }
+                inner = self.inner.borrow_mut();
+            }
+        }
+        inner.current = path;
+        true
+    }
+}
+
+impl fmt::Debug for Blockers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Blockers")
+            .field("blockers", &self.inner.borrow().blockers.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for Blockers {
+    fn eq(&self, rhs: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &rhs.inner)
+    }
+}
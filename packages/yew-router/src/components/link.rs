@@ -3,6 +3,7 @@ use wasm_bindgen::UnwrapThrowExt;
 use yew::prelude::*;
 use yew::virtual_dom::AttrValue;
 
+use crate::loader::Loaders;
 use crate::navigator::NavigatorKind;
 use crate::prelude::*;
 use crate::{Routable, utils};
@@ -21,6 +22,8 @@ where
     /// Route that will be pushed when the anchor is clicked.
     pub to: R,
     /// Route query data
+    ///
+    /// The query of a route with a `#[query]` field is kept and joined with it.
     #[prop_or_default]
     pub query: Option<Q>,
     /// Route state data
@@ -36,6 +39,9 @@ where
 }
 
 /// A wrapper around `<a>` tag to be used with [`Router`](crate::Router)
+///
+/// Hovering the link starts loading the data of the route, if it is rendered by a
+/// [`Switch`](crate::Switch) with a [`Loader`](crate::loader::Loader).
 #[component]
 pub fn Link<R, Q = (), S = ()>(props: &LinkProps<R, Q, S>) -> Html
 where
@@ -54,6 +60,16 @@ where
     } = props.clone();
 
     let navigator = use_navigator().expect_throw("failed to get navigator");
+    let loaders = use_context::<Loaders>();
+
+    let onmouseenter = {
+        let to = to.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(loaders) = &loaders {
+                loaders.prefetch(&to);
+            }
+        })
+    };
 
     let onclick = {
         let navigator = navigator.clone();
@@ -92,7 +108,10 @@ where
         let pathname = navigator.prefix_basename(&route_s);
         let mut path = query
             .and_then(|query| serde_urlencoded::to_string(query).ok())
-            .and_then(|query| utils::compose_path(&pathname, &query))
+            .and_then(|query| {
+                let (pathname, query) = utils::merge_query(&pathname, &query);
+                utils::compose_path(pathname, &query)
+            })
             .unwrap_or_else(|| pathname.into_owned());
 
         if navigator.kind() == NavigatorKind::Hash {
@@ -106,6 +125,7 @@ where
         <a class={classes}
             {href}
             {onclick}
+            {onmouseenter}
             {disabled}
             ref={anchor_ref}
         >
@@ -1,6 +1,10 @@
 //! Components to interface with [Router][crate::Router].
 
 mod link;
+mod outlet;
 mod redirect;
+mod scroll_restoration;
 pub use link::*;
+pub use outlet::*;
 pub use redirect::*;
+pub use scroll_restoration::*;
@@ -0,0 +1,107 @@
+use std::any::Any;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::Routable;
+
+/// The route nested in the route rendered by the closest [`Switch`](crate::Switch) or
+/// [`Outlet`].
+#[derive(Clone)]
+pub(crate) struct OutletContext {
+    route: Option<Rc<dyn Any>>,
+    // The path of the parent route, which includes the path of the nested route.
+    path: String,
+}
+
+impl OutletContext {
+    pub(crate) fn new<R: Routable>(route: &R) -> Self {
+        Self {
+            route: route.nested_route(),
+            path: route.to_path(),
+        }
+    }
+}
+
+impl PartialEq for OutletContext {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.path == rhs.path
+    }
+}
+
+/// Props for [`Outlet`]
+#[derive(Properties, PartialEq, Clone)]
+pub struct OutletProps<R>
+where
+    R: Routable,
+{
+    /// Callback which returns [`Html`] to be rendered for the nested route.
+    pub render: Callback<R, Html>,
+}
+
+/// Renders the route held by the `#[nested]` field of the route rendered by the closest
+/// [`Switch`](crate::Switch) or `Outlet`.
+///
+/// Place it in the layout rendered for the parent route. The layout stays mounted while the
+/// nested route changes.
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew_router::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Routable)]
+/// enum Route {
+///     #[at("/")]
+///     Home,
+///     #[at("/settings")]
+///     Settings(#[nested] SettingsRoute),
+/// }
+///
+/// #[derive(Clone, PartialEq, Routable)]
+/// enum SettingsRoute {
+///     #[at("/")]
+///     Profile,
+///     #[at("/theme")]
+///     Theme,
+/// }
+///
+/// fn switch(route: Route) -> Html {
+///     match route {
+///         Route::Home => html! { <h1>{ "Home" }</h1> },
+///         Route::Settings(_) => html! {
+///             <>
+///                 <h1>{ "Settings" }</h1>
+///                 <Outlet<SettingsRoute> render={switch_settings} />
+///             </>
+///         },
+///     }
+/// }
+///
+/// fn switch_settings(route: SettingsRoute) -> Html {
+///     match route {
+///         SettingsRoute::Profile => html! { <h2>{ "Profile" }</h2> },
+///         SettingsRoute::Theme => html! { <h2>{ "Theme" }</h2> },
+///     }
+/// }
+/// ```
+#[component]
+pub fn Outlet<R>(props: &OutletProps<R>) -> Html
+where
+    R: Routable + 'static,
+{
+    let route = use_context::<OutletContext>()
+        .and_then(|ctx| ctx.route)
+        .and_then(|route| route.downcast_ref::<R>().cloned());
+
+    match route {
+        Some(route) => html! {
+            <ContextProvider<OutletContext> context={OutletContext::new(&route)}>
+                { props.render.emit(route) }
+            </ContextProvider<OutletContext>>
+        },
+        None => {
+            tracing::warn!("no nested route matched");
+            Html::default()
+        }
+    }
+}
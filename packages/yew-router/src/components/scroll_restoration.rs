@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use gloo::events::EventListener;
+use yew::prelude::*;
+
+use crate::entries::{Entry, HistoryEntries};
+use crate::history::Location;
+use crate::router::LocationContext;
+
+/// Returns the element targeted by the hash of `location`, if any.
+fn fragment_element(location: &Location) -> Option<web_sys::Element> {
+    let fragment = location.hash().strip_prefix('#')?;
+    if fragment.is_empty() {
+        return None;
+    }
+    let document = gloo::utils::document();
+    document.get_element_by_id(fragment).or_else(|| {
+        let fragment = urlencoding::decode(fragment).ok()?;
+        document.get_element_by_id(&fragment)
+    })
+}
+
+fn scroll_position() -> (f64, f64) {
+    let window = gloo::utils::window();
+    (
+        window.scroll_x().unwrap_or_default(),
+        window.scroll_y().unwrap_or_default(),
+    )
+}
+
+fn storage_key(entry: &Entry) -> String {
+    format!("yew-router-scroll:{}", entry.key)
+}
+
+/// The scroll positions of the session history entries, kept in `sessionStorage` so they are
+/// restored when the page is reloaded.
+#[derive(Default)]
+struct Positions(HashMap<String, (f64, f64)>);
+
+impl Positions {
+    fn save(&mut self, entry: &Entry) {
+        let (x, y) = scroll_position();
+        self.0.insert(entry.key.clone(), (x, y));
+        if let Ok(Some(storage)) = gloo::utils::window().session_storage() {
+            // Fails if the storage is full, in which case the position is only kept in memory.
+            let _ = storage.set_item(&storage_key(entry), &format!("{x},{y}"));
+        }
+    }
+
+    fn get(&self, entry: &Entry) -> Option<(f64, f64)> {
+        if let Some(&position) = self.0.get(&entry.key) {
+            return Some(position);
+        }
+        let storage = gloo::utils::window().session_storage().ok()??;
+        let position = storage.get_item(&storage_key(entry)).ok()??;
+        let (x, y) = position.split_once(',')?;
+        Some((x.parse().ok()?, y.parse().ok()?))
+    }
+}
+
+/// Restores the scroll position of the window when navigating.
+///
+/// When rendered in a [`Router`](crate::Router), the scroll position of each session history
+/// entry is saved when it is left, and:
+///
+/// - restored on back and forward navigations, and when the page is reloaded,
+/// - reset to the top of the page on other navigations,
+/// - moved to the element targeted by the hash of the location, if any, unless a position is
+///   restored.
+///
+/// The browser's own scroll restoration is disabled while it is rendered.
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew_router::prelude::*;
+///
+/// #[component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <BrowserRouter>
+///             <ScrollRestoration />
+///             // ...
+///         </BrowserRouter>
+///     }
+/// }
+/// ```
+#[component(ScrollRestoration)]
+pub fn scroll_restoration() -> Html {
+    let entries = use_context::<HistoryEntries>();
+    let location = use_context::<LocationContext>();
+    let positions = use_mut_ref(Positions::default);
+
+    {
+        let positions = positions.clone();
+        use_effect_with(entries.clone(), move |entries| {
+            let entries = entries.clone();
+            let window = gloo::utils::window();
+            let history = window.history().ok();
+            if let Some(history) = &history {
+                let _ = history.set_scroll_restoration(web_sys::ScrollRestoration::Manual);
+            }
+
+            // The position is saved when the entry is left, before the next one is rendered.
+            let listener = entries.as_ref().map(|entries| {
+                let positions = positions.clone();
+                entries.listen(move |entry| positions.borrow_mut().save(entry))
+            });
+            let pagehide = EventListener::new(&window, "pagehide", {
+                let entries = entries.clone();
+                move |_| {
+                    if let Some((entry, _)) = entries.as_ref().and_then(|m| m.current()) {
+                        positions.borrow_mut().save(&entry);
+                    }
+                }
+            });
+
+            move || {
+                if let (Some(entries), Some(listener)) = (entries, listener) {
+                    entries.unlisten(listener);
+                }
+                drop(pagehide);
+                if let Some(history) = history {
+                    let _ = history.set_scroll_restoration(web_sys::ScrollRestoration::Auto);
+                }
+            }
+        });
+    }
+
+    use_effect_with(location, move |location| {
+        let (Some(entries), Some(location)) = (entries, location) else {
+            return;
+        };
+        let Some((entry, action)) = entries.current() else {
+            return;
+        };
+
+        let window = gloo::utils::window();
+        // Back and forward navigations, and the first render, restore the saved position.
+        if action.is_none() {
+            if let Some((x, y)) = positions.borrow().get(&entry) {
+                window.scroll_to_with_x_and_y(x, y);
+                return;
+            }
+        }
+
+        if let Some(element) = fragment_element(&location.location()) {
+            element.scroll_into_view();
+        } else if action.is_some() {
+            window.scroll_to_with_x_and_y(0.0, 0.0);
+        }
+    });
+
+    Html::default()
+}
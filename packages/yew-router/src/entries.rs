@@ -0,0 +1,271 @@
+//! Tracking the session history entries displayed by a router, to tell back and forward
+//! navigations apart and to save the scroll position of the entries.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::rc::Rc;
+
+use wasm_bindgen::JsValue;
+
+use crate::history::{AnyHistory, Location};
+
+/// How a navigation changes the session history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Action {
+    Push,
+    Replace,
+}
+
+/// A session history entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Entry {
+    /// A key unique to the entry, kept when the page is reloaded by the browser and hash
+    /// histories.
+    pub key: String,
+    /// The location of the entry, to tell the entries of a memory history apart.
+    location: String,
+}
+
+type Listener = Rc<dyn Fn(&Entry)>;
+
+/// The session history entries visited since a router was created.
+///
+/// Provided as a context by the router.
+#[derive(Clone, Default)]
+pub(crate) struct HistoryEntries {
+    inner: Rc<RefCell<Inner>>,
+}
+
+#[derive(Default)]
+struct Inner {
+    /// The visited entries, by their index in the session history.
+    entries: BTreeMap<isize, Entry>,
+    /// The index of the current entry. The entries visited before the router was created have
+    /// negative indices.
+    index: isize,
+    /// The navigation made by the [`Navigator`](crate::navigator::Navigator), if any.
+    action: Option<Action>,
+    /// The navigation which led to the current entry, `None` for back and forward navigations.
+    last: Option<Action>,
+    next_listener_id: usize,
+    /// Called with the entry being left, before the next one is rendered.
+    listeners: Vec<(usize, Listener)>,
+}
+
+fn location_key(location: &Location) -> String {
+    format!(
+        "{}{}{}",
+        location.path(),
+        location.query_str(),
+        location.hash()
+    )
+}
+
+fn new_key() -> String {
+    format!(
+        "{:08x}",
+        (js_sys::Math::random() * f64::from(u32::MAX)) as u32
+    )
+}
+
+/// The properties of `history.state` holding the index and the key of the entry.
+const INDEX_KEY: &str = "__yew_router_index";
+const KEY_KEY: &str = "__yew_router_key";
+
+/// Returns the index and the key stored in the state of the current entry of `history` by
+/// [`store_entry`].
+///
+/// Only the browser and hash histories store them, in `window.history`.
+fn stored_entry(history: &AnyHistory) -> Option<(isize, String)> {
+    if matches!(history, AnyHistory::Memory(_)) {
+        return None;
+    }
+    let state = gloo::utils::window().history().ok()?.state().ok()?;
+    let index = js_sys::Reflect::get(&state, &JsValue::from_str(INDEX_KEY)).ok()?;
+    let key = js_sys::Reflect::get(&state, &JsValue::from_str(KEY_KEY)).ok()?;
+    Some((index.as_f64()? as isize, key.as_string()?))
+}
+
+/// Stores the index and the key of the current entry of `history` in its state, alongside the
+/// state of the [`Location`].
+fn store_entry(history: &AnyHistory, index: isize, key: &str) {
+    if matches!(history, AnyHistory::Memory(_)) {
+        return;
+    }
+    let Ok(browser_history) = gloo::utils::window().history() else {
+        return;
+    };
+    let state = match browser_history.state() {
+        Ok(state) if state.is_object() => state,
+        Ok(state) if state.is_null() || state.is_undefined() => js_sys::Object::new().into(),
+        // Other states are left untouched, and the entry is told apart by its location.
+        _ => return,
+    };
+    let stored = js_sys::Reflect::set(
+        &state,
+        &JsValue::from_str(INDEX_KEY),
+        &JsValue::from_f64(index as f64),
+    )
+    .and_then(|_| js_sys::Reflect::set(&state, &JsValue::from_str(KEY_KEY), &key.into()));
+    if stored.is_ok() {
+        let _ = browser_history.replace_state(&state, "");
+    }
+}
+
+impl HistoryEntries {
+    /// Navigates with `f`, recording the change of the session history it makes.
+    pub(crate) fn run<T>(&self, action: Action, f: impl FnOnce() -> T) -> T {
+        self.inner.borrow_mut().action = Some(action);
+        let result = f();
+        self.inner.borrow_mut().action = None;
+        result
+    }
+
+    /// Returns the navigation being made by the navigator, `None` for back and forward
+    /// navigations.
+    pub(crate) fn action(&self) -> Option<Action> {
+        self.inner.borrow().action
+    }
+
+    /// Starts watching the session history of `history` from `location`.
+    pub(crate) fn reset(&self, history: &AnyHistory, location: &Location) {
+        // The entry is kept when the page is reloaded.
+        let (index, key) = stored_entry(history).unwrap_or_else(|| {
+            let key = new_key();
+            store_entry(history, 0, &key);
+            (0, key)
+        });
+        let mut inner = self.inner.borrow_mut();
+        inner.entries = BTreeMap::from([(
+            index,
+            Entry {
+                key,
+                location: location_key(location),
+            },
+        )]);
+        inner.index = index;
+        inner.last = None;
+    }
+
+    /// Returns the number of entries between the current entry and the entry of `location`,
+    /// reached by a back or forward navigation.
+    pub(crate) fn delta(&self, history: &AnyHistory, location: &Location) -> isize {
+        let inner = self.inner.borrow();
+        match stored_entry(history) {
+            Some((index, _)) => index - inner.index,
+            // The entries of a memory history, or pushed without the router, are told apart by
+            // their location. Unknown entries were visited before the router was created, most
+            // likely by going back.
+            None => {
+                let location = location_key(location);
+                if inner
+                    .entries
+                    .get(&(inner.index + 1))
+                    .is_some_and(|entry| entry.location == location)
+                {
+                    1
+                } else {
+                    -1
+                }
+            }
+        }
+    }
+
+    /// Records the change of the location of `history` to `location`, once it is rendered.
+    pub(crate) fn location_changed(&self, history: &AnyHistory, location: &Location) {
+        let (left, listeners) = {
+            let inner = self.inner.borrow();
+            let left = inner.entries.get(&inner.index).cloned();
+            let listeners: Vec<_> = inner.listeners.iter().map(|(_, m)| m.clone()).collect();
+            (left, listeners)
+        };
+        // The listeners are called without borrowing the entries, as they may navigate.
+        if let Some(left) = left {
+            for listener in listeners {
+                listener(&left);
+            }
+        }
+
+        let action = self.action();
+        let stored = match action {
+            Some(_) => None,
+            None => stored_entry(history),
+        };
+        let delta = self.delta(history, location);
+        let mut inner = self.inner.borrow_mut();
+        let (index, key) = match (action, stored) {
+            (Some(Action::Push), _) => {
+                let index = inner.index + 1;
+                inner.entries.retain(|&m, _| m < index);
+                (index, new_key())
+            }
+            (Some(Action::Replace), _) => (inner.index, new_key()),
+            (None, Some(stored)) => stored,
+            (None, None) => {
+                let index = inner.index + delta;
+                let key = match inner.entries.get(&index) {
+                    Some(entry) => entry.key.clone(),
+                    None => new_key(),
+                };
+                // Recognized by their index from now on.
+                store_entry(history, index, &key);
+                (index, key)
+            }
+        };
+        if action.is_some() {
+            store_entry(history, index, &key);
+        }
+        inner.entries.insert(
+            index,
+            Entry {
+                key,
+                location: location_key(location),
+            },
+        );
+        inner.index = index;
+        inner.last = action;
+    }
+
+    /// Returns the current entry, and the navigation which led to it.
+    ///
+    /// Returns `None` until the router starts watching the session history.
+    pub(crate) fn current(&self) -> Option<(Entry, Option<Action>)> {
+        let inner = self.inner.borrow();
+        let entry = inner.entries.get(&inner.index)?;
+        Some((entry.clone(), inner.last))
+    }
+
+    /// Calls `listener` with the current entry when it is left, before the next entry is
+    /// rendered.
+    pub(crate) fn listen(&self, listener: impl Fn(&Entry) + 'static) -> usize {
+        let mut inner = self.inner.borrow_mut();
+        let id = inner.next_listener_id;
+        inner.next_listener_id += 1;
+        inner.listeners.push((id, Rc::new(listener)));
+        id
+    }
+
+    pub(crate) fn unlisten(&self, id: usize) {
+        self.inner
+            .borrow_mut()
+            .listeners
+            .retain(|(listener_id, _)| *listener_id != id);
+    }
+}
+
+impl fmt::Debug for HistoryEntries {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inner = self.inner.borrow();
+        f.debug_struct("HistoryEntries")
+            .field("index", &inner.index)
+            .field("entries", &inner.entries)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for HistoryEntries {
+    fn eq(&self, rhs: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &rhs.inner)
+    }
+}
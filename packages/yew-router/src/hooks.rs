@@ -1,8 +1,13 @@
 //! Hooks to access router state and navigate between pages.
 
+use std::rc::Rc;
+
 use yew::prelude::*;
+use yew::suspense::SuspensionResult;
 
+use crate::blocker::{Blocker, BlockerState, Transition};
 use crate::history::*;
+use crate::loader::{Loader, Loaders};
 use crate::navigator::Navigator;
 use crate::routable::Routable;
 use crate::router::{LocationContext, NavigatorContext};
@@ -22,6 +27,8 @@ pub fn use_location() -> Option<Location> {
 /// A hook to access the current route.
 ///
 /// This hook will return [`None`] if there's no available location or none of the routes match.
+/// The query string of the location is deserialized into the `#[query]` field of the route, if
+/// any.
 ///
 /// # Note
 ///
@@ -36,5 +43,181 @@ where
     let location = use_location()?;
     let path = navigator.strip_basename(location.path().into());
 
-    R::recognize(&path)
+    R::recognize_with_query(&path, location.query_str())
+}
+
+/// A hook to block navigations, e.g. to ask for confirmation before leaving a page with unsaved
+/// changes.
+///
+/// `predicate` is called with every navigation made with the [`Navigator`], including the ones
+/// of [`Link`](crate::components::Link)s, and with every back and forward navigation. The
+/// navigation is blocked if it returns `true`, until it is resumed with [`Blocker::proceed`] or
+/// cancelled with [`Blocker::reset`].
+///
+/// Navigations leaving the application, e.g. reloading the page, are not blocked.
+///
+/// # Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew_router::prelude::*;
+///
+/// #[component]
+/// fn Editor() -> Html {
+///     let dirty = use_state(|| false);
+///     let blocker = use_blocker({
+///         let dirty = *dirty;
+///         move |transition: &Transition| dirty && transition.current != transition.next
+///     });
+///
+///     let prompt = blocker.is_blocked().then(|| {
+///         let proceed = {
+///             let blocker = blocker.clone();
+///             Callback::from(move |_| blocker.proceed())
+///         };
+///         let reset = Callback::from(move |_| blocker.reset());
+///         html! {
+///             <div>
+///                 { "Discard your changes?" }
+///                 <button onclick={proceed}>{ "Leave" }</button>
+///                 <button onclick={reset}>{ "Stay" }</button>
+///             </div>
+///         }
+///     });
+///
+///     let oninput = Callback::from(move |_| dirty.set(true));
+///     html! {
+///         <>
+///             <textarea {oninput} />
+///             { for prompt }
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_blocker<F>(predicate: F) -> Blocker
+where
+    F: Fn(&Transition) -> bool + 'static,
+{
+    let navigator = use_navigator();
+    let state = use_state(|| BlockerState::Unblocked);
+
+    let current = use_mut_ref(|| None);
+    *current.borrow_mut() = Some(Rc::new(predicate) as Rc<dyn Fn(&Transition) -> bool>);
+
+    {
+        let setter = state.setter();
+        use_effect_with(navigator.clone(), move |navigator| {
+            let registration = navigator.as_ref().map(|navigator| {
+                let blockers = navigator.blockers().clone();
+                let id = blockers.register(current, setter);
+                (blockers, id)
+            });
+
+            move || {
+                if let Some((blockers, id)) = registration {
+                    blockers.unregister(id);
+                }
+            }
+        });
+    }
+
+    Blocker {
+        state: (*state).clone(),
+        setter: state.setter(),
+        blockers: navigator.map(|m| m.blockers().clone()),
+    }
+}
+
+/// A hook to access the data of the current route, loaded by its [`Loader`].
+///
+/// It suspends the component until the data is loaded, so it must be rendered in a
+/// [`Suspense`](yew::suspense::Suspense). The loader is started by the [`Switch`](crate::Switch)
+/// rendering the route if it has a `loader`, or by this hook otherwise.
+///
+/// During server-side rendering, the data is loaded by this hook and sent to the client side, so
+/// it is not loaded again during hydration.
+///
+/// # Panics
+///
+/// Panics if the current location doesn't match a route of type `R`.
+///
+/// # Example
+///
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// use yew::prelude::*;
+/// use yew_router::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Routable)]
+/// enum Route {
+///     #[at("/posts/{id}")]
+///     Post { id: u32 },
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Post {
+///     title: String,
+/// }
+///
+/// impl Loader for Route {
+///     type Data = Post;
+///
+///     async fn load(self) -> Post {
+///         let Route::Post { id } = self;
+///         Post {
+///             title: format!("Post {id}"),
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn PostPage() -> HtmlResult {
+///     let post = use_loader_data::<Route>()?;
+///
+///     Ok(html! { <h1>{ &post.title }</h1> })
+/// }
+///
+/// fn switch(_: Route) -> Html {
+///     html! {
+///         <Suspense fallback={html! { { "Loading..." } }}>
+///             <PostPage />
+///         </Suspense>
+///     }
+/// }
+///
+/// #[component]
+/// fn App() -> Html {
+///     html! {
+///         <BrowserRouter>
+///             <Switch<Route> render={switch} loader={Route::loader()} />
+///         </BrowserRouter>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_loader_data<R>() -> SuspensionResult<Rc<R::Data>>
+where
+    R: Loader,
+{
+    let loaders = use_context::<Loaders>().expect("use_loader_data requires a Router");
+    let route = use_route::<R>().expect("use_loader_data requires a matched route");
+    let path = route.to_path();
+
+    let prepared = use_prepared_state!(path.clone(), async move |path| -> R::Data {
+        let (path, query) = path.split_once('?').unwrap_or((path.as_str(), ""));
+        R::recognize_with_query(path, query)
+            .expect("failed to recognize the route")
+            .load()
+            .await
+    })?;
+    if let Some(data) = prepared {
+        loaders.insert::<R>(path, data.clone());
+        return Ok(data);
+    }
+
+    let data = loaders.load(&R::loader(), &route)?;
+    Ok(data
+        .downcast::<R::Data>()
+        .expect("the data of a route has the type of its loader"))
 }
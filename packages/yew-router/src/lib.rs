@@ -68,8 +68,11 @@ extern crate self as yew_router;
 #[doc(hidden)]
 #[path = "macro_helpers.rs"]
 pub mod __macro;
+pub mod blocker;
 pub mod components;
+mod entries;
 pub mod hooks;
+pub mod loader;
 pub mod navigator;
 mod routable;
 pub mod router;
@@ -103,10 +106,13 @@ pub mod prelude {
 
     #[doc(no_inline)]
     pub use crate::Routable;
-    pub use crate::components::{Link, Redirect};
+    pub use crate::blocker::{Blocker, BlockerState, Transition};
+    pub use crate::components::{Link, Outlet, Redirect, ScrollRestoration};
     pub use crate::history::Location;
     pub use crate::hooks::*;
+    pub use crate::loader::{Loader, RouteLoader};
     pub use crate::navigator::{NavigationError, NavigationResult, Navigator};
     pub use crate::scope_ext::{LocationHandle, NavigatorHandle, RouterScopeExt};
+    pub use crate::switch::GuardResult;
     pub use crate::{BrowserRouter, HashRouter, Router, Switch};
 }
@@ -0,0 +1,254 @@
+//! Loading the data of routes before they are rendered.
+//!
+//! See [`Loader`] and [`use_loader_data`](crate::hooks::use_loader_data).
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use yew::suspense::Suspension;
+
+use crate::Routable;
+
+/// Loads the data of the routes of a [`Routable`].
+///
+/// The loader of the route matched by a [`Switch`](crate::Switch) with a `loader` is started as
+/// soon as the route is matched, and when a [`Link`](crate::components::Link) to the route is
+/// hovered. The data is read with [`use_loader_data`](crate::hooks::use_loader_data).
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use yew_router::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Routable)]
+/// enum Route {
+///     #[at("/")]
+///     Home,
+///     #[at("/posts/{id}")]
+///     Post { id: u32 },
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// enum RouteData {
+///     Home,
+///     Post { title: String },
+/// }
+///
+/// impl Loader for Route {
+///     type Data = RouteData;
+///
+///     async fn load(self) -> RouteData {
+///         match self {
+///             Route::Home => RouteData::Home,
+///             Route::Post { id } => RouteData::Post {
+///                 title: format!("Post {id}"),
+///             },
+///         }
+///     }
+/// }
+/// ```
+pub trait Loader: Routable + 'static {
+    /// The data of the routes.
+    ///
+    /// It is serialized during server-side rendering and sent to the client side for hydration.
+    type Data: Serialize + DeserializeOwned + 'static;
+
+    /// Loads the data of the route.
+    fn load(self) -> impl Future<Output = Self::Data>;
+
+    /// Returns the loader to pass to a [`Switch`](crate::Switch).
+    fn loader() -> RouteLoader<Self> {
+        RouteLoader {
+            load: Rc::new(|route: &Self| {
+                let load = route.clone().load();
+                Box::pin(async move { Rc::new(load.await) as Rc<dyn Any> })
+            }),
+        }
+    }
+}
+
+type LoadFuture = Pin<Box<dyn Future<Output = Rc<dyn Any>>>>;
+
+/// The [`Loader`] of the routes of a [`Switch`](crate::Switch), returned by [`Loader::loader`].
+pub struct RouteLoader<R> {
+    load: Rc<dyn Fn(&R) -> LoadFuture>,
+}
+
+impl<R> Clone for RouteLoader<R> {
+    fn clone(&self) -> Self {
+        Self {
+            load: self.load.clone(),
+        }
+    }
+}
+
+impl<R> fmt::Debug for RouteLoader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RouteLoader").finish_non_exhaustive()
+    }
+}
+
+impl<R> PartialEq for RouteLoader<R> {
+    fn eq(&self, _rhs: &Self) -> bool {
+        // A route has a single loader.
+        true
+    }
+}
+
+/// The data of a route, loaded or being loaded.
+struct Entry {
+    data: Rc<RefCell<Option<Rc<dyn Any>>>>,
+    suspension: Suspension,
+}
+
+/// The loaders registered in a router, and the data they loaded.
+#[derive(Clone, Default)]
+pub(crate) struct Loaders {
+    inner: Rc<RefCell<Inner>>,
+}
+
+#[derive(Default)]
+struct Inner {
+    /// The [`RouteLoader`]s by the type of their routes.
+    loaders: HashMap<TypeId, Rc<dyn Any>>,
+    /// The data by the type and the path of their routes.
+    entries: HashMap<(TypeId, String), Entry>,
+    /// The path of the route rendered last by the type of the route.
+    current: HashMap<TypeId, String>,
+}
+
+impl Loaders {
+    /// Registers the loader of the routes of type `R`, to prefetch them.
+    pub(crate) fn register<R>(&self, loader: &RouteLoader<R>)
+    where
+        R: Routable + 'static,
+    {
+        self.inner
+            .borrow_mut()
+            .loaders
+            .entry(TypeId::of::<R>())
+            .or_insert_with(|| Rc::new(loader.clone()) as Rc<dyn Any>);
+    }
+
+    /// Starts loading the data of `route`, unless it is loaded or being loaded.
+    pub(crate) fn start<R>(&self, loader: &RouteLoader<R>, route: &R)
+    where
+        R: Routable + 'static,
+    {
+        let key = (TypeId::of::<R>(), route.to_path());
+        if self.inner.borrow().entries.contains_key(&key) {
+            return;
+        }
+
+        let data = Rc::new(RefCell::new(None));
+        let load = (loader.load)(route);
+        let suspension = {
+            let data = data.clone();
+            Suspension::from_future(async move {
+                *data.borrow_mut() = Some(load.await);
+            })
+        };
+        self.inner
+            .borrow_mut()
+            .entries
+            .insert(key, Entry { data, suspension });
+    }
+
+    /// Starts loading the data of `route` with the registered loader, if any.
+    pub(crate) fn prefetch<R>(&self, route: &R)
+    where
+        R: Routable + 'static,
+    {
+        let loader = self.inner.borrow().loaders.get(&TypeId::of::<R>()).cloned();
+        if let Some(loader) = loader
+            .as_ref()
+            .and_then(|m| m.downcast_ref::<RouteLoader<R>>())
+        {
+            self.start(loader, route);
+        }
+    }
+
+    /// Records that `route` is rendered.
+    ///
+    /// When navigating to another route, the data of the route rendered before is discarded so
+    /// it is loaded again when navigating back to it, and so is the data prefetched for the
+    /// routes that weren't navigated to.
+    ///
+    /// The data of `route` is loaded if `start` is `true`.
+    pub(crate) fn matched<R>(&self, loader: &RouteLoader<R>, route: &R, start: bool)
+    where
+        R: Routable + 'static,
+    {
+        let type_id = TypeId::of::<R>();
+        let path = route.to_path();
+        {
+            let mut inner = self.inner.borrow_mut();
+            let previous = inner.current.insert(type_id, path.clone());
+            if previous.is_some_and(|previous| previous != path) {
+                inner.entries.retain(|(entry_type_id, entry_path), _| {
+                    *entry_type_id != type_id || *entry_path == path
+                });
+            }
+        }
+
+        if start {
+            self.start(loader, route);
+        }
+    }
+
+    /// Stores the data of the route of type `R` at `path`, e.g. prepared during server-side
+    /// rendering.
+    pub(crate) fn insert<R>(&self, path: String, data: Rc<dyn Any>)
+    where
+        R: Routable + 'static,
+    {
+        let (suspension, _handle) = Suspension::new();
+        self.inner
+            .borrow_mut()
+            .entries
+            .entry((TypeId::of::<R>(), path))
+            .or_insert_with(|| Entry {
+                data: Rc::new(RefCell::new(Some(data))),
+                suspension,
+            });
+    }
+
+    /// Returns the data of `route`, or a suspension resumed once it is loaded.
+    ///
+    /// The data is loaded with `loader` if it is not loaded or being loaded.
+    pub(crate) fn load<R>(
+        &self,
+        loader: &RouteLoader<R>,
+        route: &R,
+    ) -> Result<Rc<dyn Any>, Suspension>
+    where
+        R: Routable + 'static,
+    {
+        self.start(loader, route);
+
+        let inner = self.inner.borrow();
+        let entry = &inner.entries[&(TypeId::of::<R>(), route.to_path())];
+        let data = entry.data.borrow().clone();
+        data.ok_or_else(|| entry.suspension.clone())
+    }
+}
+
+impl fmt::Debug for Loaders {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Loaders")
+            .field("loaders", &self.inner.borrow().loaders.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for Loaders {
+    fn eq(&self, rhs: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &rhs.inner)
+    }
+}
@@ -1,3 +1,5 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 pub use urlencoding::{decode as decode_for_url, encode as encode_for_url};
 
 pub fn encode_path_for_url(path: &str) -> String {
@@ -11,6 +13,44 @@ use std::collections::HashMap;
 
 use crate::Routable;
 
+/// Returns the path matched by a `#[nested]` route, relative to its parent route.
+pub fn nested_path(params: &HashMap<&str, &str>) -> String {
+    match params.get("__nested") {
+        Some(rest) => format!("/{rest}"),
+        None => "/".to_string(),
+    }
+}
+
+/// Appends the path of a `#[nested]` route to the path of its parent route.
+pub fn compose_nested_path(parent: String, nested: &str) -> String {
+    match nested.strip_prefix('/') {
+        // The root of the nested route, possibly with a query.
+        Some(rest) if rest.is_empty() || rest.starts_with('?') => format!("{parent}{rest}"),
+        _ => format!("{}{nested}", parent.strip_suffix('/').unwrap_or(&parent)),
+    }
+}
+
+/// Deserializes the `#[query]` field of a route.
+pub fn from_query<Q: DeserializeOwned>(query: &str) -> Option<Q> {
+    serde_urlencoded::from_str(query.strip_prefix('?').unwrap_or(query)).ok()
+}
+
+/// Appends the `#[query]` field of a route to its path.
+pub fn append_query<Q: Serialize>(path: String, query: &Q) -> String {
+    match serde_urlencoded::to_string(query) {
+        Ok(query) if query.is_empty() => path,
+        Ok(query) => {
+            // The path may already contain the query of a nested route.
+            let sep = if path.contains('?') { '&' } else { '?' };
+            format!("{path}{sep}{query}")
+        }
+        Err(e) => {
+            tracing::warn!("failed to serialize the query of a route: {e}");
+            path
+        }
+    }
+}
+
 // re-export Router because the macro needs to access it
 pub type Router = matchit::Router<String>;
 
@@ -27,13 +67,17 @@ pub fn build_router<R: Routable>() -> Router {
 }
 
 /// Use a `matchit::Router` to match the route of a `Routable`
-pub fn recognize_with_router<R: Routable>(router: &Router, pathname: &str) -> Option<R> {
+pub fn recognize_with_router<R: Routable>(
+    router: &Router,
+    pathname: &str,
+    query: &str,
+) -> Option<R> {
     let matched = router.at(pathname);
 
     match matched {
         Ok(matched) => {
             let params: HashMap<&str, &str> = matched.params.iter().collect();
-            R::from_path(matched.value, &params).or_else(R::not_found_route)
+            R::from_path_with_query(matched.value, &params, query).or_else(R::not_found_route)
         }
         Err(_) => R::not_found_route(),
     }
@@ -1,8 +1,11 @@
 use std::borrow::Cow;
 
+use crate::blocker::Blockers;
+use crate::entries::{Action, HistoryEntries};
 use crate::history::{AnyHistory, History, HistoryError, HistoryResult};
-use crate::query::ToQuery;
+use crate::query::{Raw, ToQuery};
 use crate::routable::Routable;
+use crate::utils::merge_query;
 
 pub type NavigationError = HistoryError;
 pub type NavigationResult<T> = HistoryResult<T>;
@@ -19,20 +22,39 @@ pub enum NavigatorKind {
 }
 
 /// A struct to navigate between locations.
+///
+/// Navigations to a [`Routable`] can be blocked by [`use_blocker`](crate::hooks::use_blocker).
 #[derive(Debug, PartialEq, Clone)]
 pub struct Navigator {
     inner: AnyHistory,
     basename: Option<String>,
+    blockers: Blockers,
+    entries: HistoryEntries,
 }
 
 impl Navigator {
-    pub(crate) fn new(history: AnyHistory, basename: Option<String>) -> Self {
+    pub(crate) fn new(
+        history: AnyHistory,
+        basename: Option<String>,
+        blockers: Blockers,
+        entries: HistoryEntries,
+    ) -> Self {
         Self {
             inner: history,
             basename,
+            blockers,
+            entries,
         }
     }
 
+    pub(crate) fn blockers(&self) -> &Blockers {
+        &self.blockers
+    }
+
+    pub(crate) fn entries(&self) -> &HistoryEntries {
+        &self.entries
+    }
+
     /// Returns basename of current navigator.
     pub fn basename(&self) -> Option<&str> {
         self.basename.as_deref()
@@ -60,7 +82,7 @@ impl Navigator {
     where
         R: Routable,
     {
-        self.inner.push(self.prefix_basename(&route.to_path()));
+        self.navigate(Action::Push, route, |history, path| history.push(path));
     }
 
     /// Replaces the current history entry with provided [`Routable`] and [`None`] state.
@@ -68,7 +90,9 @@ impl Navigator {
     where
         R: Routable,
     {
-        self.inner.replace(self.prefix_basename(&route.to_path()));
+        self.navigate(Action::Replace, route, |history, path| {
+            history.replace(path)
+        });
     }
 
     /// Pushes a [`Routable`] entry with state.
@@ -77,8 +101,9 @@ impl Navigator {
         R: Routable,
         T: 'static,
     {
-        self.inner
-            .push_with_state(self.prefix_basename(&route.to_path()), state);
+        self.navigate(Action::Push, route, move |history, path| {
+            history.push_with_state(path, state)
+        });
     }
 
     /// Replaces the current history entry with provided [`Routable`] and state.
@@ -87,28 +112,45 @@ impl Navigator {
         R: Routable,
         T: 'static,
     {
-        self.inner
-            .replace_with_state(self.prefix_basename(&route.to_path()), state);
+        self.navigate(Action::Replace, route, move |history, path| {
+            history.replace_with_state(path, state)
+        });
     }
 
     /// Same as `.push()` but affix the queries to the end of the route.
+    ///
+    /// The query of a route with a `#[query]` field is kept and joined with `query`.
     pub fn push_with_query<R, Q>(&self, route: &R, query: Q) -> Result<(), Q::Error>
     where
         R: Routable,
         Q: ToQuery,
     {
-        self.inner
-            .push_with_query(self.prefix_basename(&route.to_path()), query)
+        let query = query.to_query()?.into_owned();
+        self.navigate(Action::Push, route, move |history, path| {
+            let (path, query) = merge_query(&path, &query);
+            history
+                .push_with_query(path, Raw(query))
+                .unwrap_or_else(|never| match never {})
+        });
+        Ok(())
     }
 
     /// Same as `.replace()` but affix the queries to the end of the route.
+    ///
+    /// The query of a route with a `#[query]` field is kept and joined with `query`.
     pub fn replace_with_query<R, Q>(&self, route: &R, query: Q) -> Result<(), Q::Error>
     where
         R: Routable,
         Q: ToQuery,
     {
-        self.inner
-            .replace_with_query(self.prefix_basename(&route.to_path()), query)
+        let query = query.to_query()?.into_owned();
+        self.navigate(Action::Replace, route, move |history, path| {
+            let (path, query) = merge_query(&path, &query);
+            history
+                .replace_with_query(path, Raw(query))
+                .unwrap_or_else(|never| match never {})
+        });
+        Ok(())
     }
 
     /// Same as `.push_with_state()` but affix the queries to the end of the route.
@@ -123,8 +165,14 @@ impl Navigator {
         Q: ToQuery,
         T: 'static,
     {
-        self.inner
-            .push_with_query_and_state(self.prefix_basename(&route.to_path()), query, state)
+        let query = query.to_query()?.into_owned();
+        self.navigate(Action::Push, route, move |history, path| {
+            let (path, query) = merge_query(&path, &query);
+            history
+                .push_with_query_and_state(path, Raw(query), state)
+                .unwrap_or_else(|never| match never {})
+        });
+        Ok(())
     }
 
     /// Same as `.replace_with_state()` but affix the queries to the end of the route.
@@ -139,11 +187,33 @@ impl Navigator {
         Q: ToQuery,
         T: 'static,
     {
-        self.inner.replace_with_query_and_state(
-            self.prefix_basename(&route.to_path()),
-            query,
-            state,
-        )
+        let query = query.to_query()?.into_owned();
+        self.navigate(Action::Replace, route, move |history, path| {
+            let (path, query) = merge_query(&path, &query);
+            history
+                .replace_with_query_and_state(path, Raw(query), state)
+                .unwrap_or_else(|never| match never {})
+        });
+        Ok(())
+    }
+
+    /// Navigates to `route` with `f`, unless a blocker blocks it.
+    fn navigate<R>(&self, action: Action, route: &R, f: impl FnOnce(&AnyHistory, String) + 'static)
+    where
+        R: Routable,
+    {
+        let route_s = route.to_path();
+        let path = self.prefix_basename(&route_s).into_owned();
+        let next = route_s
+            .split_once('?')
+            .map_or(route_s.as_str(), |(path, _)| path)
+            .to_owned();
+
+        let history = self.inner.clone();
+        let entries = self.entries.clone();
+        self.blockers.navigate(next, move || {
+            entries.run(action, || f(&history, path));
+        });
     }
 
     /// Returns the Navigator kind.
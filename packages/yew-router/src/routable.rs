@@ -1,4 +1,6 @@
+use std::any::Any;
 use std::collections::HashMap;
+use std::rc::Rc;
 
 pub use yew_router_macro::Routable;
 
@@ -17,7 +19,17 @@ pub trait Routable: Clone + PartialEq {
     /// Converts path to an instance of the routes enum.
     fn from_path(path: &str, params: &HashMap<&str, &str>) -> Option<Self>;
 
+    /// Converts path and query string to an instance of the routes enum.
+    ///
+    /// The query string is deserialized into the `#[query]` field of the variant, if any.
+    fn from_path_with_query(path: &str, params: &HashMap<&str, &str>, query: &str) -> Option<Self> {
+        let _ = query;
+        Self::from_path(path, params)
+    }
+
     /// Converts the route to a string that can passed to the history API.
+    ///
+    /// The `#[query]` field of the variant, if any, is serialized into the query string.
     fn to_path(&self) -> String;
 
     /// Lists all the available routes
@@ -28,6 +40,20 @@ pub trait Routable: Clone + PartialEq {
 
     /// Match a route based on the path
     fn recognize(pathname: &str) -> Option<Self>;
+
+    /// Match a route based on the path and the query string
+    fn recognize_with_query(pathname: &str, query: &str) -> Option<Self> {
+        let _ = query;
+        Self::recognize(pathname)
+    }
+
+    /// The route held by the `#[nested]` field of this route, if any.
+    ///
+    /// It is rendered by the [`Outlet`](crate::components::Outlet) in the component rendering
+    /// this route.
+    fn nested_route(&self) -> Option<Rc<dyn Any>> {
+        None
+    }
 }
 
 /// A special route that accepts any route.
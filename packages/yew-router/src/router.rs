@@ -6,7 +6,10 @@ use gloo::history::query::Raw;
 use yew::prelude::*;
 use yew::virtual_dom::AttrValue;
 
+use crate::blocker::Blockers;
+use crate::entries::{Action, HistoryEntries};
 use crate::history::{AnyHistory, BrowserHistory, HashHistory, History, Location};
+use crate::loader::Loaders;
 use crate::navigator::Navigator;
 use crate::utils::{base_url, strip_slash_suffix};
 
@@ -74,8 +77,16 @@ fn base_router(props: &RouterProps) -> Html {
         basename,
     } = props.clone();
 
+    let blockers = (*use_state(Blockers::default)).clone();
+    let entries = (*use_state(HistoryEntries::default)).clone();
+    let loaders = (*use_state(Loaders::default)).clone();
     let basename = basename.map(|m| strip_slash_suffix(&m).to_owned());
-    let navigator = Navigator::new(history.clone(), basename.clone());
+    let navigator = Navigator::new(
+        history.clone(),
+        basename.clone(),
+        blockers.clone(),
+        entries.clone(),
+    );
 
     let old_basename = use_mut_ref(|| Option::<String>::None);
     let mut old_basename = old_basename.borrow_mut();
@@ -86,6 +97,8 @@ fn base_router(props: &RouterProps) -> Html {
         let old_navigator = Navigator::new(
             history.clone(),
             old_basename.as_ref().or(basename.as_ref()).cloned(),
+            blockers.clone(),
+            entries.clone(),
         );
         old_basename.clone_from(&basename);
         let location = history.location();
@@ -93,8 +106,10 @@ fn base_router(props: &RouterProps) -> Html {
         let prefixed = navigator.prefix_basename(&stripped);
 
         if prefixed != location.path() {
-            history
-                .replace_with_query(prefixed, Raw(location.query_str()))
+            entries
+                .run(Action::Replace, || {
+                    history.replace_with_query(prefixed, Raw(location.query_str()))
+                })
                 .unwrap_or_else(|never| match never {});
         } else {
             // Reaching here is possible if the page loads with the correct path, including the
@@ -104,7 +119,9 @@ fn base_router(props: &RouterProps) -> Html {
         }
     }
 
-    let navi_ctx = NavigatorContext { navigator };
+    let navi_ctx = NavigatorContext {
+        navigator: navigator.clone(),
+    };
 
     let loc_ctx = use_reducer(|| LocationContext {
         location: history.location(),
@@ -114,14 +131,38 @@ fn base_router(props: &RouterProps) -> Html {
     {
         let loc_ctx_dispatcher = loc_ctx.dispatcher();
 
-        use_effect_with(history, move |history| {
+        // The navigator changes with the history and the basename.
+        use_effect_with(navigator, move |navigator| {
+            let navigator = navigator.clone();
             let history = history.clone();
+            let location = history.location();
+            navigator.entries().reset(&history, &location);
+            navigator.blockers().reset_history(
+                navigator
+                    .strip_basename(location.path().into())
+                    .into_owned(),
+            );
             // Force location update when history changes.
-            loc_ctx_dispatcher.dispatch(history.location());
+            loc_ctx_dispatcher.dispatch(location);
 
             let history_cb = {
                 let history = history.clone();
-                move || loc_ctx_dispatcher.dispatch(history.location())
+                move || {
+                    let location = history.location();
+                    let path = navigator
+                        .strip_basename(location.path().into())
+                        .into_owned();
+                    let entries = navigator.entries();
+                    let delta = match entries.action() {
+                        Some(_) => None,
+                        None => Some(entries.delta(&history, &location)),
+                    };
+                    // Blocked back and forward navigations are reverted.
+                    if navigator.blockers().location_changed(&history, delta, path) {
+                        entries.location_changed(&history, &location);
+                        loc_ctx_dispatcher.dispatch(location);
+                    }
+                }
             };
 
             let listener = history.listen(history_cb);
@@ -136,7 +177,11 @@ fn base_router(props: &RouterProps) -> Html {
     html! {
         <ContextProvider<NavigatorContext> context={navi_ctx}>
             <ContextProvider<LocationContext> context={(*loc_ctx).clone()}>
-                {children}
+                <ContextProvider<HistoryEntries> context={entries}>
+                    <ContextProvider<Loaders> context={loaders}>
+                        {children}
+                    </ContextProvider<Loaders>>
+                </ContextProvider<HistoryEntries>>
             </ContextProvider<LocationContext>>
         </ContextProvider<NavigatorContext>>
     }
@@ -126,7 +126,7 @@ impl<COMP: Component> RouterScopeExt for yew::html::Scope<COMP> {
 
         let path = navigator.strip_basename(location.path().into());
 
-        R::recognize(&path)
+        R::recognize_with_query(&path, location.query_str())
     }
 
     fn add_location_listener(&self, cb: Callback<Location>) -> Option<LocationHandle> {
@@ -1,7 +1,10 @@
 //! The [`Switch`] Component.
 
+use yew::functional::{Hook, HookContext};
 use yew::prelude::*;
 
+use crate::components::OutletContext;
+use crate::loader::Loaders;
 use crate::prelude::*;
 
 /// Props for [`Switch`]
@@ -14,6 +17,24 @@ where
     pub render: Callback<R, Html>,
     #[prop_or_default]
     pub pathname: Option<String>,
+    /// Callback called with the current route before rendering it, which can redirect to
+    /// another route instead, e.g. to a login page.
+    #[prop_or_default]
+    pub guard: Option<Callback<R, GuardResult<R>>>,
+    /// The loader of the routes, started as soon as a route is matched.
+    ///
+    /// See [`Loader`].
+    #[prop_or_default]
+    pub loader: Option<RouteLoader<R>>,
+}
+
+/// The result of the `guard` of a [`Switch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GuardResult<R> {
+    /// Render the route.
+    Allow,
+    /// Replace the route with another one, without rendering it.
+    Redirect(R),
 }
 
 /// A Switch that dispatches route among variants of a [`Routable`].
@@ -24,24 +45,124 @@ where
 /// Otherwise `html! {}` is rendered and a message is logged to console
 /// stating that no route can be matched.
 /// See the [crate level document][crate] for more information.
+///
+/// The route held by the `#[nested]` field of the matched route, if any, is rendered by the
+/// [`Outlet`](crate::components::Outlet) in the rendered component.
+///
+/// The data of the matched route is loaded by the `loader`, if any, as soon as the route is
+/// matched. See [`Loader`].
+///
+/// The `guard`, if any, is called with the matched route before rendering it:
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew_router::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Routable)]
+/// enum Route {
+///     #[at("/")]
+///     Home,
+///     #[at("/login")]
+///     Login,
+///     #[at("/account")]
+///     Account,
+/// }
+///
+/// #[derive(Properties, PartialEq)]
+/// struct AppProps {
+///     logged_in: bool,
+/// }
+///
+/// #[component]
+/// fn App(props: &AppProps) -> Html {
+///     let logged_in = props.logged_in;
+///     let guard = Callback::from(move |route| match route {
+///         Route::Account if !logged_in => GuardResult::Redirect(Route::Login),
+///         _ => GuardResult::Allow,
+///     });
+///
+///     html! {
+///         <Switch<Route> render={switch} {guard} />
+///     }
+/// }
+/// # fn switch(_: Route) -> Html { Html::default() }
+/// ```
 #[component]
 pub fn Switch<R>(props: &SwitchProps<R>) -> Html
 where
     R: Routable + 'static,
 {
     let route = use_route::<R>();
+    let navigator = use_navigator();
+    let loaders = use_context::<Loaders>();
+    // The first render of a server-rendered route uses the data prepared on the server, which is
+    // not loaded again.
+    let server_rendered = use_server_rendered();
+    let first_render = use_mut_ref(|| true);
+    {
+        let first_render = first_render.clone();
+        use_effect_with((), move |_| {
+            *first_render.borrow_mut() = false;
+        });
+    }
 
     let route = props
         .pathname
         .as_ref()
-        .and_then(|p| R::recognize(p))
+        .and_then(|p| match p.split_once('?') {
+            Some((path, query)) => R::recognize_with_query(path, query),
+            None => R::recognize(p),
+        })
         .or(route);
 
+    let redirect = route
+        .clone()
+        .zip(props.guard.as_ref())
+        .and_then(|(route, guard)| match guard.emit(route) {
+            GuardResult::Allow => None,
+            GuardResult::Redirect(to) => Some(to),
+        });
+    use_effect_with(redirect.clone(), move |redirect| {
+        if let Some((to, navigator)) = redirect.as_ref().zip(navigator) {
+            navigator.replace(to);
+        }
+    });
+    if redirect.is_some() {
+        return Html::default();
+    }
+
+    if let Some((route, (loader, loaders))) = route
+        .as_ref()
+        .zip(props.loader.as_ref().zip(loaders.as_ref()))
+    {
+        loaders.register(loader);
+        loaders.matched(loader, route, !(server_rendered && *first_render.borrow()));
+    }
+
     match route {
-        Some(route) => props.render.emit(route),
+        Some(route) => html! {
+            <ContextProvider<OutletContext> context={OutletContext::new(&route)}>
+                { props.render.emit(route) }
+            </ContextProvider<OutletContext>>
+        },
         None => {
             tracing::warn!("no route matched");
             Html::default()
         }
     }
 }
+
+/// Returns whether the component is rendered on the server or hydrated.
+fn use_server_rendered() -> impl Hook<Output = bool> {
+    struct HookProvider;
+
+    impl Hook for HookProvider {
+        type Output = bool;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            ctx.is_server_rendered()
+        }
+    }
+
+    HookProvider
+}
@@ -66,6 +66,21 @@ pub fn compose_path(pathname: &str, query: &str) -> Option<String> {
     }
 }
 
+/// Splits the query rendered by the `#[query]` field of a route off `path` and joins it with
+/// `query`, so both are kept when `query` is appended to the path.
+pub(crate) fn merge_query<'a>(path: &'a str, query: &str) -> (&'a str, String) {
+    let Some((path, route_query)) = path.split_once('?') else {
+        return (path, query.to_owned());
+    };
+
+    let query = match (route_query.is_empty(), query.is_empty()) {
+        (true, _) => query.to_owned(),
+        (false, true) => route_query.to_owned(),
+        (false, false) => format!("{route_query}&{query}"),
+    };
+    (path, query)
+}
+
 // TODO: remove the cfg after wasm-bindgen-test stops emitting the function unconditionally
 #[cfg(all(
     test,
@@ -121,4 +136,21 @@ mod tests {
             Some("/events?from=2019&to=2021".to_string())
         );
     }
+
+    #[test]
+    fn test_merge_query() {
+        assert_eq!(merge_query("/home", ""), ("/home", "".to_string()));
+        assert_eq!(
+            merge_query("/path/to", "foo=bar"),
+            ("/path/to", "foo=bar".to_string())
+        );
+        assert_eq!(
+            merge_query("/search?q=yew", ""),
+            ("/search", "q=yew".to_string())
+        );
+        assert_eq!(
+            merge_query("/search?q=yew", "page=2"),
+            ("/search", "q=yew&page=2".to_string())
+        );
+    }
 }
@@ -0,0 +1,182 @@
+// TODO: remove the cfg after wasm-bindgen-test stops emitting the function unconditionally
+#![cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+
+use std::time::Duration;
+
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+use yew::functional::component;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew_router::history::{AnyHistory, BrowserHistory, History, MemoryHistory};
+use yew_router::prelude::*;
+
+mod utils;
+use utils::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, PartialEq, Routable)]
+enum Routes {
+    #[at("/")]
+    Home,
+    #[at("/form")]
+    Form,
+    #[at("/login")]
+    Login,
+    #[at("/account")]
+    Account,
+}
+
+#[component(Form)]
+fn form() -> Html {
+    let blocker = use_blocker(|transition: &Transition| transition.current != transition.next);
+
+    let proceed = {
+        let blocker = blocker.clone();
+        Callback::from(move |_| blocker.proceed())
+    };
+    let reset = {
+        let blocker = blocker.clone();
+        Callback::from(move |_| blocker.reset())
+    };
+
+    html! {
+        <>
+            <div id="result">{ "Form" }</div>
+            <div id="blocked">{ blocker.is_blocked().to_string() }</div>
+            <Link<Routes> classes="home" to={Routes::Home}>{ "Home" }</Link<Routes>>
+            <button class="proceed" onclick={proceed}>{ "Leave" }</button>
+            <button class="reset" onclick={reset}>{ "Stay" }</button>
+        </>
+    }
+}
+
+fn switch(route: Routes) -> Html {
+    match route {
+        Routes::Home => html! {
+            <>
+                <div id="result">{ "Home" }</div>
+                <Link<Routes> classes="login" to={Routes::Login}>{ "Login" }</Link<Routes>>
+            </>
+        },
+        Routes::Form => html! { <Form /> },
+        Routes::Login => html! {
+            <>
+                <div id="result">{ "Login" }</div>
+                <Link<Routes> classes="form" to={Routes::Form}>{ "Form" }</Link<Routes>>
+            </>
+        },
+        Routes::Account => html! { <div id="result">{ "Account" }</div> },
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct RootProps {
+    history: AnyHistory,
+}
+
+#[component(Root)]
+fn root(props: &RootProps) -> Html {
+    let guard = Callback::from(|route| match route {
+        Routes::Account => GuardResult::Redirect(Routes::Login),
+        _ => GuardResult::Allow,
+    });
+
+    html! {
+        <Router history={props.history.clone()}>
+            <Switch<Routes> render={switch} {guard} />
+        </Router>
+    }
+}
+
+fn render(id: &str, history: AnyHistory) {
+    let div = gloo::utils::document().create_element("div").unwrap();
+    div.set_attribute("id", id).unwrap();
+    gloo::utils::body().append_child(&div).unwrap();
+    yew::Renderer::<Root>::with_root_and_props(div, RootProps { history }).render();
+}
+
+#[test]
+async fn guard_redirects() {
+    let history = AnyHistory::from(MemoryHistory::new());
+    history.push("/account");
+    render("guard", history.clone());
+
+    // The guard redirects before rendering the route.
+    sleep(Duration::ZERO).await;
+    sleep(Duration::ZERO).await;
+    assert_eq!("Login", obtain_result_by_selector("#guard #result"));
+    assert_eq!("/login", history.location().path());
+}
+
+#[test]
+async fn blocker_blocks_navigations() {
+    let history = AnyHistory::from(MemoryHistory::new());
+    history.push("/form");
+    render("blocker", history.clone());
+
+    sleep(Duration::ZERO).await;
+    assert_eq!("Form", obtain_result_by_selector("#blocker #result"));
+    assert_eq!("false", obtain_result_by_selector("#blocker #blocked"));
+
+    // Links are blocked until the navigation is resumed or cancelled.
+    click("#blocker a.home");
+    sleep(Duration::ZERO).await;
+    assert_eq!("Form", obtain_result_by_selector("#blocker #result"));
+    assert_eq!("true", obtain_result_by_selector("#blocker #blocked"));
+    assert_eq!("/form", history.location().path());
+
+    click("#blocker button.reset");
+    sleep(Duration::ZERO).await;
+    assert_eq!("false", obtain_result_by_selector("#blocker #blocked"));
+
+    // Back navigations are reverted.
+    history.back();
+    sleep(Duration::ZERO).await;
+    sleep(Duration::ZERO).await;
+    assert_eq!("Form", obtain_result_by_selector("#blocker #result"));
+    assert_eq!("true", obtain_result_by_selector("#blocker #blocked"));
+    assert_eq!("/form", history.location().path());
+
+    click("#blocker button.reset");
+    sleep(Duration::ZERO).await;
+
+    click("#blocker a.home");
+    sleep(Duration::ZERO).await;
+    click("#blocker button.proceed");
+    sleep(Duration::ZERO).await;
+    assert_eq!("Home", obtain_result_by_selector("#blocker #result"));
+    assert_eq!("/", history.location().path());
+}
+
+// Waits for the `popstate` events of the browser history to be dispatched.
+async fn settle() {
+    sleep(Duration::from_millis(100)).await;
+}
+
+#[test]
+async fn blocker_reverts_browser_history_navigations() {
+    let history = AnyHistory::from(BrowserHistory::new());
+    history.push("/");
+    render("browser", history.clone());
+
+    settle().await;
+    click("#browser a.login");
+    settle().await;
+    click("#browser a.form");
+    settle().await;
+    assert_eq!("Form", obtain_result_by_selector("#browser #result"));
+
+    // Navigations of several entries are reverted to the current entry.
+    history.go(-2);
+    settle().await;
+    settle().await;
+    assert_eq!("Form", obtain_result_by_selector("#browser #result"));
+    assert_eq!("true", obtain_result_by_selector("#browser #blocked"));
+    assert_eq!("/form", history.location().path());
+
+    click("#browser button.proceed");
+    settle().await;
+    assert_eq!("Home", obtain_result_by_selector("#browser #result"));
+    assert_eq!("/", history.location().path());
+}
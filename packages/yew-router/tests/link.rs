@@ -23,6 +23,11 @@ struct PageParam {
     page: i32,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+struct TagParam {
+    tag: i32,
+}
+
 #[derive(Clone, Serialize, Deserialize, PartialEq)]
 struct SearchParams {
     q: String,
@@ -51,6 +56,11 @@ enum Routes {
     Posts,
     #[at("/search")]
     Search,
+    #[at("/tagged")]
+    Tagged {
+        #[query]
+        query: TagParam,
+    },
 }
 
 #[derive(PartialEq, Properties)]
@@ -94,6 +104,11 @@ fn navigation_menu(props: &NavigationMenuProps) -> Html {
                     { "Search with keyword and language parameters" }
                 </Link<Routes, SearchParams>>
             </li>
+            <li class="tagged-page-2">
+                <Link<Routes, PageParam> to={Routes::Tagged { query: TagParam { tag: 1 } }} query={Some(PageParam { page: 2 })}>
+                    { "Tagged posts of 2nd page" }
+                </Link<Routes, PageParam>>
+            </li>
         </ul>
     }
 }
@@ -130,6 +145,10 @@ async fn link_in_browser_router() {
         "/search?q=Rust&lang=en_US",
         link_href("#browser-router ul > li.search-q-lang > a")
     );
+    assert_eq!(
+        "/tagged?tag=1&page=2",
+        link_href("#browser-router ul > li.tagged-page-2 > a")
+    );
 
     handle.destroy();
 }
@@ -0,0 +1,180 @@
+// TODO: remove the cfg after wasm-bindgen-test stops emitting the function unconditionally
+#![cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+
+use std::cell::Cell;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+use yew::functional::component;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew_router::history::{AnyHistory, History, MemoryHistory};
+use yew_router::prelude::*;
+
+mod utils;
+use utils::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+thread_local! {
+    static LOADS: Cell<u32> = const { Cell::new(0) };
+}
+
+#[derive(Debug, Clone, PartialEq, Routable)]
+enum Routes {
+    #[at("/")]
+    Home,
+    #[at("/posts/{id}")]
+    Post { id: u32 },
+}
+
+#[derive(Serialize, Deserialize)]
+struct Data {
+    title: String,
+}
+
+impl Loader for Routes {
+    type Data = Data;
+
+    async fn load(self) -> Data {
+        LOADS.with(|m| m.set(m.get() + 1));
+        sleep(Duration::from_millis(50)).await;
+        let title = match self {
+            Routes::Home => "Home".to_owned(),
+            Routes::Post { id } => format!("Post {id}"),
+        };
+        Data { title }
+    }
+}
+
+#[component(Page)]
+fn page() -> HtmlResult {
+    let data = use_loader_data::<Routes>()?;
+
+    Ok(html! {
+        <>
+            <div id="result">{ &data.title }</div>
+            <Link<Routes> classes="post" to={Routes::Post { id: 1 }}>{ "Post" }</Link<Routes>>
+        </>
+    })
+}
+
+fn switch(_: Routes) -> Html {
+    html! {
+        <Suspense fallback={html! { <div id="result">{ "Loading" }</div> }}>
+            <Page />
+        </Suspense>
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct RootProps {
+    history: AnyHistory,
+}
+
+#[component(Root)]
+fn root(props: &RootProps) -> Html {
+    html! {
+        <Router history={props.history.clone()}>
+            <Switch<Routes> render={switch} loader={Routes::loader()} />
+        </Router>
+    }
+}
+
+fn loads() -> u32 {
+    LOADS.with(|m| m.get())
+}
+
+fn hover(selector: &str) {
+    let event = MouseEvent::new("mouseenter").unwrap();
+    gloo::utils::document()
+        .query_selector(selector)
+        .unwrap()
+        .unwrap()
+        .dispatch_event(&event)
+        .unwrap();
+}
+
+#[test]
+async fn loader() {
+    let history = AnyHistory::from(MemoryHistory::new());
+    yew::Renderer::<Root>::with_root_and_props(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+        RootProps {
+            history: history.clone(),
+        },
+    )
+    .render();
+
+    sleep(Duration::ZERO).await;
+    assert_eq!("Loading", obtain_result_by_id("result"));
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!("Home", obtain_result_by_id("result"));
+    assert_eq!(1, loads());
+
+    // Hovering a link prefetches the data of its route.
+    hover("a.post");
+    assert_eq!(2, loads());
+    sleep(Duration::from_millis(100)).await;
+
+    // The prefetched data is rendered without loading it again.
+    click("a.post");
+    sleep(Duration::ZERO).await;
+    assert_eq!("Post 1", obtain_result_by_id("result"));
+    assert_eq!(2, loads());
+
+    // Navigating to a route starts loading its data.
+    history.push("/posts/2");
+    sleep(Duration::ZERO).await;
+    assert_eq!("Loading", obtain_result_by_id("result"));
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!("Post 2", obtain_result_by_id("result"));
+    assert_eq!(3, loads());
+
+    // The prefetched data of the routes that aren't navigated to is discarded on the next
+    // navigation.
+    hover("a.post");
+    assert_eq!(4, loads());
+    history.push("/");
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!("Home", obtain_result_by_id("result"));
+    assert_eq!(5, loads());
+
+    click("a.post");
+    sleep(Duration::ZERO).await;
+    assert_eq!("Loading", obtain_result_by_id("result"));
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!("Post 1", obtain_result_by_id("result"));
+    assert_eq!(6, loads());
+}
+
+fn switch_without_data(_: Routes) -> Html {
+    html! { <div id="result">{ "Rendered" }</div> }
+}
+
+#[component(RootWithoutData)]
+fn root_without_data(props: &RootProps) -> Html {
+    html! {
+        <Router history={props.history.clone()}>
+            <Switch<Routes> render={switch_without_data} loader={Routes::loader()} />
+        </Router>
+    }
+}
+
+#[test]
+async fn loader_starts_on_first_client_render() {
+    let before = loads();
+    yew::Renderer::<RootWithoutData>::with_root_and_props(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+        RootProps {
+            history: AnyHistory::from(MemoryHistory::new()),
+        },
+    )
+    .render();
+
+    // The route is not server-rendered, so its data is loaded even if it isn't used yet.
+    sleep(Duration::ZERO).await;
+    assert_eq!("Rendered", obtain_result_by_id("result"));
+    assert_eq!(before + 1, loads());
+}
@@ -0,0 +1,108 @@
+// TODO: remove the cfg after wasm-bindgen-test stops emitting the function unconditionally
+#![cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+
+use std::cell::Cell;
+use std::time::Duration;
+
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+use yew::functional::component;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew_router::history::{AnyHistory, History, MemoryHistory};
+use yew_router::prelude::*;
+
+mod utils;
+use utils::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+thread_local! {
+    static LAYOUT_MOUNTS: Cell<u32> = const { Cell::new(0) };
+}
+
+#[derive(Debug, Clone, PartialEq, Routable)]
+enum Routes {
+    #[at("/")]
+    Home,
+    #[at("/settings")]
+    Settings(#[nested] SettingsRoutes),
+}
+
+#[derive(Debug, Clone, PartialEq, Routable)]
+enum SettingsRoutes {
+    #[at("/")]
+    Profile,
+    #[at("/theme")]
+    Theme,
+}
+
+#[component(SettingsLayout)]
+fn settings_layout() -> Html {
+    use_effect_with((), |_| {
+        LAYOUT_MOUNTS.with(|m| m.set(m.get() + 1));
+    });
+
+    html! {
+        <>
+            <div id="layout">{ "Settings" }</div>
+            <Outlet<SettingsRoutes> render={switch_settings} />
+        </>
+    }
+}
+
+fn switch(route: Routes) -> Html {
+    match route {
+        Routes::Home => html! { <div id="result">{ "Home" }</div> },
+        Routes::Settings(_) => html! { <SettingsLayout /> },
+    }
+}
+
+fn switch_settings(route: SettingsRoutes) -> Html {
+    match route {
+        SettingsRoutes::Profile => html! { <div id="result">{ "Profile" }</div> },
+        SettingsRoutes::Theme => html! { <div id="result">{ "Theme" }</div> },
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct RootProps {
+    history: AnyHistory,
+}
+
+#[component(Root)]
+fn root(props: &RootProps) -> Html {
+    html! {
+        <Router history={props.history.clone()}>
+            <Switch<Routes> render={switch} />
+        </Router>
+    }
+}
+
+#[test]
+async fn outlet_renders_nested_route() {
+    let history = AnyHistory::from(MemoryHistory::new());
+    history.push("/settings");
+
+    yew::Renderer::<Root>::with_root_and_props(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+        RootProps {
+            history: history.clone(),
+        },
+    )
+    .render();
+
+    sleep(Duration::ZERO).await;
+    assert_eq!("Settings", obtain_result_by_id("layout"));
+    assert_eq!("Profile", obtain_result_by_id("result"));
+
+    history.push("/settings/theme");
+    sleep(Duration::ZERO).await;
+    assert_eq!("Theme", obtain_result_by_id("result"));
+
+    // The layout stays mounted while the nested route changes.
+    assert_eq!(1, LAYOUT_MOUNTS.with(Cell::get));
+
+    history.push("/");
+    sleep(Duration::ZERO).await;
+    assert_eq!("Home", obtain_result_by_id("result"));
+}
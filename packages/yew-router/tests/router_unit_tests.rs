@@ -1,6 +1,7 @@
 // TODO: remove the cfg after wasm-bindgen-test stops emitting the function unconditionally
 #![cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
 
+use serde::{Deserialize, Serialize};
 use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
 use yew_router::prelude::*;
 
@@ -254,3 +255,177 @@ fn router_root_not_affected() {
 
     assert_eq!(Some(AppRoute::Home), AppRoute::recognize("/"));
 }
+
+#[test]
+fn router_nested_field() {
+    #[derive(Routable, Debug, Clone, PartialEq)]
+    enum MainRoute {
+        #[at("/")]
+        Home,
+        #[at("/settings")]
+        Settings(#[nested] SettingsRoute),
+        #[at("/users/{id}")]
+        User {
+            id: u64,
+            #[nested]
+            tab: UserRoute,
+        },
+        #[at("/404")]
+        #[not_found]
+        NotFound,
+    }
+
+    #[derive(Routable, Debug, Clone, PartialEq)]
+    enum SettingsRoute {
+        #[at("/")]
+        Profile,
+        #[at("/theme")]
+        Theme,
+        #[at("/404")]
+        #[not_found]
+        NotFound,
+    }
+
+    #[derive(Routable, Debug, Clone, PartialEq)]
+    enum UserRoute {
+        #[at("/")]
+        Overview,
+        #[at("/posts/{page}")]
+        Posts { page: u32 },
+    }
+
+    assert_eq!(
+        Some(MainRoute::Settings(SettingsRoute::Profile)),
+        MainRoute::recognize("/settings")
+    );
+    assert_eq!(
+        Some(MainRoute::Settings(SettingsRoute::Theme)),
+        MainRoute::recognize("/settings/theme")
+    );
+    // Unknown subpaths match the not found route of the nested route
+    assert_eq!(
+        Some(MainRoute::Settings(SettingsRoute::NotFound)),
+        MainRoute::recognize("/settings/unknown")
+    );
+    assert_eq!(
+        Some(MainRoute::User {
+            id: 1,
+            tab: UserRoute::Posts { page: 2 }
+        }),
+        MainRoute::recognize("/users/1/posts/2")
+    );
+    // Without a not found route, unknown subpaths match the not found route of the parent
+    assert_eq!(
+        Some(MainRoute::NotFound),
+        MainRoute::recognize("/users/1/unknown")
+    );
+
+    assert_eq!(
+        MainRoute::Settings(SettingsRoute::Profile).to_path(),
+        "/settings"
+    );
+    assert_eq!(
+        MainRoute::Settings(SettingsRoute::Theme).to_path(),
+        "/settings/theme"
+    );
+    assert_eq!(
+        MainRoute::User {
+            id: 1,
+            tab: UserRoute::Overview
+        }
+        .to_path(),
+        "/users/1"
+    );
+    assert_eq!(
+        MainRoute::User {
+            id: 1,
+            tab: UserRoute::Posts { page: 2 }
+        }
+        .to_path(),
+        "/users/1/posts/2"
+    );
+}
+
+#[test]
+fn router_query_field() {
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct SearchQuery {
+        q: String,
+        page: Option<u32>,
+    }
+
+    #[derive(Routable, Debug, Clone, PartialEq)]
+    enum AppRoute {
+        #[at("/")]
+        Home,
+        #[at("/search")]
+        Search {
+            #[query]
+            query: SearchQuery,
+        },
+        #[at("/users/{id}")]
+        User {
+            id: u64,
+            #[nested]
+            tab: UserRoute,
+        },
+        #[at("/404")]
+        #[not_found]
+        NotFound,
+    }
+
+    #[derive(Routable, Debug, Clone, PartialEq)]
+    enum UserRoute {
+        #[at("/")]
+        Overview,
+        #[at("/posts")]
+        Posts {
+            #[query]
+            query: SearchQuery,
+        },
+    }
+
+    let route = AppRoute::Search {
+        query: SearchQuery {
+            q: "a b".to_string(),
+            page: Some(2),
+        },
+    };
+    assert_eq!(route.to_path(), "/search?q=a+b&page=2");
+    assert_eq!(
+        Some(route),
+        AppRoute::recognize_with_query("/search", "?q=a+b&page=2")
+    );
+    assert_eq!(
+        Some(AppRoute::Search {
+            query: SearchQuery {
+                q: "yew".to_string(),
+                page: None,
+            },
+        }),
+        AppRoute::recognize_with_query("/search", "q=yew")
+    );
+
+    // The route doesn't match if the query can't be deserialized
+    assert_eq!(
+        Some(AppRoute::NotFound),
+        AppRoute::recognize_with_query("/search", "page=2")
+    );
+    assert_eq!(Some(AppRoute::NotFound), AppRoute::recognize("/search"));
+
+    // Nested routes receive the query string
+    let route = AppRoute::User {
+        id: 1,
+        tab: UserRoute::Posts {
+            query: SearchQuery {
+                q: "yew".to_string(),
+                page: None,
+            },
+        },
+    };
+    assert_eq!(route.to_path(), "/users/1/posts?q=yew");
+    assert_eq!(
+        Some(route),
+        AppRoute::recognize_with_query("/users/1/posts", "?q=yew")
+    );
+}
@@ -0,0 +1,114 @@
+// TODO: remove the cfg after wasm-bindgen-test stops emitting the function unconditionally
+#![cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+
+use std::time::Duration;
+
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+use yew::functional::component;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew_router::history::{AnyHistory, History, MemoryHistory};
+use yew_router::prelude::*;
+
+mod utils;
+use utils::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, PartialEq, Routable)]
+enum Routes {
+    #[at("/")]
+    Home,
+    #[at("/article")]
+    Article,
+}
+
+fn switch(route: Routes) -> Html {
+    match route {
+        Routes::Home => html! {
+            <>
+                <div id="result">{ "Home" }</div>
+                <Link<Routes> classes="article" to={Routes::Article}>{ "Article" }</Link<Routes>>
+                <div style="height: 5000px;" />
+            </>
+        },
+        Routes::Article => html! {
+            <>
+                <div id="result">{ "Article" }</div>
+                <div style="height: 3000px;" />
+                <h2 id="comments">{ "Comments" }</h2>
+                <div style="height: 5000px;" />
+            </>
+        },
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct RootProps {
+    history: AnyHistory,
+}
+
+#[component(Root)]
+fn root(props: &RootProps) -> Html {
+    html! {
+        <Router history={props.history.clone()}>
+            <ScrollRestoration />
+            <Switch<Routes> render={switch} />
+        </Router>
+    }
+}
+
+fn scroll_y() -> f64 {
+    gloo::utils::window().scroll_y().unwrap()
+}
+
+// Waits for the scroll events to be dispatched.
+async fn settle() {
+    sleep(Duration::from_millis(100)).await;
+}
+
+#[test]
+async fn scroll_restoration() {
+    let history = AnyHistory::from(MemoryHistory::new());
+    yew::Renderer::<Root>::with_root_and_props(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+        RootProps {
+            history: history.clone(),
+        },
+    )
+    .render();
+    settle().await;
+    assert_eq!("Home", obtain_result_by_id("result"));
+
+    // The position is saved when the entry is left, even before the scroll events are
+    // dispatched.
+    gloo::utils::window().scroll_to_with_x_and_y(0.0, 500.0);
+
+    // Pushing scrolls to the top.
+    click("a.article");
+    settle().await;
+    assert_eq!("Article", obtain_result_by_id("result"));
+    assert_eq!(0.0, scroll_y());
+
+    // The position is kept in the session storage for when the page is reloaded.
+    let storage = gloo::utils::window().session_storage().unwrap().unwrap();
+    let saved = (0..storage.length().unwrap())
+        .filter_map(|i| storage.key(i).unwrap())
+        .filter(|key| key.starts_with("yew-router-scroll:"))
+        .filter_map(|key| storage.get_item(&key).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(saved, ["0,500"]);
+
+    // Going back restores the position.
+    history.back();
+    settle().await;
+    assert_eq!("Home", obtain_result_by_id("result"));
+    assert_eq!(500.0, scroll_y());
+
+    // The element targeted by the hash is scrolled into view.
+    history.forward();
+    settle().await;
+    history.push("/article#comments");
+    settle().await;
+    assert!(scroll_y() >= 3000.0);
+}
@@ -37,3 +37,12 @@ pub fn link_href(selector: &str) -> String {
         .get_attribute("href")
         .expect("No href attribute")
 }
+
+#[allow(dead_code)]
+pub fn obtain_result_by_selector(selector: &str) -> String {
+    gloo::utils::document()
+        .query_selector(selector)
+        .expect("Failed to run query selector")
+        .unwrap_or_else(|| panic!("No result found for {selector}"))
+        .inner_html()
+}
@@ -28,10 +28,14 @@ where
         name = "mount",
         skip(props),
     )]
-    pub(crate) fn mount_with_props(host: Element, props: Rc<COMP::Properties>) -> Self {
+    pub(crate) fn mount_with_props(
+        host: Element,
+        props: Rc<COMP::Properties>,
+        id_prefix: Option<&str>,
+    ) -> Self {
         clear_element(&host);
         let app = Self {
-            scope: Scope::new(None),
+            scope: Scope::new_root(id_prefix),
         };
         let hosting_root = BSubtree::create_root(&host);
         let _ = app
@@ -88,6 +92,7 @@ fn clear_element(host: &Element) {
 mod feat_hydration {
     use super::*;
     use crate::dom_bundle::Fragment;
+    use crate::hydration::HydrationMismatchPolicy;
 
     impl<COMP> AppHandle<COMP>
     where
@@ -96,15 +101,23 @@ mod feat_hydration {
         #[tracing::instrument(
             level = tracing::Level::DEBUG,
             name = "hydrate",
-            skip(props),
+            skip(props, mismatch_policy),
         )]
-        pub(crate) fn hydrate_with_props(host: Element, props: Rc<COMP::Properties>) -> Self {
+        pub(crate) fn hydrate_with_props(
+            host: Element,
+            props: Rc<COMP::Properties>,
+            id_prefix: Option<&str>,
+            mismatch_policy: HydrationMismatchPolicy,
+            progressive_hydration: bool,
+        ) -> Self {
             let app = Self {
-                scope: Scope::new(None),
+                scope: Scope::new_root(id_prefix),
             };
 
             let mut fragment = Fragment::collect_children(&host);
             let hosting_root = BSubtree::create_root(&host);
+            hosting_root.set_hydration_mismatch_policy(mismatch_policy);
+            hosting_root.set_progressive_hydration(progressive_hydration);
 
             let mut previous_next_sibling = None;
             app.scope.hydrate_in_place(
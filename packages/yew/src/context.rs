@@ -71,9 +71,19 @@ impl<T: Clone + PartialEq> ContextProvider<T> {
             .iter()
             .map(|(_, v)| v.clone())
             .collect();
-        for consumer in consumers {
-            consumer.emit(self.context.clone());
-        }
+        let notify = || {
+            for consumer in consumers {
+                consumer.emit(self.context.clone());
+            }
+        };
+
+        #[cfg(feature = "devtools")]
+        crate::devtools::profiler::with_cause(
+            crate::devtools::profiler::RenderCause::Context,
+            notify,
+        );
+        #[cfg(not(feature = "devtools"))]
+        notify();
     }
 }
 
@@ -0,0 +1,246 @@
+//! Inspection of mounted components, for building developer tools.
+//!
+//! This module is available with the `devtools` feature. It lists the components that are
+//! currently mounted, formats their properties and the state of their hooks, and notifies
+//! subscribers when components are created, rendered and destroyed. Renders can be recorded with
+//! the [`profiler`].
+//!
+//! ```
+//! use yew::devtools::{self, ComponentEvent};
+//! use yew::prelude::*;
+//!
+//! let subscription = devtools::subscribe(Callback::from(|event: ComponentEvent| {
+//!     if let ComponentEvent::Rendered { id } = event {
+//!         let _props = devtools::props(id);
+//!     }
+//! }));
+//!
+//! // Events are no longer delivered once the subscription is dropped.
+//! drop(subscription);
+//! ```
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::{Rc, Weak};
+
+use crate::callback::Callback;
+use crate::functional::HookContext;
+use crate::html::AnyScope;
+
+pub mod profiler;
+
+/// A component mounted in the component tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentNode {
+    /// The id of the component.
+    pub id: usize,
+    /// The type name of the component.
+    pub type_name: &'static str,
+    /// The mounted children of the component, in the order they were created.
+    pub children: Vec<ComponentNode>,
+}
+
+/// The state of a hook of a function component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookState {
+    /// The type name of the state kept by the hook.
+    pub type_name: &'static str,
+    /// The value returned by the hook which keeps the state, formatted with its `Debug`
+    /// implementation when the component last rendered.
+    ///
+    /// When a hook keeps several states, e.g. a custom hook calling other hooks, the value it
+    /// returns is formatted with its last state. Values which do not implement `Debug` and
+    /// `Clone`, or whose type is generic in the component, are not formatted, in which case only
+    /// the [`type_name`](Self::type_name) of the state is available.
+    pub value: Option<String>,
+}
+
+/// A lifecycle event of a component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentEvent {
+    /// The component has been created.
+    Created {
+        /// The id of the component.
+        id: usize,
+    },
+    /// The component has rendered.
+    Rendered {
+        /// The id of the component.
+        id: usize,
+    },
+    /// The component has been destroyed.
+    Destroyed {
+        /// The id of the component.
+        id: usize,
+    },
+}
+
+/// A subscription to component events created with [`subscribe`].
+///
+/// Events are delivered until the subscription is dropped.
+#[derive(Debug)]
+#[must_use = "events are not delivered once the subscription is dropped"]
+pub struct Subscription {
+    id: usize,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        // The registry may already be gone if the subscription is dropped when the thread exits.
+        let _ = REGISTRY.try_with(|m| m.borrow_mut().subscribers.remove(&self.id));
+    }
+}
+
+struct Component {
+    type_name: &'static str,
+    parent: Option<usize>,
+    debug_props: Rc<dyn Fn() -> Option<String>>,
+}
+
+#[derive(Default)]
+struct Registry {
+    components: BTreeMap<usize, Component>,
+    hooks: HashMap<usize, Weak<RefCell<HookContext>>>,
+    subscribers: BTreeMap<usize, Callback<ComponentEvent>>,
+    next_subscriber_id: usize,
+}
+
+thread_local! {
+    static REGISTRY: RefCell<Registry> = RefCell::default();
+}
+
+fn notify(event: ComponentEvent) {
+    // Subscribers are cloned so they can subscribe or inspect components while being notified.
+    let subscribers =
+        REGISTRY.with(|m| m.borrow().subscribers.values().cloned().collect::<Vec<_>>());
+
+    for subscriber in subscribers {
+        subscriber.emit(event);
+    }
+}
+
+pub(crate) fn component_created(scope: &AnyScope, debug_props: Rc<dyn Fn() -> Option<String>>) {
+    let id = scope.get_id();
+    REGISTRY.with(|m| {
+        m.borrow_mut().components.insert(
+            id,
+            Component {
+                type_name: scope.get_type_name(),
+                parent: scope.get_parent().map(AnyScope::get_id),
+                debug_props,
+            },
+        )
+    });
+
+    notify(ComponentEvent::Created { id });
+}
+
+pub(crate) fn component_rendered(id: usize) {
+    notify(ComponentEvent::Rendered { id });
+}
+
+pub(crate) fn component_destroyed(id: usize) {
+    REGISTRY.with(|m| {
+        let mut m = m.borrow_mut();
+        m.components.remove(&id);
+        m.hooks.remove(&id);
+    });
+
+    notify(ComponentEvent::Destroyed { id });
+}
+
+pub(crate) fn hooks_created(id: usize, hook_ctx: Weak<RefCell<HookContext>>) {
+    REGISTRY.with(|m| m.borrow_mut().hooks.insert(id, hook_ctx));
+}
+
+/// Returns the mounted components as a tree.
+///
+/// The roots are the components mounted by a [`Renderer`](crate::Renderer) and components whose
+/// parent is no longer mounted.
+pub fn component_tree() -> Vec<ComponentNode> {
+    REGISTRY.with(|m| {
+        let m = m.borrow();
+
+        let mut children = HashMap::<Option<usize>, Vec<usize>>::new();
+        for (id, component) in m.components.iter() {
+            let parent = component
+                .parent
+                .filter(|parent| m.components.contains_key(parent));
+            children.entry(parent).or_default().push(*id);
+        }
+
+        fn collect(
+            m: &Registry,
+            children: &HashMap<Option<usize>, Vec<usize>>,
+            parent: Option<usize>,
+        ) -> Vec<ComponentNode> {
+            children
+                .get(&parent)
+                .into_iter()
+                .flatten()
+                .map(|id| ComponentNode {
+                    id: *id,
+                    type_name: m.components[id].type_name,
+                    children: collect(m, children, Some(*id)),
+                })
+                .collect()
+        }
+
+        collect(&m, &children, None)
+    })
+}
+
+/// Returns the properties of a mounted component formatted with their `Debug` implementation.
+///
+/// Returns [`None`] if the component is not mounted, is rendering, or its properties do not
+/// implement `Debug`.
+pub fn props(id: usize) -> Option<String> {
+    // The formatter is called outside of the registry as formatting may run arbitrary code.
+    let debug_props = REGISTRY.with(|m| {
+        m.borrow()
+            .components
+            .get(&id)
+            .map(|m| m.debug_props.clone())
+    })?;
+
+    debug_props()
+}
+
+/// Returns the state of the hooks of a mounted function component.
+///
+/// Returns [`None`] if the component is not a mounted function component or is rendering.
+pub fn hooks(id: usize) -> Option<Vec<HookState>> {
+    let hook_ctx = REGISTRY.with(|m| m.borrow().hooks.get(&id).and_then(Weak::upgrade))?;
+    let states = {
+        let hook_ctx = hook_ctx.try_borrow().ok()?;
+        hook_ctx
+            .state_type_names()
+            .iter()
+            .enumerate()
+            .map(|(pos, &type_name)| (type_name, hook_ctx.state_debug(pos)))
+            .collect::<Vec<_>>()
+    };
+
+    // The formatters are called outside of the hook context as formatting may run arbitrary code.
+    Some(
+        states
+            .into_iter()
+            .map(|(type_name, debug)| HookState {
+                type_name,
+                value: debug.map(|m| m()),
+            })
+            .collect(),
+    )
+}
+
+/// Subscribes to lifecycle events of all components.
+pub fn subscribe(callback: Callback<ComponentEvent>) -> Subscription {
+    REGISTRY.with(|m| {
+        let mut m = m.borrow_mut();
+        let id = m.next_subscriber_id;
+        m.next_subscriber_id += 1;
+        m.subscribers.insert(id, callback);
+
+        Subscription { id }
+    })
+}
@@ -0,0 +1,363 @@
+//! Recording of component renders, for finding components that render too often.
+//!
+//! While profiling, every render of a component and every update of its properties is recorded
+//! with its duration and the causes that led to it. A recording can be exported in the
+//! [Chrome trace event format](https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU)
+//! and opened in the performance panel of the browser or in [Perfetto](https://ui.perfetto.dev).
+//!
+//! ```
+//! use yew::devtools::profiler::{self, RenderCause};
+//!
+//! profiler::start();
+//! // Interact with the application.
+//! let profile = profiler::stop();
+//!
+//! for entry in profile.entries() {
+//!     for cause in entry.causes.iter() {
+//!         if let RenderCause::Props { changed_fields } = cause {
+//!             let _ = (entry.type_name, changed_fields);
+//!         }
+//!     }
+//! }
+//!
+//! let _trace = profile.to_chrome_trace();
+//! ```
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+
+/// The reason a component was rendered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenderCause {
+    /// The component was rendered for the first time.
+    FirstRender,
+    /// A message was sent to the component, or the state of a hook of a function component has
+    /// changed.
+    Message,
+    /// A function component was re-rendered with
+    /// [`use_force_update`](crate::functional::use_force_update).
+    ForceUpdate,
+    /// A context consumed by the component has changed.
+    Context,
+    /// The properties of the component have changed.
+    Props {
+        /// The names of the fields that differ from the previous properties.
+        ///
+        /// Only fields of properties that derive [`Properties`](crate::html::Properties) and
+        /// implement `PartialEq` are reported.
+        changed_fields: Vec<&'static str>,
+    },
+    /// A suspension the component was waiting for has been resumed.
+    Suspension,
+}
+
+impl RenderCause {
+    fn is_message(&self) -> bool {
+        matches!(self, Self::Message | Self::ForceUpdate | Self::Context)
+    }
+}
+
+impl fmt::Display for RenderCause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FirstRender => write!(f, "first render"),
+            Self::Message => write!(f, "message"),
+            Self::ForceUpdate => write!(f, "force update"),
+            Self::Context => write!(f, "context"),
+            Self::Props { changed_fields } if changed_fields.is_empty() => write!(f, "props"),
+            Self::Props { changed_fields } => write!(f, "props ({})", changed_fields.join(", ")),
+            Self::Suspension => write!(f, "suspension"),
+        }
+    }
+}
+
+/// The kind of work recorded by a [`ProfileEntry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunKind {
+    /// The component was rendered.
+    Render,
+    /// New properties were passed to the component, which decided whether to render.
+    PropsUpdate,
+}
+
+/// A render or properties update of a component recorded while profiling.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileEntry {
+    /// The id of the component.
+    pub id: usize,
+    /// The type name of the component.
+    pub type_name: &'static str,
+    /// The kind of work that was recorded.
+    pub kind: RunKind,
+    /// The time the work started, in milliseconds since profiling started.
+    pub start: f64,
+    /// The time the work took, in milliseconds.
+    pub duration: f64,
+    /// The causes of the work.
+    ///
+    /// Renders list every cause since the previous render of the component. Properties updates
+    /// list the changed properties, if they have changed.
+    pub causes: Vec<RenderCause>,
+}
+
+/// A recording created with [`start`] and [`stop`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Profile {
+    entries: Vec<ProfileEntry>,
+}
+
+impl Profile {
+    /// The recorded entries, in the order the work has finished.
+    pub fn entries(&self) -> &[ProfileEntry] {
+        &self.entries
+    }
+
+    /// Exports the recording in the Chrome trace event format.
+    pub fn to_chrome_trace(&self) -> String {
+        let mut s = String::from(r#"{"traceEvents":["#);
+
+        for (i, m) in self.entries.iter().enumerate() {
+            if i > 0 {
+                s.push(',');
+            }
+
+            let cat = match m.kind {
+                RunKind::Render => "render",
+                RunKind::PropsUpdate => "props_update",
+            };
+
+            s.push_str(r#"{"name":"#);
+            write_json_str(&mut s, m.type_name);
+            // Timestamps are in microseconds.
+            let _ = write!(
+                s,
+                r#","cat":"{cat}","ph":"X","ts":{},"dur":{},"pid":1,"tid":1,"args":{{"id":{},"causes":["#,
+                m.start * 1000.0,
+                m.duration * 1000.0,
+                m.id
+            );
+
+            for (i, cause) in m.causes.iter().enumerate() {
+                if i > 0 {
+                    s.push(',');
+                }
+                write_json_str(&mut s, &cause.to_string());
+            }
+
+            s.push_str("]}}");
+        }
+
+        s.push_str("]}");
+        s
+    }
+}
+
+fn write_json_str(s: &mut String, value: &str) {
+    s.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => s.push_str(r#"\""#),
+            '\\' => s.push_str(r"\\"),
+            c if c.is_control() => {
+                let _ = write!(s, "\\u{:04x}", c as u32);
+            }
+            c => s.push(c),
+        }
+    }
+    s.push('"');
+}
+
+#[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+struct Clock {
+    origin: f64,
+}
+
+#[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+impl Clock {
+    fn new() -> Self {
+        Self {
+            origin: Self::now(),
+        }
+    }
+
+    fn now() -> f64 {
+        web_sys::window()
+            .and_then(|m| m.performance())
+            .map(|m| m.now())
+            .unwrap_or_else(js_sys::Date::now)
+    }
+
+    fn elapsed(&self) -> f64 {
+        Self::now() - self.origin
+    }
+}
+
+#[cfg(not(all(target_arch = "wasm32", not(target_os = "wasi"))))]
+struct Clock {
+    origin: std::time::Instant,
+}
+
+#[cfg(not(all(target_arch = "wasm32", not(target_os = "wasi"))))]
+impl Clock {
+    fn new() -> Self {
+        Self {
+            origin: std::time::Instant::now(),
+        }
+    }
+
+    fn elapsed(&self) -> f64 {
+        self.origin.elapsed().as_secs_f64() * 1000.0
+    }
+}
+
+struct Recording {
+    clock: Clock,
+    entries: Vec<ProfileEntry>,
+    /// Causes of renders that have been requested but have not run yet.
+    pending: HashMap<usize, Vec<RenderCause>>,
+}
+
+thread_local! {
+    static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+    static CAUSE: RefCell<Option<RenderCause>> = const { RefCell::new(None) };
+}
+
+/// Starts recording renders.
+///
+/// A recording that is already in progress is discarded.
+pub fn start() {
+    RECORDING.with(|m| {
+        *m.borrow_mut() = Some(Recording {
+            clock: Clock::new(),
+            entries: Vec::new(),
+            pending: HashMap::new(),
+        })
+    });
+}
+
+/// Stops recording renders and returns the recording.
+///
+/// Returns an empty [`Profile`] if no recording is in progress.
+pub fn stop() -> Profile {
+    let recording = RECORDING.with(|m| m.borrow_mut().take());
+
+    Profile {
+        entries: recording.map(|m| m.entries).unwrap_or_default(),
+    }
+}
+
+/// Returns whether renders are being recorded.
+pub fn is_profiling() -> bool {
+    RECORDING.with(|m| m.borrow().is_some())
+}
+
+fn with_recording(f: impl FnOnce(&mut Recording)) {
+    RECORDING.with(|m| {
+        if let Some(m) = m.borrow_mut().as_mut() {
+            f(m)
+        }
+    })
+}
+
+/// Attributes the renders requested by `f` to `cause` instead of the cause given by the requester.
+pub(crate) fn with_cause<R>(cause: RenderCause, f: impl FnOnce() -> R) -> R {
+    let prev = CAUSE.with(|m| m.replace(Some(cause)));
+    let result = f();
+    CAUSE.with(|m| *m.borrow_mut() = prev);
+    result
+}
+
+pub(crate) fn render_requested(id: usize, cause: RenderCause) {
+    with_recording(|m| {
+        let cause = CAUSE.with(|c| c.borrow().clone()).unwrap_or(cause);
+        let causes = m.pending.entry(id).or_default();
+        if !causes.contains(&cause) {
+            causes.push(cause);
+        }
+    })
+}
+
+pub(crate) fn props_changed(id: usize, fields: Vec<&'static str>) {
+    with_recording(|m| {
+        let causes = m.pending.entry(id).or_default();
+        match causes
+            .iter_mut()
+            .find(|m| matches!(m, RenderCause::Props { .. }))
+        {
+            Some(RenderCause::Props { changed_fields }) => {
+                for field in fields {
+                    if !changed_fields.contains(&field) {
+                        changed_fields.push(field);
+                    }
+                }
+            }
+            _ => causes.push(RenderCause::Props {
+                changed_fields: fields,
+            }),
+        }
+    })
+}
+
+/// Discards the causes of messages that have been handled without rendering.
+pub(crate) fn messages_discarded(id: usize) {
+    with_recording(|m| {
+        if let Some(causes) = m.pending.get_mut(&id) {
+            causes.retain(|m| !m.is_message());
+        }
+    })
+}
+
+/// Returns the current time if renders are being recorded.
+pub(crate) fn now() -> Option<f64> {
+    RECORDING.with(|m| m.borrow().as_ref().map(|m| m.clock.elapsed()))
+}
+
+pub(crate) fn rendered(id: usize, type_name: &'static str, start: f64, first_render: bool) {
+    with_recording(|m| {
+        let mut causes = m.pending.remove(&id).unwrap_or_default();
+        if first_render {
+            causes.insert(0, RenderCause::FirstRender);
+        }
+
+        let duration = m.clock.elapsed() - start;
+        m.entries.push(ProfileEntry {
+            id,
+            type_name,
+            kind: RunKind::Render,
+            start,
+            duration,
+            causes,
+        });
+    })
+}
+
+pub(crate) fn props_updated(id: usize, type_name: &'static str, start: f64, schedule_render: bool) {
+    with_recording(|m| {
+        let causes = match m.pending.get_mut(&id) {
+            Some(causes) => {
+                let props: Vec<_> = causes
+                    .iter()
+                    .filter(|m| matches!(m, RenderCause::Props { .. }))
+                    .cloned()
+                    .collect();
+                // The properties are only a cause of the next render if the component renders.
+                if !schedule_render {
+                    causes.retain(|m| !matches!(m, RenderCause::Props { .. }));
+                }
+                props
+            }
+            None => Vec::new(),
+        };
+
+        let duration = m.clock.elapsed() - start;
+        m.entries.push(ProfileEntry {
+            id,
+            type_name,
+            kind: RunKind::PropsUpdate,
+            start,
+            duration,
+            causes,
+        });
+    })
+}
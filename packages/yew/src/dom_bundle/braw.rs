@@ -152,14 +152,28 @@ mod feat_hydration {
     impl Hydratable for VRaw {
         fn hydrate(
             self,
-            _root: &BSubtree,
-            _parent_scope: &AnyScope,
+            root: &BSubtree,
+            parent_scope: &AnyScope,
             parent: &Element,
             fragment: &mut Fragment,
             prev_next_sibling: &mut Option<DynamicDomSlot>,
         ) -> Self::Bundle {
             let collectable = Collectable::Raw;
-            let fallback_fragment = Fragment::collect_between(fragment, &collectable, parent);
+            let fallback_fragment =
+                Fragment::collect_between(root, parent_scope, fragment, &collectable, parent);
+
+            // The server-rendered markup is missing, so the html is rendered on the client.
+            if fallback_fragment.is_empty() && !self.html.is_empty() {
+                let slot = DomSlot::create(fallback_fragment.sibling_at_end().cloned());
+                let (slot, bundle) = self.attach(root, parent_scope, parent, slot);
+                if let Some(prev_next_sibling) = prev_next_sibling {
+                    prev_next_sibling.reassign(slot);
+                }
+                *prev_next_sibling = None;
+
+                return bundle;
+            }
+
             let first_child = fallback_fragment.iter().next().cloned();
 
             if let (Some(first_child), prev_next_sibling) = (&first_child, prev_next_sibling) {
@@ -3,6 +3,10 @@
 use gloo::utils::document;
 use web_sys::Element;
 
+#[cfg(feature = "hydration")]
+use self::feat_hydration::EventReplay;
+#[cfg(feature = "hydration")]
+pub(super) use self::feat_hydration::outside_replay_boundary;
 #[cfg(feature = "hydration")]
 use super::Fragment;
 use super::{BNode, BSubtree, DomSlot, Reconcilable, ReconcileTarget};
@@ -15,7 +19,7 @@ enum Fallback {
     Bundle(BNode),
     /// Suspense Fallback with Hydration Fragment being rendered as placeholder.
     #[cfg(feature = "hydration")]
-    Fragment(Fragment),
+    Fragment(Fragment, Option<EventReplay>),
 }
 
 /// The bundle implementation to [VSuspense]
@@ -45,7 +49,7 @@ impl ReconcileTarget for BSuspense {
                     }
 
                     #[cfg(feature = "hydration")]
-                    Fallback::Fragment(fragment) => {
+                    Fallback::Fragment(fragment, _) => {
                         fragment.detach(root, parent, parent_to_detach);
                     }
                 }
@@ -63,7 +67,7 @@ impl ReconcileTarget for BSuspense {
         match self.fallback.as_ref() {
             Some(Fallback::Bundle(bundle)) => bundle.shift(next_parent, slot),
             #[cfg(feature = "hydration")]
-            Some(Fallback::Fragment(fragment)) => fragment.shift(next_parent, slot),
+            Some(Fallback::Fragment(fragment, _)) => fragment.shift(next_parent, slot),
             None => self.children_bundle.shift(next_parent, slot),
         }
     }
@@ -171,7 +175,7 @@ impl Reconcilable for VSuspense {
                         vfallback.reconcile_node(root, parent_scope, parent, slot, bundle)
                     }
                     #[cfg(feature = "hydration")]
-                    Fallback::Fragment(fragment) => match fragment.front().cloned() {
+                    Fallback::Fragment(fragment, _) => match fragment.front().cloned() {
                         Some(m) => DomSlot::at(m),
                         None => slot,
                     },
@@ -201,12 +205,16 @@ impl Reconcilable for VSuspense {
             }
             // Freshly unsuspended. Detach fallback from the DOM, then shift children into it.
             (false, Some(_)) => {
+                #[cfg(feature = "hydration")]
+                let mut replay = None;
+
                 match suspense.fallback.take() {
                     Some(Fallback::Bundle(bundle)) => {
                         bundle.detach(root, parent, false);
                     }
                     #[cfg(feature = "hydration")]
-                    Some(Fallback::Fragment(fragment)) => {
+                    Some(Fallback::Fragment(fragment, event_replay)) => {
+                        replay = event_replay.map(EventReplay::finish);
                         fragment.detach(root, parent, false);
                     }
                     None => {
@@ -215,7 +223,17 @@ impl Reconcilable for VSuspense {
                 };
 
                 children_bundle.shift(parent, slot.clone());
-                children.reconcile_node(root, parent_scope, parent, slot, children_bundle)
+                let slot =
+                    children.reconcile_node(root, parent_scope, parent, slot, children_bundle);
+
+                // Interactions with the server-rendered content are replayed once the hydrated
+                // content is in place.
+                #[cfg(feature = "hydration")]
+                if let Some(replay) = replay {
+                    replay();
+                }
+
+                slot
             }
         }
     }
@@ -223,10 +241,264 @@ impl Reconcilable for VSuspense {
 
 #[cfg(feature = "hydration")]
 mod feat_hydration {
+    use std::cell::RefCell;
+    use std::fmt;
+    use std::rc::Rc;
+
+    use gloo::events::{EventListener, EventListenerOptions, EventListenerPhase};
+    use js_sys::{Array, Function, Reflect};
+    use wasm_bindgen::{JsCast, JsValue};
+    use web_sys::{
+        Event, HtmlElement, HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement, Node,
+    };
+
     use super::*;
     use crate::dom_bundle::{DynamicDomSlot, Fragment, Hydratable};
+    use crate::scheduler;
+    use crate::suspense::BaseSuspense;
     use crate::virtual_dom::Collectable;
 
+    /// Events that are captured on the server-rendered content of a suspense that is hydrating,
+    /// and whether their default action is prevented until they are replayed.
+    const REPLAYED_EVENTS: &[(&str, bool)] = &[
+        ("click", true),
+        ("dblclick", true),
+        ("auxclick", true),
+        ("contextmenu", true),
+        ("submit", true),
+        ("keydown", false),
+        ("keyup", false),
+        ("input", false),
+        ("change", false),
+    ];
+
+    thread_local! {
+        /// The hydrated nodes of the suspense an event is being replayed in.
+        static REPLAY_BOUNDARY: RefCell<Vec<Node>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Returns whether `element` is outside of the suspense an event is being replayed in.
+    ///
+    /// Listeners outside of the suspense have received the original event already.
+    pub(in crate::dom_bundle) fn outside_replay_boundary(element: &Element) -> bool {
+        REPLAY_BOUNDARY.with(|m| {
+            let boundary = m.borrow();
+            !boundary.is_empty() && !boundary.iter().any(|m| m.contains(Some(element)))
+        })
+    }
+
+    /// Returns whether an event of `event_type` on `target` has an effect that is lost if it is
+    /// not replayed.
+    fn needs_replay(event_type: &str, target: &Node) -> bool {
+        match event_type {
+            // Keys and edits only matter to the fields they are entered into.
+            "keydown" | "keyup" | "input" | "change" => {
+                target.has_type::<HtmlInputElement>()
+                    || target.has_type::<HtmlTextAreaElement>()
+                    || target.has_type::<HtmlSelectElement>()
+                    || target
+                        .dyn_ref::<HtmlElement>()
+                        .is_some_and(HtmlElement::is_content_editable)
+            }
+            _ => true,
+        }
+    }
+
+    /// The server-rendered nodes of a suspense and the clones that are being hydrated in their
+    /// place.
+    struct HydratingNodes {
+        server: Vec<Node>,
+        hydrating: Vec<Node>,
+    }
+
+    impl HydratingNodes {
+        /// Finds the hydrating node that corresponds to a server-rendered node.
+        fn find(&self, target: &Node) -> Option<Node> {
+            let (index, server) = self
+                .server
+                .iter()
+                .enumerate()
+                .find(|(_, m)| m.contains(Some(target)))?;
+
+            let mut target = match target.dyn_ref::<Element>() {
+                Some(_) => target.clone(),
+                None if target == server => target.clone(),
+                None => target.parent_node()?,
+            };
+
+            // Elements are looked up by their position among the elements of their parent as
+            // hydration may add or merge text nodes.
+            let mut path = Vec::new();
+            while &target != server {
+                let element = target.dyn_ref::<Element>()?;
+                path.push(
+                    std::iter::successors(element.previous_element_sibling(), |m| {
+                        m.previous_element_sibling()
+                    })
+                    .count() as u32,
+                );
+                target = target.parent_node()?;
+            }
+
+            path.into_iter()
+                .rev()
+                .try_fold(self.hydrating[index].clone(), |node, index| {
+                    node.dyn_ref::<Element>()?
+                        .children()
+                        .item(index)
+                        .map(Node::from)
+                })
+        }
+    }
+
+    /// Captures interactions with the server-rendered content of a suspense while its children
+    /// are hydrated.
+    ///
+    /// The suspense is hydrated ahead of other suspenses once the user interacts with it, and the
+    /// captured events are replayed on the hydrated content. Only the server-rendered elements of
+    /// the suspense are listened to and the captured events keep propagating, so listeners
+    /// outside of the suspense receive them as usual.
+    pub(super) struct EventReplay {
+        nodes: Rc<HydratingNodes>,
+        events: Rc<RefCell<Vec<(Event, Node)>>>,
+        _listeners: Vec<EventListener>,
+    }
+
+    impl fmt::Debug for EventReplay {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("EventReplay").finish_non_exhaustive()
+        }
+    }
+
+    impl EventReplay {
+        fn new(boundary_id: usize, server: Vec<Node>, hydrating: Vec<Node>) -> Self {
+            let listened: Vec<Element> = server
+                .iter()
+                .filter_map(|m| m.dyn_ref::<Element>().cloned())
+                .collect();
+            let nodes = Rc::new(HydratingNodes { server, hydrating });
+            let events: Rc<RefCell<Vec<(Event, Node)>>> = Rc::default();
+
+            let listeners = listened
+                .iter()
+                .flat_map(|element| {
+                    REPLAYED_EVENTS
+                        .iter()
+                        .map(|&(event_type, prevent_default)| {
+                            let nodes = nodes.clone();
+                            let events = events.clone();
+                            let options = EventListenerOptions {
+                                phase: EventListenerPhase::Capture,
+                                passive: false,
+                            };
+
+                            EventListener::new_with_options(
+                                element,
+                                event_type,
+                                options,
+                                move |event| {
+                                    let Some(target) = event
+                                        .target()
+                                        .and_then(|m| m.dyn_into::<Node>().ok())
+                                        .filter(|m| needs_replay(event_type, m))
+                                        .and_then(|m| nodes.find(&m))
+                                    else {
+                                        return;
+                                    };
+
+                                    if prevent_default {
+                                        event.prevent_default();
+                                    }
+                                    events.borrow_mut().push((event.clone(), target));
+
+                                    scheduler::prioritize_hydration(boundary_id);
+                                    scheduler::start();
+                                },
+                            )
+                        })
+                })
+                .collect();
+
+            Self {
+                nodes,
+                events,
+                _listeners: listeners,
+            }
+        }
+
+        /// Stops capturing events and returns a function that replays them.
+        ///
+        /// This has to be called before the server-rendered content is removed, so the focus
+        /// can be moved to the hydrated content.
+        pub fn finish(self) -> impl FnOnce() {
+            let Self { nodes, events, .. } = self;
+
+            let focused = document()
+                .active_element()
+                .and_then(|m| nodes.find(&m))
+                .and_then(|m| m.dyn_into::<HtmlElement>().ok());
+
+            move || {
+                if let Some(focused) = focused {
+                    let _ = focused.focus();
+                }
+
+                let events = events.take();
+                if events.is_empty() {
+                    return;
+                }
+
+                let previous = REPLAY_BOUNDARY.replace(nodes.hydrating.clone());
+                for (event, target) in events {
+                    replay_event(&nodes.hydrating, &event, &target);
+                }
+                REPLAY_BOUNDARY.set(previous);
+            }
+        }
+    }
+
+    fn replay_event(boundary: &[Node], event: &Event, target: &Node) {
+        // Values entered into server-rendered fields are carried over.
+        if let Some(from) = event.target() {
+            if let (Some(from), Some(to)) = (
+                from.dyn_ref::<HtmlInputElement>(),
+                target.dyn_ref::<HtmlInputElement>(),
+            ) {
+                to.set_value(&from.value());
+                to.set_checked(from.checked());
+            } else if let (Some(from), Some(to)) = (
+                from.dyn_ref::<HtmlTextAreaElement>(),
+                target.dyn_ref::<HtmlTextAreaElement>(),
+            ) {
+                to.set_value(&from.value());
+            } else if let (Some(from), Some(to)) = (
+                from.dyn_ref::<HtmlSelectElement>(),
+                target.dyn_ref::<HtmlSelectElement>(),
+            ) {
+                to.set_value(&from.value());
+            }
+        }
+
+        // The same kind of event is created with `new event.constructor(event.type, event)`.
+        let replayed = Reflect::get(event, &JsValue::from_str("constructor"))
+            .ok()
+            .and_then(|m| m.dyn_into::<Function>().ok())
+            .and_then(|m| Reflect::construct(&m, &Array::of2(&event.type_().into(), event)).ok())
+            .and_then(|m| m.dyn_into::<Event>().ok());
+
+        let Some(replayed) = replayed else {
+            return;
+        };
+
+        // The replayed event stops bubbling when it leaves the suspense.
+        let _stop = boundary
+            .iter()
+            .find(|m| m.contains(Some(target)))
+            .map(|m| EventListener::new(m, event.type_(), |event| event.stop_propagation()));
+
+        let _ = target.dispatch_event(&replayed);
+    }
+
     impl Hydratable for VSuspense {
         fn hydrate(
             self,
@@ -241,7 +513,41 @@ mod feat_hydration {
                 .expect("failed to create detached element");
 
             let collectable = Collectable::Suspense;
-            let fallback_fragment = Fragment::collect_between(fragment, &collectable, parent);
+            let mut fallback_fragment =
+                Fragment::collect_between(root, parent_scope, fragment, &collectable, parent);
+
+            // A suspense that has been streamed out of order and whose children have not been
+            // swapped in yet only contains the fallback after its placeholder.
+            let placeholder = fallback_fragment
+                .front()
+                .and_then(|m| m.dyn_ref::<Element>())
+                .filter(|m| m.has_attribute("data-yew-pending"))
+                .cloned();
+
+            if let Some(placeholder) = placeholder {
+                // The placeholder is removed so the swap script leaves the hydrated suspense
+                // alone if the children are resolved on the server later on.
+                fallback_fragment.pop_front();
+                parent.remove_child(&placeholder).unwrap();
+
+                // There is nothing to hydrate against, so the children are rendered while the
+                // fallback from the server stays in place.
+                let (child_slot, children_bundle) =
+                    self.children
+                        .attach(root, parent_scope, &detached_parent, DomSlot::at_end());
+
+                if let Some(previous_next_sibling) = previous_next_sibling {
+                    previous_next_sibling.reassign(child_slot);
+                }
+                *previous_next_sibling = None;
+
+                return BSuspense {
+                    children_bundle,
+                    detached_parent,
+                    key: self.key,
+                    fallback: Some(Fallback::Fragment(fallback_fragment, None)),
+                };
+            }
 
             let mut nodes = fallback_fragment.deep_clone();
 
@@ -249,6 +555,17 @@ mod feat_hydration {
                 detached_parent.append_child(node).unwrap();
             }
 
+            let replay = parent_scope
+                .try_downcast::<BaseSuspense>()
+                .filter(|_| root.progressive_hydration())
+                .map(|m| {
+                    EventReplay::new(
+                        m.id,
+                        fallback_fragment.iter().cloned().collect(),
+                        nodes.iter().cloned().collect(),
+                    )
+                });
+
             // Even if initially suspended, these children correspond to the first non-suspended
             // content Refer to VSuspense::render_to_string
             let children_bundle = self.children.hydrate(
@@ -259,10 +576,7 @@ mod feat_hydration {
                 previous_next_sibling,
             );
 
-            // We trim all leading text nodes before checking as it's likely these are whitespaces.
-            nodes.trim_start_text_nodes();
-
-            assert!(nodes.is_empty(), "expected end of suspense, found node.");
+            nodes.check_end(root, parent_scope, &detached_parent, "suspense");
 
             BSuspense {
                 children_bundle,
@@ -271,7 +585,7 @@ mod feat_hydration {
 
                 // We start hydration with the BSuspense being suspended.
                 // A subsequent render will resume the BSuspense if not needed to be suspended.
-                fallback: Some(Fallback::Fragment(fallback_fragment)),
+                fallback: Some(Fallback::Fragment(fallback_fragment, replay)),
             }
         }
     }
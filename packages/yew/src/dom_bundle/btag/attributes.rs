@@ -185,20 +185,13 @@ impl Attributes {
     ///
     /// Hydration assumes the DOM already matches the server-rendered HTML, so
     /// attributes are left untouched: re-writing them would needlessly trigger
-    /// side effects. In debug builds we only assert that the existing attribute
-    /// matches the expected value. Properties are not reflected in the HTML, so
-    /// they are always set.
+    /// side effects. Differing attributes are reported by the hydrating tag in
+    /// debug builds. Properties are not reflected in the HTML, so they are always
+    /// set.
     #[cfg(feature = "hydration")]
     fn hydrate_set(el: &Element, key: &str, value: &AttributeOrProperty) {
-        match value {
-            AttributeOrProperty::Attribute(value) => {
-                debug_assert_eq!(
-                    el.get_attribute(key).as_deref(),
-                    Some(value.as_ref()),
-                    "attribute `{key}` does not match the server-rendered value during hydration",
-                );
-            }
-            AttributeOrProperty::Property(_) => Self::set(el, key, value),
+        if let AttributeOrProperty::Property(_) = value {
+            Self::set(el, key, value);
         }
     }
 
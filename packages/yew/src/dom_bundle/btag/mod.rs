@@ -352,7 +352,12 @@ mod feat_hydration {
     use web_sys::Node;
 
     use super::*;
-    use crate::dom_bundle::{DynamicDomSlot, Fragment, Hydratable, node_type_str};
+    use crate::dom_bundle::{
+        DynamicDomSlot, Fragment, Hydratable, describe_element, describe_node,
+    };
+    #[cfg(debug_assertions)]
+    use crate::hydration::AttributeMismatch;
+    use crate::hydration::HydrationMismatch;
 
     impl Hydratable for VTag {
         fn hydrate(
@@ -365,30 +370,13 @@ mod feat_hydration {
         ) -> Self::Bundle {
             let tag_name = self.tag().to_owned();
 
-            let Self {
-                inner,
-                listeners,
-                attributes,
-                node_ref,
-                key,
-            } = self;
-
             // We trim all text nodes as it's likely these are whitespaces.
             fragment.trim_start_text_nodes();
 
-            let node = fragment
-                .pop_front()
-                .unwrap_or_else(|| panic!("expected element of type {tag_name}, found EOF."));
-
-            assert_eq!(
-                node.node_type(),
-                Node::ELEMENT_NODE,
-                "expected element, found node type {}.",
-                node_type_str(&node),
-            );
-            let el = node.dyn_into::<Element>().expect("expected an element.");
-
-            {
+            let is_same_kind = |node: &Node| {
+                let Some(el) = node.dyn_ref::<Element>() else {
+                    return false;
+                };
                 let el_tag_name = el.tag_name();
                 let parent_namespace = _parent.namespace_uri();
 
@@ -399,19 +387,107 @@ mod feat_hydration {
                     .is_none_or(|ns| ns == HTML_NAMESPACE);
 
                 if should_compare_case_insensitive {
-                    // Case-insensitive comparison for HTML elements
-                    assert!(
-                        tag_name.eq_ignore_ascii_case(&el_tag_name),
-                        "expected element of kind {tag_name}, found {el_tag_name}.",
-                    );
+                    tag_name.eq_ignore_ascii_case(&el_tag_name)
                 } else {
-                    // Case-sensitive comparison for namespaced elements (SVG, MathML)
-                    assert_eq!(
-                        el_tag_name, tag_name,
-                        "expected element of kind {tag_name}, found {el_tag_name}.",
+                    el_tag_name == tag_name
+                }
+            };
+
+            let el = match fragment.front().cloned() {
+                Some(m) if is_same_kind(&m) => {
+                    fragment.pop_front();
+                    m.unchecked_into::<Element>()
+                }
+                found => {
+                    root.report_hydration_mismatch(HydrationMismatch::new(
+                        parent_scope,
+                        describe_element(&tag_name, self.attributes.iter()),
+                        found.as_ref().map(describe_node),
+                    ));
+
+                    // The element is rendered on the client in place of the mismatched node.
+                    // Comments are kept as they may mark the content of a following sibling.
+                    if let Some(m) = found.filter(|m| m.node_type() != Node::COMMENT_NODE) {
+                        fragment.pop_front();
+                        _parent.remove_child(&m).unwrap();
+                    }
+
+                    let slot =
+                        DomSlot::create(fragment.front().or(fragment.sibling_at_end()).cloned());
+                    let (slot, bundle) = self.attach(root, parent_scope, _parent, slot);
+                    if let Some(prev_next_sibling) = prev_next_sibling {
+                        prev_next_sibling.reassign(slot);
+                    }
+                    *prev_next_sibling = None;
+
+                    return bundle;
+                }
+            };
+
+            let Self {
+                inner,
+                listeners,
+                attributes,
+                node_ref,
+                key,
+            } = self;
+
+            // Attributes are not written during hydration, so differences are only
+            // looked for in debug builds.
+            #[cfg(debug_assertions)]
+            {
+                let mut mismatches = attributes
+                    .iter()
+                    .filter_map(|(name, value)| {
+                        let server = el.get_attribute(name);
+                        (server.as_deref() != Some(value)).then(|| AttributeMismatch {
+                            name: name.to_owned(),
+                            server,
+                            client: Some(value.to_owned()),
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                // Attributes only rendered on the server. The value and checked state of inputs
+                // are rendered as attributes on the server, but are set as properties here.
+                let is_input = matches!(inner, VTagInner::Input(_));
+                for name in el
+                    .get_attribute_names()
+                    .iter()
+                    .filter_map(|m| m.as_string())
+                {
+                    let is_client = attributes
+                        .iter()
+                        .any(|(m, _)| m.eq_ignore_ascii_case(&name));
+                    let is_input_field = is_input && (name == "value" || name == "checked");
+                    if !is_client && !is_input_field {
+                        mismatches.push(AttributeMismatch {
+                            server: el.get_attribute(&name),
+                            name,
+                            client: None,
+                        });
+                    }
+                }
+
+                if !mismatches.is_empty() {
+                    let mut mismatch = HydrationMismatch::new(
+                        parent_scope,
+                        describe_element(&tag_name, attributes.iter()),
+                        Some(describe_node(&el)),
                     );
+                    mismatch.attributes = mismatches;
+                    root.report_hydration_mismatch(mismatch);
+
+                    for m in mismatches.iter() {
+                        match m.client {
+                            Some(ref value) => el.set_attribute(&m.name, value),
+                            None => el.remove_attribute(&m.name),
+                        }
+                        .expect("invalid attribute key");
+                    }
                 }
             }
+
             // Register listeners and attributes.
             let attributes = attributes.hydrate(root, &el);
             let listeners = listeners.apply(root, &el);
@@ -436,9 +512,7 @@ mod feat_hydration {
                         prev_next_child.reassign(DomSlot::at_end());
                     }
 
-                    nodes.trim_start_text_nodes();
-
-                    assert!(nodes.is_empty(), "expected EOF, found node.");
+                    nodes.check_end(root, parent_scope, &el, &format!("<{tag_name}>"));
 
                     BTagInner::Other { child_bundle, tag }
                 }
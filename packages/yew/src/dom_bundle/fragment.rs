@@ -4,7 +4,9 @@ use std::ops::{Deref, DerefMut};
 use wasm_bindgen::JsCast;
 use web_sys::{Element, Node};
 
-use super::{BSubtree, DomSlot};
+use super::{BSubtree, DomSlot, describe_node};
+use crate::html::AnyScope;
+use crate::hydration::HydrationMismatch;
 use crate::virtual_dom::Collectable;
 
 /// A Hydration Fragment
@@ -43,7 +45,13 @@ impl Fragment {
     }
 
     /// Collects nodes for a Component Bundle or a BSuspense.
+    ///
+    /// If the opening tag is missing, the mismatch is reported and the nodes found in its place
+    /// are skipped. If the opening tag does not follow them, an empty fragment is returned, so the
+    /// contents are rendered on the client.
     pub fn collect_between(
+        root: &BSubtree,
+        parent_scope: &AnyScope,
         collect_from: &mut Fragment,
         collect_for: &Collectable,
         parent: &Element,
@@ -65,27 +73,52 @@ impl Fragment {
         // We trim all leading text nodes as it's likely these are whitespaces.
         collect_from.trim_start_text_nodes();
 
-        let first_node = collect_from
-            .pop_front()
-            .unwrap_or_else(|| panic!("expected {} opening tag, found EOF", collect_for.name()));
+        let take_open_tag = |collect_from: &mut Fragment| {
+            let found = collect_from
+                .front()
+                .is_some_and(|m| m.node_type() == Node::COMMENT_NODE && is_open_tag(m));
+            if found {
+                collect_from.pop_front()
+            } else {
+                None
+            }
+        };
 
-        assert_eq!(
-            first_node.node_type(),
-            Node::COMMENT_NODE,
-            // TODO: improve error message with human readable node type name.
-            "expected {} start, found node type {}",
-            collect_for.name(),
-            first_node.node_type()
-        );
+        let first_node = match take_open_tag(collect_from) {
+            Some(m) => m,
+            None => {
+                root.report_hydration_mismatch(HydrationMismatch::new(
+                    parent_scope,
+                    format!("{} opening tag", collect_for.name()),
+                    collect_from.front().map(describe_node),
+                ));
+
+                // The nodes rendered on the server in place of the opening tag are removed, so
+                // they are not hydrated by the following siblings. Comments are kept as they may
+                // mark the content of a following sibling.
+                while let Some(m) = collect_from
+                    .front()
+                    .filter(|m| m.node_type() != Node::COMMENT_NODE)
+                    .cloned()
+                {
+                    collect_from.pop_front();
+                    parent.remove_child(&m).unwrap();
+                }
 
-        let mut nodes = VecDeque::new();
+                match take_open_tag(collect_from) {
+                    Some(m) => m,
+                    None => {
+                        let next_child = collect_from
+                            .front()
+                            .or(collect_from.sibling_at_end())
+                            .cloned();
+                        return Self(VecDeque::new(), next_child);
+                    }
+                }
+            }
+        };
 
-        if !is_open_tag(&first_node) {
-            panic!(
-                "expected {} opening tag, found comment node",
-                collect_for.name()
-            );
-        }
+        let mut nodes = VecDeque::new();
 
         // We remove the opening tag.
         parent.remove_child(&first_node).unwrap();
@@ -93,9 +126,15 @@ impl Fragment {
         let mut nested_layers = 1;
 
         loop {
-            let current_node = collect_from.pop_front().unwrap_or_else(|| {
-                panic!("expected {} closing tag, found EOF", collect_for.name())
-            });
+            let Some(current_node) = collect_from.pop_front() else {
+                root.report_hydration_mismatch(HydrationMismatch::new(
+                    parent_scope,
+                    format!("{} closing tag", collect_for.name()),
+                    None,
+                ));
+
+                return Self(nodes, collect_from.sibling_at_end().cloned());
+            };
 
             if current_node.node_type() == Node::COMMENT_NODE {
                 if is_open_tag(&current_node) {
@@ -118,10 +157,43 @@ impl Fragment {
             nodes.push_back(current_node);
         }
 
-        let next_child = collect_from.0.front().cloned();
+        let next_child = collect_from
+            .front()
+            .or(collect_from.sibling_at_end())
+            .cloned();
         Self(nodes, next_child)
     }
 
+    /// Checks that no nodes are left after hydrating the contents of `expected`.
+    ///
+    /// Remaining nodes are reported as a mismatch and removed.
+    pub fn check_end(
+        &mut self,
+        root: &BSubtree,
+        parent_scope: &AnyScope,
+        parent: &Element,
+        expected: &str,
+    ) {
+        // We trim all leading text nodes before checking as it's likely these are whitespaces.
+        self.trim_start_text_nodes();
+
+        let Some(found) = self.front() else {
+            return;
+        };
+
+        root.report_hydration_mismatch(HydrationMismatch::new(
+            parent_scope,
+            format!("end of {expected}"),
+            Some(describe_node(found)),
+        ));
+
+        for node in self.drain(..) {
+            parent
+                .remove_child(&node)
+                .expect("failed to remove child element");
+        }
+    }
+
     /// Remove child nodes until first non-text node.
     pub fn trim_start_text_nodes(&mut self) {
         while let Some(ref m) = self.front().cloned() {
@@ -30,6 +30,8 @@ use bnode::BNode;
 use bportal::BPortal;
 use braw::BRaw;
 use bsuspense::BSuspense;
+#[cfg(feature = "hydration")]
+use bsuspense::outside_replay_boundary;
 use btag::{BTag, Registry};
 use btext::BText;
 pub(crate) use position::{DomSlot, DynamicDomSlot};
@@ -80,7 +82,7 @@ impl Bundle {
 #[path = "."]
 mod feat_hydration {
     pub(super) use super::traits::Hydratable;
-    pub(super) use super::utils::node_type_str;
+    pub(super) use super::utils::{describe_element, describe_node};
     #[path = "./fragment.rs"]
     mod fragment;
     pub(crate) use fragment::Fragment;
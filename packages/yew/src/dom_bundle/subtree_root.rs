@@ -13,7 +13,11 @@ use web_sys::{
     AddEventListenerOptions, Element, Event, EventTarget as HtmlEventTarget, ShadowRoot,
 };
 
+#[cfg(feature = "hydration")]
+use super::outside_replay_boundary;
 use super::{Registry, test_log};
+#[cfg(feature = "hydration")]
+use crate::hydration::{HydrationMismatch, HydrationMismatchPolicy};
 use crate::virtual_dom::{Listener, ListenerKind};
 
 /// DOM-Types that capture (bubbling) events. This generally includes event targets,
@@ -251,6 +255,10 @@ impl Eq for WeakSubtree {}
 struct AppData {
     subtrees: HashSet<WeakSubtree>,
     listening: HashSet<EventDescriptor>,
+    #[cfg(feature = "hydration")]
+    hydration_mismatch_policy: HydrationMismatchPolicy,
+    #[cfg(feature = "hydration")]
+    progressive_hydration: bool,
 }
 
 impl AppData {
@@ -492,6 +500,11 @@ impl SubtreeData {
                 if event.cancel_bubble() {
                     break;
                 }
+                // Handlers outside of a suspense have received the original of a replayed event.
+                #[cfg(feature = "hydration")]
+                if outside_replay_boundary(&el) {
+                    break;
+                }
                 run_handler(subtree, &el);
             }
         }
@@ -551,4 +564,30 @@ impl BSubtree {
     pub fn brand_element(&self, el: &dyn EventGrating) {
         el.set_subtree_id(self.0.subtree_id);
     }
+
+    /// Set how hydration mismatches are handled in all subtrees of the app
+    #[cfg(feature = "hydration")]
+    pub fn set_hydration_mismatch_policy(&self, policy: HydrationMismatchPolicy) {
+        self.0.app_data.borrow_mut().hydration_mismatch_policy = policy;
+    }
+
+    /// Set whether suspenses are hydrated progressively in all subtrees of the app
+    #[cfg(feature = "hydration")]
+    pub fn set_progressive_hydration(&self, progressive: bool) {
+        self.0.app_data.borrow_mut().progressive_hydration = progressive;
+    }
+
+    /// Whether suspenses are hydrated progressively in the app
+    #[cfg(feature = "hydration")]
+    pub fn progressive_hydration(&self) -> bool {
+        self.0.app_data.borrow().progressive_hydration
+    }
+
+    /// Report a hydration mismatch according to the policy of the app
+    #[cfg(feature = "hydration")]
+    pub fn report_hydration_mismatch(&self, mismatch: HydrationMismatch) {
+        // The policy is cloned so no borrow is held while the callback runs.
+        let policy = self.0.app_data.borrow().hydration_mismatch_policy.clone();
+        policy.report(mismatch);
+    }
 }
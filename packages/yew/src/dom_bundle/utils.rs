@@ -18,6 +18,7 @@ pub(super) use test_log;
 #[cfg(feature = "hydration")]
 mod feat_hydration {
     use std::borrow::Cow;
+    use std::fmt::{Display, Write};
 
     use wasm_bindgen::JsCast;
     use web_sys::{Element, Node};
@@ -46,6 +47,47 @@ mod feat_hydration {
             _ => "unknown node".into(),
         }
     }
+
+    /// Describes an element with its attributes for hydration mismatch reports, e.g. `<div
+    /// class="a">`.
+    pub(in crate::dom_bundle) fn describe_element<K, V>(
+        tag_name: &str,
+        attributes: impl IntoIterator<Item = (K, V)>,
+    ) -> String
+    where
+        K: Display,
+        V: Display,
+    {
+        let mut s = format!("<{tag_name}");
+        for (key, value) in attributes {
+            let _ = write!(s, r#" {key}="{value}""#);
+        }
+        s.push('>');
+
+        s
+    }
+
+    /// Describes a server-rendered node for hydration mismatch reports.
+    pub(in crate::dom_bundle) fn describe_node(node: &Node) -> String {
+        match node.node_type() {
+            Node::ELEMENT_NODE => {
+                let el = node.unchecked_ref::<Element>();
+                let attributes = el
+                    .get_attribute_names()
+                    .iter()
+                    .filter_map(|m| m.as_string())
+                    .map(|m| {
+                        let value = el.get_attribute(&m).unwrap_or_default();
+                        (m, value)
+                    });
+
+                describe_element(&el.tag_name().to_lowercase(), attributes)
+            }
+            Node::TEXT_NODE => format!("text {:?}", node.text_content().unwrap_or_default()),
+            Node::COMMENT_NODE => format!("<!--{}-->", node.text_content().unwrap_or_default()),
+            _ => node_type_str(node).into_owned(),
+        }
+    }
 }
 
 #[cfg(feature = "hydration")]
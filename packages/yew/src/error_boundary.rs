@@ -0,0 +1,502 @@
+//! This module provides error boundary support.
+//!
+//! A component can fail to render by returning [`RenderError::Error`](crate::html::RenderError)
+//! from its `view` function. The error is caught by the nearest [`ErrorBoundary`], which replaces
+//! its children with a fallback UI until it is reset.
+
+use std::error::Error;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::callback::Callback;
+use crate::html::{Html, Properties};
+
+/// An error caught by an [`ErrorBoundary`].
+///
+/// This is passed to the fallback of the [`ErrorBoundary`].
+#[derive(Debug, Clone)]
+pub struct CaughtError {
+    error: Rc<dyn Error>,
+    reset: Callback<()>,
+}
+
+impl CaughtError {
+    /// Returns the error reported by the component that failed to render.
+    pub fn error(&self) -> &Rc<dyn Error> {
+        &self.error
+    }
+
+    /// Returns a callback that clears the error and renders the children of the
+    /// [`ErrorBoundary`] again.
+    pub fn reset(&self) -> Callback<()> {
+        self.reset.clone()
+    }
+}
+
+impl fmt::Display for CaughtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+/// Properties for [ErrorBoundary].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct ErrorBoundaryProps {
+    /// The Children of the current Error Boundary.
+    #[prop_or_default]
+    pub children: Html,
+
+    /// Renders the Fallback UI after a component in the children failed to render.
+    pub fallback: Callback<CaughtError, Html>,
+}
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+mod feat_csr_ssr {
+    use super::*;
+    use crate::html;
+    use crate::html::{Component, Context, Scope};
+
+    /// An error caught during server-side rendering.
+    ///
+    /// Only the message of the error is sent to the client.
+    #[derive(thiserror::Error, Debug)]
+    #[error("{0}")]
+    struct ServerRenderError(String);
+
+    // The message is stored in a `<script>` element, so it is encoded like the states of
+    // `use_prepared_state` to never close it.
+    #[cfg(feature = "ssr")]
+    fn encode_message(s: &str) -> String {
+        use base64ct::{Base64, Encoding};
+
+        let message = bincode::serde::encode_to_vec(s, bincode::config::standard())
+            .expect("failed to prepare error message");
+
+        Base64::encode_string(&message)
+    }
+
+    #[cfg(feature = "hydration")]
+    fn decode_message(s: &str) -> Option<String> {
+        use base64ct::{Base64, Encoding};
+
+        let message = Base64::decode_vec(s).ok()?;
+        bincode::serde::decode_from_slice(&message, bincode::config::standard())
+            .ok()
+            .map(|(message, _)| message)
+    }
+
+    #[cfg(not(feature = "hydration"))]
+    fn decode_message(_s: &str) -> Option<String> {
+        None
+    }
+
+    #[derive(Properties, PartialEq, Debug, Clone)]
+    pub(crate) struct BaseErrorBoundaryProps {
+        pub children: Html,
+        pub onerror: Callback<Rc<dyn Error>>,
+        /// Renders the fallback of the [`ErrorBoundary`] in place of the children during
+        /// server-side rendering.
+        #[cfg_attr(not(feature = "ssr"), allow(dead_code))]
+        pub fallback: Callback<Rc<dyn Error>, Html>,
+    }
+
+    /// The component that failed components report their errors to.
+    ///
+    /// The children of an [`ErrorBoundary`] are rendered inside this component while its fallback
+    /// is not. Therefore, errors of the fallback are caught by the next error boundary upwards.
+    #[derive(Debug)]
+    pub(crate) struct BaseErrorBoundary;
+
+    impl Component for BaseErrorBoundary {
+        type Message = Rc<dyn Error>;
+        type Properties = BaseErrorBoundaryProps;
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self
+        }
+
+        fn update(&mut self, ctx: &Context<Self>, error: Self::Message) -> bool {
+            ctx.props().onerror.emit(error);
+
+            false
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            ctx.props().children.clone()
+        }
+    }
+
+    impl BaseErrorBoundary {
+        pub(crate) fn catch(scope: &Scope<Self>, error: Rc<dyn Error>) {
+            scope.send_message(error);
+        }
+    }
+
+    /// Messages of an [`ErrorBoundary`].
+    #[derive(Debug)]
+    pub enum ErrorBoundaryMsg {
+        /// A component in the children failed to render.
+        Catch(Rc<dyn Error>),
+        /// Render the children again.
+        Reset,
+    }
+
+    /// Catch errors of components in the children and show a fallback UI instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use yew::prelude::*;
+    /// # use yew::html::RenderError;
+    /// # use yew::error_boundary::CaughtError;
+    /// #[component]
+    /// fn Content() -> HtmlResult {
+    ///     let n: u32 = "not a number".parse().map_err(RenderError::error)?;
+    ///
+    ///     Ok(html! { <span>{n}</span> })
+    /// }
+    ///
+    /// #[component]
+    /// fn App() -> Html {
+    ///     let fallback = Callback::from(|e: CaughtError| {
+    ///         let onclick = e.reset().reform(|_: MouseEvent| ());
+    ///
+    ///         html! {
+    ///             <div>
+    ///                 {format!("Something went wrong: {e}")}
+    ///                 <button {onclick}>{"Retry"}</button>
+    ///             </div>
+    ///         }
+    ///     });
+    ///
+    ///     html! {
+    ///         <ErrorBoundary {fallback}>
+    ///             <Content />
+    ///         </ErrorBoundary>
+    ///     }
+    /// }
+    /// ```
+    #[derive(Debug)]
+    pub struct ErrorBoundary {
+        error: Option<Rc<dyn Error>>,
+    }
+
+    impl Component for ErrorBoundary {
+        type Message = ErrorBoundaryMsg;
+        type Properties = ErrorBoundaryProps;
+
+        fn create(ctx: &Context<Self>) -> Self {
+            // An error caught during server-side rendering is restored to render the same fallback
+            // during hydration.
+            let error = ctx
+                .prepared_state()
+                .and_then(decode_message)
+                .map(|m| Rc::new(ServerRenderError(m)) as Rc<dyn Error>);
+
+            // On the server, the fallback is rendered after the `BaseErrorBoundary` that rendered
+            // the children, so its position is skipped for the components of the fallback to be
+            // at the same position in the component tree.
+            #[cfg(feature = "hydration")]
+            if error.is_some() {
+                ctx.link().skip_child_position();
+            }
+
+            Self { error }
+        }
+
+        fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+            match msg {
+                Self::Message::Catch(error) => {
+                    // The first error wins, other children may fail in the same render pass.
+                    if self.error.is_some() {
+                        return false;
+                    }
+                    self.error = Some(error);
+
+                    true
+                }
+                Self::Message::Reset => self.error.take().is_some(),
+            }
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            let ErrorBoundaryProps { children, fallback } = ctx.props().clone();
+            let reset = ctx.link().callback(|_| ErrorBoundaryMsg::Reset);
+
+            match self.error.clone() {
+                Some(error) => fallback.emit(CaughtError { error, reset }),
+                None => {
+                    let onerror = ctx.link().callback(ErrorBoundaryMsg::Catch);
+                    let fallback = Callback::from(move |error: Rc<dyn Error>| {
+                        fallback.emit(CaughtError {
+                            error,
+                            reset: reset.clone(),
+                        })
+                    });
+
+                    html! {
+                        <BaseErrorBoundary {onerror} {fallback}>
+                            {children}
+                        </BaseErrorBoundary>
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "ssr")]
+        fn prepare_state(&self) -> Option<String> {
+            self.error.as_ref().map(|m| encode_message(&m.to_string()))
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    mod feat_ssr {
+        use std::fmt::Write;
+
+        use futures::StreamExt;
+
+        use super::*;
+        use crate::feat_ssr::{DeferredSuspense, VTagKind};
+        use crate::platform::fmt::{BufStream, BufWriter};
+
+        impl BaseErrorBoundary {
+            /// Renders the children into a buffer first, so a subtree that fails to render can be
+            /// replaced by the fallback before anything is written.
+            pub(crate) async fn render_into_stream(
+                scope: &Scope<Self>,
+                w: &mut BufWriter,
+                props: Rc<BaseErrorBoundaryProps>,
+                hydratable: bool,
+                deferred: &DeferredSuspense,
+                parent_vtag_kind: VTagKind,
+            ) {
+                let children = {
+                    let scope = scope.clone();
+                    let props = props.clone();
+                    let deferred = deferred.clone();
+
+                    BufStream::new(move |mut w| async move {
+                        scope
+                            .render_component_into_stream(
+                                &mut w,
+                                props,
+                                hydratable,
+                                &deferred,
+                                parent_vtag_kind,
+                            )
+                            .await;
+                    })
+                    .collect::<String>()
+                    .await
+                };
+
+                let parent_scope = scope
+                    .get_parent()
+                    .expect("a BaseErrorBoundary is always rendered by an ErrorBoundary");
+
+                let error = parent_scope
+                    .try_downcast::<ErrorBoundary>()
+                    .and_then(|boundary| {
+                        let boundary = boundary.get_component()?;
+                        boundary.error.clone()
+                    });
+
+                match error {
+                    // The fallback is rendered on behalf of the ErrorBoundary, so it hydrates as
+                    // if the ErrorBoundary had rendered it in the first place.
+                    Some(error) => {
+                        props
+                            .fallback
+                            .emit(error)
+                            .render_into_stream(
+                                w,
+                                parent_scope,
+                                hydratable,
+                                deferred,
+                                parent_vtag_kind,
+                            )
+                            .await;
+                    }
+                    None => {
+                        let _ = w.write_str(&children);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub(crate) use feat_csr_ssr::BaseErrorBoundary;
+#[cfg(feature = "ssr")]
+pub(crate) use feat_csr_ssr::BaseErrorBoundaryProps;
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub use feat_csr_ssr::{ErrorBoundary, ErrorBoundaryMsg};
+
+#[cfg(not(any(feature = "ssr", feature = "csr")))]
+mod feat_no_csr_ssr {
+    use super::*;
+    use crate::component;
+
+    /// Catch errors of components in the children and show a fallback UI instead.
+    #[component]
+    pub fn ErrorBoundary(_props: &ErrorBoundaryProps) -> Html {
+        Html::default()
+    }
+}
+
+#[cfg(not(any(feature = "ssr", feature = "csr")))]
+pub use feat_no_csr_ssr::*;
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use std::rc::Rc;
+
+    use tokio::test;
+
+    use super::CaughtError;
+    use crate::ServerRenderer;
+    use crate::html::RenderError;
+    use crate::prelude::*;
+
+    #[derive(PartialEq, Properties, Debug)]
+    struct ChildProps {
+        value: &'static str,
+    }
+
+    #[component]
+    fn Child(props: &ChildProps) -> HtmlResult {
+        let n: u32 = props.value.parse().map_err(RenderError::error)?;
+
+        Ok(html! { <span>{n}</span> })
+    }
+
+    fn fallback() -> Callback<CaughtError, Html> {
+        Callback::from(|e: CaughtError| html! { <div>{format!("failed: {e}")}</div> })
+    }
+
+    #[test]
+    async fn test_error_boundary_renders_children() {
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <ErrorBoundary fallback={fallback()}>
+                    <Child value="1" />
+                    <Child value="2" />
+                </ErrorBoundary>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<span>1</span><span>2</span>");
+    }
+
+    #[test]
+    async fn test_error_boundary_renders_fallback() {
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <div>
+                    <ErrorBoundary fallback={fallback()}>
+                        <Child value="1" />
+                        <Child value="x" />
+                    </ErrorBoundary>
+                    <Child value="3" />
+                </div>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            "<div><div>failed: invalid digit found in string</div><span>3</span></div>"
+        );
+    }
+
+    #[test]
+    async fn test_error_boundary_nested() {
+        #[component]
+        fn Broken() -> HtmlResult {
+            Err(RenderError::Error(Rc::new(std::fmt::Error)))
+        }
+
+        #[component]
+        fn Comp() -> Html {
+            // The fallback of the inner boundary fails, too.
+            let inner_fallback = Callback::from(|_: CaughtError| html! { <Broken /> });
+
+            html! {
+                <ErrorBoundary fallback={fallback()}>
+                    <ErrorBoundary fallback={inner_fallback}>
+                        <Broken />
+                    </ErrorBoundary>
+                </ErrorBoundary>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            "<div>failed: an error occurred when formatting an argument</div>"
+        );
+    }
+
+    #[test]
+    async fn test_error_boundary_hydratable_state() {
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <ErrorBoundary fallback={fallback()}>
+                    <Child value="<x>" />
+                </ErrorBoundary>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new().render().await;
+
+        assert!(s.contains("<div>failed: invalid digit found in string</div>"));
+        assert!(!s.contains("BaseErrorBoundary"));
+        assert!(s.contains(
+            r#"<script type="application/x-yew-comp-state">HWludmFsaWQgZGlnaXQgZm91bmQgaW4gc3RyaW5n</script>"#
+        ));
+    }
+
+    #[test]
+    async fn test_error_boundary_state_cannot_close_script() {
+        #[derive(thiserror::Error, Debug)]
+        #[error("</script><b>injected</b>")]
+        struct Injected;
+
+        #[component]
+        fn Broken() -> HtmlResult {
+            Err(RenderError::error(Injected))
+        }
+
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <ErrorBoundary fallback={Callback::from(|_| Html::default())}>
+                    <Broken />
+                </ErrorBoundary>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new().render().await;
+
+        assert!(!s.contains("injected"));
+    }
+}
@@ -1,24 +1,32 @@
 mod use_callback;
 mod use_context;
+mod use_deferred_value;
 mod use_effect;
 mod use_force_update;
+mod use_id;
 mod use_memo;
 mod use_prepared_state;
 mod use_reducer;
 mod use_ref;
 mod use_state;
+mod use_sync_external_store;
+mod use_transition;
 
 mod use_transitive_state;
 
 pub use use_callback::*;
 pub use use_context::*;
+pub use use_deferred_value::*;
 pub use use_effect::*;
 pub use use_force_update::*;
+pub use use_id::*;
 pub use use_memo::*;
 pub use use_prepared_state::*;
 pub use use_reducer::*;
 pub use use_ref::*;
 pub use use_state::*;
+pub use use_sync_external_store::*;
+pub use use_transition::*;
 pub use use_transitive_state::*;
 
 use crate::functional::HookContext;
@@ -0,0 +1,87 @@
+use std::cell::RefCell;
+
+use super::{Hook, HookContext};
+use crate::functional::ReRender;
+use crate::scheduler;
+
+/// This hook is used to defer the rendering of an expensive part of the user interface.
+///
+/// When `value` changes, urgent renders keep returning the previous value, and the component is
+/// re-rendered with the new value in a low-priority lane, like the state updates of a transition
+/// started with [`use_transition`](super::use_transition()). Renders in the low-priority lane are
+/// interrupted by urgent updates and yield to the browser.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// pub struct SearchProps {
+///     pub query: AttrValue,
+/// }
+///
+/// #[derive(Properties, PartialEq)]
+/// pub struct ResultsProps {
+///     pub query: AttrValue,
+/// }
+///
+/// #[component]
+/// fn Results(props: &ResultsProps) -> Html {
+///     // An expensive list that depends on the query.
+///     html! { <p>{"Results for "}{&props.query}</p> }
+/// }
+///
+/// #[component]
+/// fn Search(props: &SearchProps) -> Html {
+///     let query = use_deferred_value(props.query.clone());
+///     let is_stale = query != props.query;
+///
+///     html! {
+///         <div style={is_stale.then_some("opacity: 0.5")}>
+///             <Results {query} />
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_deferred_value<T>(value: T) -> impl Hook<Output = T>
+where
+    T: 'static + Clone + PartialEq,
+{
+    struct UseDeferredValue<T> {
+        value: T,
+    }
+
+    struct DeferredValue<T> {
+        value: RefCell<T>,
+        re_render: ReRender,
+    }
+
+    impl<T> Hook for UseDeferredValue<T>
+    where
+        T: 'static + Clone + PartialEq,
+    {
+        type Output = T;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let Self { value } = self;
+            let state = ctx.next_state(|re_render| DeferredValue {
+                value: RefCell::new(value.clone()),
+                re_render,
+            });
+
+            let mut deferred = state.value.borrow_mut();
+            if *deferred != value {
+                if scheduler::in_transition() {
+                    *deferred = value;
+                } else {
+                    scheduler::start_transition(|| (state.re_render)());
+                }
+            }
+
+            deferred.clone()
+        }
+    }
+
+    UseDeferredValue { value }
+}
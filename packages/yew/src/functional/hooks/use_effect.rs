@@ -73,6 +73,7 @@ fn use_effect_base<T, D>(
     runner: impl FnOnce(&T) -> D + 'static,
     deps: T,
     effect_changed_fn: fn(Option<&T>, Option<&T>) -> bool,
+    layout: bool,
 ) -> impl Hook<Output = ()>
 where
     T: 'static,
@@ -87,6 +88,7 @@ where
         runner: F,
         deps: T,
         effect_changed_fn: fn(Option<&T>, Option<&T>) -> bool,
+        layout: bool,
     }
 
     impl<T, F, D> Hook for HookProvider<T, F, D>
@@ -102,16 +104,22 @@ where
                 runner,
                 deps,
                 effect_changed_fn,
+                layout,
             } = self;
 
-            let state = ctx.next_effect(|_| -> RefCell<UseEffectBase<T, F, D>> {
+            let initializer = |_| -> RefCell<UseEffectBase<T, F, D>> {
                 RefCell::new(UseEffectBase {
                     runner_with_deps: None,
                     destructor: None,
                     deps: None,
                     effect_changed_fn,
                 })
-            });
+            };
+            let state = if layout {
+                ctx.next_layout_effect(initializer)
+            } else {
+                ctx.next_effect(initializer)
+            };
 
             state.borrow_mut().runner_with_deps = Some((deps, runner));
         }
@@ -121,6 +129,7 @@ where
         runner,
         deps,
         effect_changed_fn,
+        layout,
     }
 }
 
@@ -172,7 +181,7 @@ where
     F: FnOnce() -> D + 'static,
     D: TearDown,
 {
-    use_effect_base(|_| f(), (), |_, _| true);
+    use_effect_base(|_| f(), (), |_, _| true, false);
 }
 
 /// This hook is similar to [`use_effect`] but it accepts dependencies.
@@ -261,5 +270,68 @@ where
     F: FnOnce(&T) -> D + 'static,
     D: TearDown,
 {
-    use_effect_base(f, deps, |lhs, rhs| lhs != rhs)
+    use_effect_base(f, deps, |lhs, rhs| lhs != rhs, false)
+}
+
+/// This hook is similar to [`use_effect`] but the callback is called synchronously after the
+/// render has been applied to the DOM, before the browser paints it.
+///
+/// It is used to read the layout of the DOM, for example the size of an element held by a
+/// [`NodeRef`](crate::NodeRef), and to update it without any flicker. Layout effects run before
+/// the effects of [`use_effect`] and block the browser from painting, so [`use_effect`] should be
+/// preferred when possible.
+///
+/// Layout effects run once the child components created by the render have been rendered, so they
+/// can read the DOM of the children. The layout effects of the children run before the ones of
+/// their parent. Layout effects are not run during server-side rendering.
+///
+/// # Example
+///
+/// ```rust
+/// use web_sys::HtmlElement;
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Tooltip() -> Html {
+///     let tooltip_ref = use_node_ref();
+///     let top = use_state_eq(|| 0);
+///
+///     {
+///         let tooltip_ref = tooltip_ref.clone();
+///         let top = top.clone();
+///         use_layout_effect(move || {
+///             if let Some(tooltip) = tooltip_ref.cast::<HtmlElement>() {
+///                 // Place the tooltip above its anchor before it is painted.
+///                 top.set(-tooltip.offset_height());
+///             }
+///         });
+///     }
+///
+///     html! {
+///         <div ref={tooltip_ref} style={format!("position: absolute; top: {}px", *top)}>
+///             {"Tooltip"}
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_layout_effect<F, D>(f: F)
+where
+    F: FnOnce() -> D + 'static,
+    D: TearDown,
+{
+    use_effect_base(|_| f(), (), |_, _| true, true);
+}
+
+/// This hook is similar to [`use_layout_effect`] but it accepts dependencies.
+///
+/// Whenever the dependencies are changed, the layout effect callback is called again.
+/// To detect changes, dependencies must implement [`PartialEq`].
+pub fn use_layout_effect_with<T, F, D>(deps: T, f: F) -> impl Hook<Output = ()>
+where
+    T: PartialEq + 'static,
+    F: FnOnce(&T) -> D + 'static,
+    D: TearDown,
+{
+    use_effect_base(f, deps, |lhs, rhs| lhs != rhs, true)
 }
@@ -19,6 +19,12 @@ impl fmt::Debug for UseForceUpdateHandle {
 impl UseForceUpdateHandle {
     /// Trigger an unconditional re-render of the associated function component
     pub fn force_update(&self) {
+        #[cfg(feature = "devtools")]
+        crate::devtools::profiler::with_cause(
+            crate::devtools::profiler::RenderCause::ForceUpdate,
+            || (self.trigger)(),
+        );
+        #[cfg(not(feature = "devtools"))]
         (self.trigger)()
     }
 }
@@ -0,0 +1,57 @@
+use super::{Hook, HookContext};
+use crate::html::AttrValue;
+
+/// This hook is used to generate a unique id that is stable across server-side rendering and
+/// hydration.
+///
+/// The id is derived from the position of the component in the component tree and from the order
+/// of the `use_id` calls in the component, so the server and the client generate the same ids even
+/// when [`Suspense`](crate::suspense::Suspense) boundaries are resolved in a different order.
+/// Ids do not change between renders.
+///
+/// Ids are unique within an application, and across the applications rendered by different
+/// `Renderer`s on the same page, as each application is given a unique prefix.
+/// Hydrated applications have no prefix by default, like the applications rendered on the server,
+/// so several applications hydrated on the same page must be given a prefix with
+/// `Renderer::id_prefix` and the same prefix on the server with `ServerRenderer::id_prefix`.
+///
+/// It is intended to be used for accessibility attributes, such as `aria-labelledby` or the `for`
+/// attribute of a `<label>`, and should not be used as a key.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn NameInput() -> Html {
+///     let id = use_id();
+///
+///     html! {
+///         <>
+///             <label for={id.clone()}>{"Name"}</label>
+///             <input id={id} type="text" />
+///         </>
+///     }
+/// }
+/// ```
+pub fn use_id() -> impl Hook<Output = AttrValue> {
+    struct UseId;
+
+    impl Hook for UseId {
+        type Output = AttrValue;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let index = ctx.id_counter;
+            ctx.id_counter += 1;
+
+            let scope = ctx.scope.clone();
+            let id = ctx
+                .next_state(move |_| AttrValue::from(format!("yew-{}-{index}", scope.tree_path())));
+
+            (*id).clone()
+        }
+    }
+
+    UseId
+}
@@ -9,6 +9,7 @@ use implicit_clone::ImplicitClone;
 use crate::Callback;
 use crate::functional::{Hook, HookContext, hook};
 use crate::html::IntoPropValue;
+use crate::scheduler;
 
 type DispatchFn<T> = Rc<dyn Fn(<T as Reducible>::Action)>;
 
@@ -26,6 +27,8 @@ where
     T: Reducible,
 {
     current_state: Rc<RefCell<Rc<T>>>,
+    /// The state with the actions dispatched in transitions, until it is rendered in a transition.
+    transition_state: Rc<RefCell<Option<Rc<T>>>>,
 
     dispatch: DispatchFn<T>,
 }
@@ -289,19 +292,39 @@ where
 
             let state = ctx.next_state(move |re_render| {
                 let val = Rc::new(RefCell::new(Rc::new(init_fn())));
+                let transition_val: Rc<RefCell<Option<Rc<T>>>> = Rc::default();
                 let should_render_fn = Rc::new(should_render_fn);
 
                 UseReducer {
                     current_state: val.clone(),
+                    transition_state: transition_val.clone(),
                     dispatch: Rc::new(move |action: T::Action| {
                         let should_render = {
                             let should_render_fn = should_render_fn.clone();
                             let mut val = val.borrow_mut();
-                            let next_val = (*val).clone().reduce(action);
-                            let should_render = should_render_fn(&next_val, &val);
-                            *val = next_val;
-
-                            should_render
+                            let mut transition_val = transition_val.borrow_mut();
+
+                            if scheduler::in_transition() {
+                                // Only renders in the transition lane see actions dispatched in
+                                // transitions.
+                                let prev_val =
+                                    transition_val.clone().unwrap_or_else(|| (*val).clone());
+                                let next_val = prev_val.clone().reduce(action);
+                                let should_render = should_render_fn(&next_val, &prev_val);
+                                *transition_val = Some(next_val);
+
+                                should_render
+                            } else {
+                                // Urgent actions are applied on top of pending transitions, which
+                                // become visible with them.
+                                let prev_val =
+                                    transition_val.take().unwrap_or_else(|| (*val).clone());
+                                let next_val = prev_val.reduce(action);
+                                let should_render = should_render_fn(&next_val, &val);
+                                *val = next_val;
+
+                                should_render
+                            }
                         };
 
                         // Currently, this triggers a render immediately, so we need to release the
@@ -313,6 +336,12 @@ where
                 }
             });
 
+            if scheduler::in_transition() {
+                if let Some(next_val) = state.transition_state.borrow_mut().take() {
+                    *state.current_state.borrow_mut() = next_val;
+                }
+            }
+
             let current_state = state.current_state.clone();
             let snapshot = state.current_state.borrow().clone();
             let dispatch = state.dispatch.clone();
@@ -0,0 +1,55 @@
+//! The client-side rendering variant with hydration. The server snapshot is rendered until the
+//! component is hydrated.
+
+use super::use_sync_external_store_base;
+use crate::Callback;
+use crate::functional::{Hook, HookContext, TearDown};
+use crate::html::RenderMode;
+
+pub(super) fn use_sync_external_store<T, S, D, G, H>(
+    subscribe: S,
+    get_snapshot: G,
+    get_server_snapshot: H,
+) -> impl Hook<Output = T>
+where
+    T: 'static + Clone + PartialEq,
+    S: 'static + FnOnce(Callback<()>) -> D,
+    D: TearDown,
+    G: 'static + Fn() -> T,
+    H: FnOnce() -> T,
+{
+    struct HookProvider<S, G, H> {
+        subscribe: S,
+        get_snapshot: G,
+        get_server_snapshot: H,
+    }
+
+    impl<T, S, D, G, H> Hook for HookProvider<S, G, H>
+    where
+        T: 'static + Clone + PartialEq,
+        S: 'static + FnOnce(Callback<()>) -> D,
+        D: TearDown,
+        G: 'static + Fn() -> T,
+        H: FnOnce() -> T,
+    {
+        type Output = T;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let hydrate = ctx.creation_mode == RenderMode::Hydration;
+
+            use_sync_external_store_base(
+                self.subscribe,
+                self.get_snapshot,
+                self.get_server_snapshot,
+                hydrate,
+            )
+            .run(ctx)
+        }
+    }
+
+    HookProvider {
+        subscribe,
+        get_snapshot,
+        get_server_snapshot,
+    }
+}
@@ -0,0 +1,63 @@
+//! The client-and-server-side rendering variant.
+
+use super::{feat_hydration, feat_ssr};
+use crate::Callback;
+use crate::functional::{Hook, HookContext, TearDown};
+use crate::html::RenderMode;
+
+pub(super) fn use_sync_external_store<T, S, D, G, H>(
+    subscribe: S,
+    get_snapshot: G,
+    get_server_snapshot: H,
+) -> impl Hook<Output = T>
+where
+    T: 'static + Clone + PartialEq,
+    S: 'static + FnOnce(Callback<()>) -> D,
+    D: TearDown,
+    G: 'static + Fn() -> T,
+    H: FnOnce() -> T,
+{
+    struct HookProvider<S, G, H> {
+        subscribe: S,
+        get_snapshot: G,
+        get_server_snapshot: H,
+    }
+
+    impl<T, S, D, G, H> Hook for HookProvider<S, G, H>
+    where
+        T: 'static + Clone + PartialEq,
+        S: 'static + FnOnce(Callback<()>) -> D,
+        D: TearDown,
+        G: 'static + Fn() -> T,
+        H: FnOnce() -> T,
+    {
+        type Output = T;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let Self {
+                subscribe,
+                get_snapshot,
+                get_server_snapshot,
+            } = self;
+
+            match ctx.creation_mode {
+                RenderMode::Ssr => {
+                    feat_ssr::use_sync_external_store(subscribe, get_snapshot, get_server_snapshot)
+                        .run(ctx)
+                }
+                _ => feat_hydration::use_sync_external_store(
+                    subscribe,
+                    get_snapshot,
+                    get_server_snapshot,
+                )
+                .run(ctx),
+            }
+        }
+    }
+
+    HookProvider {
+        subscribe,
+        get_snapshot,
+        get_server_snapshot,
+    }
+}
@@ -0,0 +1,21 @@
+//! The client-side rendering variant. This is used for client side rendering when hydration is
+//! disabled.
+
+use super::use_sync_external_store_base;
+use crate::Callback;
+use crate::functional::{Hook, TearDown};
+
+pub(super) fn use_sync_external_store<T, S, D, G, H>(
+    subscribe: S,
+    get_snapshot: G,
+    get_server_snapshot: H,
+) -> impl Hook<Output = T>
+where
+    T: 'static + Clone + PartialEq,
+    S: 'static + FnOnce(Callback<()>) -> D,
+    D: TearDown,
+    G: 'static + Fn() -> T,
+    H: FnOnce() -> T,
+{
+    use_sync_external_store_base(subscribe, get_snapshot, get_server_snapshot, false)
+}
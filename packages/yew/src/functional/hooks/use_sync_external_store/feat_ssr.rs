@@ -0,0 +1,39 @@
+//! The server-side rendering variant. This is used for server side rendering.
+
+use crate::Callback;
+use crate::functional::{Hook, HookContext, TearDown};
+
+pub(super) fn use_sync_external_store<T, S, D, G, H>(
+    subscribe: S,
+    get_snapshot: G,
+    get_server_snapshot: H,
+) -> impl Hook<Output = T>
+where
+    T: 'static + Clone + PartialEq,
+    S: 'static + FnOnce(Callback<()>) -> D,
+    D: TearDown,
+    G: 'static + Fn() -> T,
+    H: FnOnce() -> T,
+{
+    struct HookProvider<H> {
+        get_server_snapshot: H,
+    }
+
+    impl<T, H> Hook for HookProvider<H>
+    where
+        H: FnOnce() -> T,
+    {
+        type Output = T;
+
+        // The store is not subscribed to, as components are not re-rendered on the server.
+        fn run(self, _ctx: &mut HookContext) -> Self::Output {
+            (self.get_server_snapshot)()
+        }
+    }
+
+    let _ = (subscribe, get_snapshot);
+
+    HookProvider {
+        get_server_snapshot,
+    }
+}
@@ -0,0 +1,248 @@
+#[cfg(feature = "hydration")]
+mod feat_hydration;
+#[cfg(all(feature = "hydration", feature = "ssr"))]
+mod feat_hydration_ssr;
+#[cfg(not(any(feature = "hydration", feature = "ssr")))]
+mod feat_none;
+#[cfg(feature = "ssr")]
+mod feat_ssr;
+
+#[cfg(all(feature = "hydration", not(feature = "ssr")))]
+use feat_hydration::use_sync_external_store as use_sync_external_store_impl;
+#[cfg(all(feature = "ssr", feature = "hydration"))]
+use feat_hydration_ssr::use_sync_external_store as use_sync_external_store_impl;
+#[cfg(not(any(feature = "hydration", feature = "ssr")))]
+use feat_none::use_sync_external_store as use_sync_external_store_impl;
+#[cfg(all(feature = "ssr", not(feature = "hydration")))]
+use feat_ssr::use_sync_external_store as use_sync_external_store_impl;
+
+use super::TearDown;
+use crate::Callback;
+use crate::functional::Hook;
+
+/// This hook is used to subscribe to a store that is not managed by Yew.
+///
+/// It accepts three arguments:
+///
+/// - `subscribe` is called with a callback once the component has rendered for the first time. It
+///   must call the callback whenever the store changes, and return a destructor that unsubscribes
+///   from the store, like the destructor of [`use_effect`](super::use_effect()). It is called
+///   again when the component is destroyed.
+/// - `get_snapshot` returns the current value of the store. It must return equal values as long as
+///   the store has not changed.
+/// - `get_server_snapshot` returns the value of the store used during server-side rendering. It is
+///   also used while the component is hydrated, so the hydrated layout matches the server-side
+///   rendered one.
+///
+/// Every render reads a single snapshot of the store. After rendering, the snapshot is compared
+/// with the store again and the component re-renders if the store has changed in the meantime,
+/// so components reading the same store never stay out of sync.
+///
+/// # Example
+///
+/// ```rust
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use yew::prelude::*;
+///
+/// #[derive(Default)]
+/// struct Store {
+///     value: u32,
+///     listeners: Vec<Callback<()>>,
+/// }
+///
+/// thread_local! {
+///     static STORE: Rc<RefCell<Store>> = Rc::default();
+/// }
+///
+/// #[component]
+/// fn Counter() -> Html {
+///     let value = use_sync_external_store(
+///         |on_change| {
+///             STORE.with(|m| m.borrow_mut().listeners.push(on_change));
+///             || STORE.with(|m| m.borrow_mut().listeners.clear())
+///         },
+///         || STORE.with(|m| m.borrow().value),
+///         || 0,
+///     );
+///
+///     html! { <span>{value}</span> }
+/// }
+/// ```
+pub fn use_sync_external_store<T, S, D, G, H>(
+    subscribe: S,
+    get_snapshot: G,
+    get_server_snapshot: H,
+) -> impl Hook<Output = T>
+where
+    T: 'static + Clone + PartialEq,
+    S: 'static + FnOnce(Callback<()>) -> D,
+    D: TearDown,
+    G: 'static + Fn() -> T,
+    H: FnOnce() -> T,
+{
+    use_sync_external_store_impl(subscribe, get_snapshot, get_server_snapshot)
+}
+
+#[cfg(any(feature = "hydration", not(feature = "ssr")))]
+mod feat_csr {
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    use super::TearDown;
+    use crate::Callback;
+    use crate::functional::{Effect, Hook, HookContext, ReRender};
+
+    struct StoreSnapshot<T> {
+        get_snapshot: RefCell<Option<Rc<dyn Fn() -> T>>>,
+        // The snapshot returned by the latest render.
+        rendered: RefCell<Option<T>>,
+        // Whether the server snapshot is rendered until the component is hydrated.
+        hydrating: Cell<bool>,
+        re_render: ReRender,
+    }
+
+    impl<T> StoreSnapshot<T>
+    where
+        T: PartialEq,
+    {
+        /// Re-renders the component if the store has changed since the latest render.
+        fn check(&self) {
+            // The getter is called without borrowing the state, as it may run arbitrary code.
+            let get_snapshot = match self.get_snapshot.borrow().clone() {
+                Some(m) => m,
+                None => return,
+            };
+            let snapshot = get_snapshot();
+
+            if self.rendered.borrow().as_ref() != Some(&snapshot) {
+                (self.re_render)();
+            }
+        }
+    }
+
+    struct ExternalStore<T, S, D>
+    where
+        D: TearDown,
+    {
+        snapshot: Rc<StoreSnapshot<T>>,
+        subscribe: RefCell<Option<S>>,
+        destructor: RefCell<Option<D>>,
+    }
+
+    impl<T, S, D> Effect for ExternalStore<T, S, D>
+    where
+        T: 'static + PartialEq,
+        S: FnOnce(Callback<()>) -> D,
+        D: TearDown,
+    {
+        fn rendered(&self) {
+            self.snapshot.hydrating.set(false);
+
+            let subscribe = self.subscribe.borrow_mut().take();
+            if let Some(subscribe) = subscribe {
+                let snapshot = Rc::downgrade(&self.snapshot);
+                let on_change = Callback::from(move |_| {
+                    if let Some(snapshot) = snapshot.upgrade() {
+                        snapshot.check();
+                    }
+                });
+
+                let destructor = subscribe(on_change);
+                *self.destructor.borrow_mut() = Some(destructor);
+            }
+
+            // The store may have changed before the subscription, or the server snapshot has been
+            // rendered during hydration.
+            self.snapshot.check();
+        }
+    }
+
+    impl<T, S, D> Drop for ExternalStore<T, S, D>
+    where
+        D: TearDown,
+    {
+        fn drop(&mut self) {
+            if let Some(destructor) = self.destructor.get_mut().take() {
+                destructor.tear_down();
+            }
+        }
+    }
+
+    /// The client-side rendering variant, shared by hydration.
+    ///
+    /// The component renders the server snapshot until it is hydrated if `hydrate` is `true`.
+    pub(super) fn use_sync_external_store_base<T, S, D, G, H>(
+        subscribe: S,
+        get_snapshot: G,
+        get_server_snapshot: H,
+        hydrate: bool,
+    ) -> impl Hook<Output = T>
+    where
+        T: 'static + Clone + PartialEq,
+        S: 'static + FnOnce(Callback<()>) -> D,
+        D: TearDown,
+        G: 'static + Fn() -> T,
+        H: FnOnce() -> T,
+    {
+        struct HookProvider<S, G, H> {
+            subscribe: S,
+            get_snapshot: G,
+            get_server_snapshot: H,
+            hydrate: bool,
+        }
+
+        impl<T, S, D, G, H> Hook for HookProvider<S, G, H>
+        where
+            T: 'static + Clone + PartialEq,
+            S: 'static + FnOnce(Callback<()>) -> D,
+            D: TearDown,
+            G: 'static + Fn() -> T,
+            H: FnOnce() -> T,
+        {
+            type Output = T;
+
+            fn run(self, ctx: &mut HookContext) -> Self::Output {
+                let Self {
+                    subscribe,
+                    get_snapshot,
+                    get_server_snapshot,
+                    hydrate,
+                } = self;
+
+                let state = ctx.next_effect(move |re_render| ExternalStore {
+                    snapshot: Rc::new(StoreSnapshot {
+                        get_snapshot: RefCell::new(None),
+                        rendered: RefCell::new(None),
+                        hydrating: Cell::new(hydrate),
+                        re_render,
+                    }),
+                    subscribe: RefCell::new(Some(subscribe)),
+                    destructor: RefCell::new(None),
+                });
+
+                let snapshot = if state.snapshot.hydrating.get() {
+                    get_server_snapshot()
+                } else {
+                    get_snapshot()
+                };
+
+                *state.snapshot.get_snapshot.borrow_mut() = Some(Rc::new(get_snapshot));
+                *state.snapshot.rendered.borrow_mut() = Some(snapshot.clone());
+
+                snapshot
+            }
+        }
+
+        HookProvider {
+            subscribe,
+            get_snapshot,
+            get_server_snapshot,
+            hydrate,
+        }
+    }
+}
+
+#[cfg(any(feature = "hydration", not(feature = "ssr")))]
+use feat_csr::use_sync_external_store_base;
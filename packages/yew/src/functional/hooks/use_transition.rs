@@ -0,0 +1,139 @@
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+
+use super::{Hook, HookContext};
+use crate::functional::ReRender;
+use crate::scheduler::{self, Runnable};
+
+/// A handle to start transitions, returned by [`use_transition`].
+#[derive(Clone)]
+pub struct StartTransition {
+    pending: Rc<Cell<bool>>,
+    re_render: ReRender,
+}
+
+impl fmt::Debug for StartTransition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StartTransition")
+            .field("pending", &self.pending.get())
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for StartTransition {
+    fn eq(&self, rhs: &Self) -> bool {
+        Rc::ptr_eq(&self.pending, &rhs.pending)
+    }
+}
+
+struct TransitionDone {
+    pending: Rc<Cell<bool>>,
+    re_render: ReRender,
+}
+
+impl Runnable for TransitionDone {
+    fn run(self: Box<Self>) {
+        if self.pending.replace(false) {
+            (self.re_render)()
+        }
+    }
+}
+
+impl StartTransition {
+    /// Runs `f` in a transition.
+    ///
+    /// Renders caused by state updates in `f` are run with a low priority: they are interrupted
+    /// by urgent updates, such as the ones caused by user input, and yield to the browser.
+    /// Until they are rendered, [`use_state`](super::use_state()) and
+    /// [`use_reducer`](super::use_reducer()) keep returning the previous state in other renders.
+    pub fn start(&self, f: impl FnOnce()) {
+        if !self.pending.replace(true) {
+            (self.re_render)();
+        }
+
+        scheduler::start_transition(f);
+
+        scheduler::push_transition_done(Box::new(TransitionDone {
+            pending: self.pending.clone(),
+            re_render: self.re_render.clone(),
+        }));
+    }
+}
+
+/// This hook is used to update the state without blocking the user interface.
+///
+/// It returns whether a transition started by the component is pending and a [`StartTransition`]
+/// handle. State updates made in [`StartTransition::start`] are rendered in a low-priority lane
+/// that is interrupted by urgent updates and yields to the browser, so the application stays
+/// responsive while an expensive part of it is rendered.
+///
+/// The transition stays pending until all the work it caused has been rendered.
+///
+/// # Example
+///
+/// ```rust
+/// use web_sys::HtmlInputElement;
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// pub struct ResultsProps {
+///     pub query: AttrValue,
+/// }
+///
+/// #[component]
+/// fn Results(props: &ResultsProps) -> Html {
+///     // An expensive list that depends on the query.
+///     html! { <p>{"Results for "}{&props.query}</p> }
+/// }
+///
+/// #[component]
+/// fn Search() -> Html {
+///     let input = use_state(AttrValue::default);
+///     let query = use_state(AttrValue::default);
+///     let (is_pending, start_transition) = use_transition();
+///
+///     let oninput = {
+///         let input = input.clone();
+///         let query = query.clone();
+///         Callback::from(move |e: InputEvent| {
+///             let value: AttrValue = e.target_unchecked_into::<HtmlInputElement>().value().into();
+///             // The input is updated immediately...
+///             input.set(value.clone());
+///             // ...while the results are rendered when there is time.
+///             let query = query.clone();
+///             start_transition.start(move || query.set(value));
+///         })
+///     };
+///
+///     html! {
+///         <>
+///             <input value={(*input).clone()} {oninput} />
+///             if is_pending {
+///                 <p>{"Updating..."}</p>
+///             }
+///             <Results query={(*query).clone()} />
+///         </>
+///     }
+/// }
+/// ```
+pub fn use_transition() -> impl Hook<Output = (bool, StartTransition)> {
+    struct UseTransition;
+
+    impl Hook for UseTransition {
+        type Output = (bool, StartTransition);
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let pending = ctx.next_state(|_| Cell::new(false));
+
+            let start_transition = StartTransition {
+                pending: pending.clone(),
+                re_render: ctx.re_render.clone(),
+            };
+
+            (pending.get(), start_transition)
+        }
+    }
+
+    UseTransition
+}
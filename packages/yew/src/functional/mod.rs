@@ -28,7 +28,7 @@ use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 
 use crate::Properties;
-#[cfg(all(feature = "hydration", feature = "ssr"))]
+#[cfg(feature = "hydration")]
 use crate::html::RenderMode;
 use crate::html::{AnyScope, BaseComponent, Context, HtmlResult};
 
@@ -84,12 +84,17 @@ pub(crate) trait Effect {
 /// A hook context to be passed to hooks.
 pub struct HookContext {
     pub(crate) scope: AnyScope,
-    #[cfg(all(feature = "hydration", feature = "ssr"))]
+    #[cfg(feature = "hydration")]
     creation_mode: RenderMode,
     re_render: ReRender,
 
     states: Vec<Rc<dyn Any>>,
     effects: Vec<Rc<dyn Effect>>,
+    layout_effects: Vec<Rc<dyn Effect>>,
+    #[cfg(feature = "devtools")]
+    state_type_names: Vec<&'static str>,
+    #[cfg(feature = "devtools")]
+    state_debug: Vec<Option<DebugHookFn>>,
 
     #[cfg(any(feature = "hydration", feature = "ssr"))]
     prepared_states: Vec<Rc<dyn PreparedState>>,
@@ -99,6 +104,8 @@ pub struct HookContext {
     #[cfg(feature = "hydration")]
     prepared_state_counter: usize,
 
+    id_counter: usize,
+
     counter: usize,
     #[cfg(debug_assertions)]
     total_hook_counter: Option<usize>,
@@ -108,21 +115,26 @@ impl HookContext {
     fn new(
         scope: AnyScope,
         re_render: ReRender,
-        #[cfg(all(feature = "hydration", feature = "ssr"))] creation_mode: RenderMode,
+        #[cfg(feature = "hydration")] creation_mode: RenderMode,
         #[cfg(feature = "hydration")] prepared_state: Option<&str>,
     ) -> RefCell<Self> {
         RefCell::new(HookContext {
             scope,
             re_render,
 
-            #[cfg(all(feature = "hydration", feature = "ssr"))]
+            #[cfg(feature = "hydration")]
             creation_mode,
 
             states: Vec::new(),
+            #[cfg(feature = "devtools")]
+            state_type_names: Vec::new(),
+            #[cfg(feature = "devtools")]
+            state_debug: Vec::new(),
 
             #[cfg(any(feature = "hydration", feature = "ssr"))]
             prepared_states: Vec::new(),
             effects: Vec::new(),
+            layout_effects: Vec::new(),
 
             #[cfg(feature = "hydration")]
             prepared_states_data: {
@@ -134,6 +146,8 @@ impl HookContext {
             #[cfg(feature = "hydration")]
             prepared_state_counter: 0,
 
+            id_counter: 0,
+
             counter: 0,
             #[cfg(debug_assertions)]
             total_hook_counter: None,
@@ -153,6 +167,8 @@ impl HookContext {
             None => {
                 let initial_state = Rc::new(initializer(self.re_render.clone()));
                 self.states.push(initial_state.clone());
+                #[cfg(feature = "devtools")]
+                self.state_type_names.push(std::any::type_name::<T>());
 
                 initial_state
             }
@@ -176,6 +192,21 @@ impl HookContext {
         t
     }
 
+    pub(crate) fn next_layout_effect<T>(&mut self, initializer: impl FnOnce(ReRender) -> T) -> Rc<T>
+    where
+        T: 'static + Effect,
+    {
+        let prev_state_len = self.states.len();
+        let t = self.next_state(initializer);
+
+        // This is a new layout effect, we add it to layout effects.
+        if self.states.len() != prev_state_len {
+            self.layout_effects.push(t.clone());
+        }
+
+        t
+    }
+
     #[cfg(any(feature = "hydration", feature = "ssr"))]
     pub(crate) fn next_prepared_state<T>(
         &mut self,
@@ -213,6 +244,7 @@ impl HookContext {
             self.prepared_state_counter = 0;
         }
 
+        self.id_counter = 0;
         self.counter = 0;
     }
 
@@ -255,13 +287,80 @@ impl HookContext {
         }
     }
 
+    fn run_layout_effects(&self) {
+        for effect in self.layout_effects.iter() {
+            effect.rendered();
+        }
+    }
+
     fn drain_states(&mut self) {
         // We clear the effects as these are also references to states.
         self.effects.clear();
+        self.layout_effects.clear();
 
         for state in self.states.drain(..) {
             drop(state);
         }
+        #[cfg(feature = "devtools")]
+        {
+            self.state_type_names.clear();
+            self.state_debug.clear();
+        }
+    }
+
+    /// Returns whether the component is rendered on the server or hydrated from the markup
+    /// rendered on the server.
+    ///
+    /// In both cases, the states of `use_prepared_state` are prepared on the server.
+    pub fn is_server_rendered(&self) -> bool {
+        #[cfg(feature = "hydration")]
+        {
+            self.creation_mode != RenderMode::Render
+        }
+        #[cfg(all(feature = "ssr", not(feature = "hydration")))]
+        {
+            true
+        }
+        #[cfg(not(any(feature = "hydration", feature = "ssr")))]
+        {
+            false
+        }
+    }
+
+    /// Type names of the states of the hooks, in the order the hooks are called.
+    #[cfg(feature = "devtools")]
+    pub(crate) fn state_type_names(&self) -> &[&'static str] {
+        &self.state_type_names
+    }
+
+    /// Formatters of the states of the hooks, in the order the hooks are called.
+    ///
+    /// A state has no formatter if the value returned by the hook which created it does not
+    /// implement `Debug` and `Clone`.
+    #[cfg(feature = "devtools")]
+    pub(crate) fn state_debug(&self, pos: usize) -> Option<DebugHookFn> {
+        self.state_debug.get(pos).cloned().flatten()
+    }
+
+    /// Returns the position of the next hook state, used by [`__hook_run`](crate::__hook_run).
+    #[cfg(feature = "devtools")]
+    #[doc(hidden)]
+    pub fn __hook_pos(&self) -> usize {
+        self.counter
+    }
+
+    /// Records `debug` as the formatter of the last state used by a hook called at `start`, if
+    /// the hook used any state.
+    #[cfg(feature = "devtools")]
+    #[doc(hidden)]
+    pub fn __debug_hook(&mut self, start: usize, debug: Option<DebugHookFn>) {
+        let Some(pos) = self.counter.checked_sub(1).filter(|pos| *pos >= start) else {
+            return;
+        };
+        if self.state_debug.len() <= pos {
+            self.state_debug.resize(pos + 1, None);
+        }
+        self.state_debug[pos] = debug;
     }
 
     #[cfg(not(feature = "ssr"))]
@@ -294,6 +393,101 @@ impl fmt::Debug for HookContext {
     }
 }
 
+/// Formats the value returned by a hook.
+#[cfg(feature = "devtools")]
+#[doc(hidden)]
+pub type DebugHookFn = Rc<dyn Fn() -> String>;
+
+/// Wrapper used by the hook calls of function components and hooks to format the values
+/// returned by hooks.
+///
+/// `(&DebugHook(value)).debug_hook()` resolves to [`ViaDebugHook`] if the value implements
+/// `Debug` and `Clone` and falls back to [`ViaNoDebugHook`] otherwise.
+#[cfg(feature = "devtools")]
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct DebugHook<'a, T>(pub &'a T);
+
+/// Formats values that implement `Debug`.
+#[cfg(feature = "devtools")]
+#[doc(hidden)]
+pub trait ViaDebugHook {
+    /// Returns a formatter of a clone of the value.
+    fn debug_hook(&self) -> Option<DebugHookFn>;
+}
+
+#[cfg(feature = "devtools")]
+impl<T: fmt::Debug + Clone + 'static> ViaDebugHook for DebugHook<'_, T> {
+    fn debug_hook(&self) -> Option<DebugHookFn> {
+        let value = self.0.clone();
+        Some(Rc::new(move || format!("{value:?}")))
+    }
+}
+
+/// Fallback for values that do not implement `Debug`.
+#[cfg(feature = "devtools")]
+#[doc(hidden)]
+pub trait ViaNoDebugHook {
+    /// Returns [`None`] as the value can not be formatted.
+    fn debug_hook(&self) -> Option<DebugHookFn>;
+}
+
+#[cfg(feature = "devtools")]
+impl<T> ViaNoDebugHook for &DebugHook<'_, T> {
+    fn debug_hook(&self) -> Option<DebugHookFn> {
+        None
+    }
+}
+
+/// Runs a hook called by a function component or a hook.
+///
+/// With the `devtools` feature, the value returned by the hook is recorded to inspect the state
+/// of the hooks of mounted components.
+#[cfg(feature = "devtools")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hook_run {
+    ($hook:expr, $ctx:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::functional::{ViaDebugHook as _, ViaNoDebugHook as _};
+        let start = $ctx.__hook_pos();
+        let value = $crate::functional::Hook::run($hook, $ctx);
+        $ctx.__debug_hook(start, (&$crate::functional::DebugHook(&value)).debug_hook());
+        value
+    }};
+}
+
+/// Runs a hook called by a function component or a hook.
+#[cfg(not(feature = "devtools"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hook_run {
+    ($hook:expr, $ctx:expr) => {
+        $crate::functional::Hook::run($hook, $ctx)
+    };
+}
+
+/// Expands to its input with the `devtools` feature, and to nothing otherwise.
+///
+/// The macros of `yew-macro` can't check the features of `yew`, so they gate the code generated
+/// for the `devtools` feature with this macro.
+#[cfg(feature = "devtools")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __devtools {
+    ($($tt:tt)*) => {
+        $($tt)*
+    };
+}
+
+/// Expands to its input with the `devtools` feature, and to nothing otherwise.
+#[cfg(not(feature = "devtools"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __devtools {
+    ($($tt:tt)*) => {};
+}
+
 /// Trait that allows a struct to act as Function Component.
 pub trait FunctionProvider {
     /// Properties for the Function Component.
@@ -320,7 +514,7 @@ where
     T: FunctionProvider,
 {
     _never: std::marker::PhantomData<T>,
-    hook_ctx: RefCell<HookContext>,
+    hook_ctx: Rc<RefCell<HookContext>>,
 }
 
 impl<T> FunctionComponent<T>
@@ -339,16 +533,21 @@ where
             Rc::new(move || link.send_message(()))
         };
 
+        let hook_ctx = Rc::new(HookContext::new(
+            scope,
+            re_render,
+            #[cfg(feature = "hydration")]
+            ctx.creation_mode(),
+            #[cfg(feature = "hydration")]
+            ctx.prepared_state(),
+        ));
+
+        #[cfg(feature = "devtools")]
+        crate::devtools::hooks_created(ctx.link().id, Rc::downgrade(&hook_ctx));
+
         Self {
             _never: std::marker::PhantomData,
-            hook_ctx: HookContext::new(
-                scope,
-                re_render,
-                #[cfg(all(feature = "hydration", feature = "ssr"))]
-                ctx.creation_mode(),
-                #[cfg(feature = "hydration")]
-                ctx.prepared_state(),
-            ),
+            hook_ctx,
         }
     }
 
@@ -373,6 +572,12 @@ where
         hook_ctx.run_effects();
     }
 
+    /// Run Layout Effects of a function component.
+    pub fn layout_rendered(&self) {
+        let hook_ctx = self.hook_ctx.borrow();
+        hook_ctx.run_layout_effects();
+    }
+
     /// Destroys the function component.
     pub fn destroy(&self) {
         let mut hook_ctx = self.hook_ctx.borrow_mut();
@@ -12,6 +12,7 @@ use super::scope::{AnyScope, Scope};
 use crate::dom_bundle::Fragment;
 #[cfg(feature = "csr")]
 use crate::dom_bundle::{BSubtree, Bundle, DomSlot, DynamicDomSlot};
+use crate::error_boundary::BaseErrorBoundary;
 #[cfg(feature = "hydration")]
 use crate::html::RenderMode;
 use crate::html::{Html, RenderError};
@@ -151,6 +152,8 @@ pub(crate) trait Stateful {
     fn view(&self) -> HtmlResult;
     #[cfg(feature = "csr")]
     fn rendered(&mut self, first_render: bool);
+    #[cfg(feature = "csr")]
+    fn layout_rendered(&mut self, first_render: bool);
     fn destroy(&mut self);
 
     fn any_scope(&self) -> AnyScope;
@@ -163,6 +166,9 @@ pub(crate) trait Stateful {
 
     #[cfg(feature = "hydration")]
     fn creation_mode(&self) -> RenderMode;
+
+    #[cfg(feature = "devtools")]
+    fn debug_props(&self) -> Option<String>;
 }
 
 impl<COMP> Stateful for CompStateInner<COMP>
@@ -178,6 +184,11 @@ where
         self.component.rendered(&self.context, first_render)
     }
 
+    #[cfg(feature = "csr")]
+    fn layout_rendered(&mut self, first_render: bool) {
+        self.component.layout_rendered(&self.context, first_render)
+    }
+
     fn destroy(&mut self) {
         self.component.destroy(&self.context);
     }
@@ -209,6 +220,14 @@ where
         };
 
         if self.context.props != props {
+            #[cfg(feature = "devtools")]
+            if crate::devtools::profiler::is_profiling() {
+                use crate::html::Properties;
+
+                let changed_fields = self.context.props.__changed_fields(&props);
+                crate::devtools::profiler::props_changed(self.context.link().id, changed_fields);
+            }
+
             let old_props = std::mem::replace(&mut self.context.props, props);
             self.component.changed(&self.context, &old_props)
         } else {
@@ -219,6 +238,13 @@ where
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    #[cfg(feature = "devtools")]
+    fn debug_props(&self) -> Option<String> {
+        use crate::html::Properties;
+
+        self.context.props.__debug_props()
+    }
 }
 
 pub(crate) struct ComponentState {
@@ -330,6 +356,21 @@ impl<COMP: BaseComponent> Runnable for CreateRunner<COMP> {
                 #[cfg(feature = "hydration")]
                 self.prepared_state,
             ));
+
+            #[cfg(feature = "devtools")]
+            {
+                drop(current_state);
+
+                let state = Rc::downgrade(&self.scope.state);
+                crate::devtools::component_created(
+                    &self.scope.clone().into(),
+                    Rc::new(move || {
+                        let state = state.upgrade()?;
+                        let state = state.try_borrow().ok()?;
+                        state.as_ref()?.inner.debug_props()
+                    }),
+                );
+            }
         }
     }
 }
@@ -354,17 +395,34 @@ impl ComponentState {
 impl Runnable for UpdateRunner {
     fn run(self: Box<Self>) {
         if let Some(state) = self.state.borrow_mut().as_mut() {
+            let lanes = scheduler::take_update_lanes(state.comp_id);
             let schedule_render = state.update();
 
             if schedule_render {
-                scheduler::push_component_render(
-                    state.comp_id,
-                    Box::new(RenderRunner {
-                        state: self.state.clone(),
-                    }),
-                );
+                let push_render = || {
+                    scheduler::push_component_render(
+                        state.comp_id,
+                        Box::new(RenderRunner {
+                            state: self.state.clone(),
+                        }),
+                    )
+                };
+
+                // Messages sent in a transition are rendered in the transition lane, after the
+                // urgent render if there are urgent messages as well.
+                if lanes.urgent {
+                    push_render();
+                }
+                if lanes.transition {
+                    scheduler::start_transition(push_render);
+                }
                 // Only run from the scheduler, so no need to call `scheduler::start()`
             }
+
+            #[cfg(feature = "devtools")]
+            if !schedule_render {
+                crate::devtools::profiler::messages_discarded(state.comp_id);
+            }
         }
     }
 }
@@ -416,7 +474,13 @@ impl ComponentState {
 impl Runnable for DestroyRunner {
     fn run(self: Box<Self>) {
         if let Some(state) = self.state.borrow_mut().take() {
+            let comp_id = state.comp_id;
+
             state.destroy(self.parent_to_detach);
+
+            scheduler::component_destroyed(comp_id);
+            #[cfg(feature = "devtools")]
+            crate::devtools::component_destroyed(comp_id);
         }
     }
 }
@@ -426,18 +490,57 @@ pub(crate) struct RenderRunner {
 }
 
 impl ComponentState {
+    /// Renders the component and returns whether the layout has been committed.
     #[tracing::instrument(
         level = tracing::Level::DEBUG,
         skip_all,
         fields(component.id = self.comp_id)
     )]
-    fn render(&mut self, shared_state: &Shared<Option<ComponentState>>) {
+    fn render(&mut self, shared_state: &Shared<Option<ComponentState>>) -> bool {
         let view = self.inner.view();
         tracing::trace!(?view, "render result");
         match view {
-            Ok(vnode) => self.commit_render(shared_state, vnode),
-            Err(RenderError::Suspended(susp)) => self.suspend(shared_state, susp),
-        };
+            Ok(vnode) => {
+                self.commit_render(shared_state, vnode);
+                true
+            }
+            Err(RenderError::Suspended(susp)) => {
+                self.suspend(shared_state, susp);
+                false
+            }
+            Err(RenderError::Error(error)) => {
+                self.fail(error);
+                false
+            }
+        }
+    }
+
+    fn fail(&mut self, error: Rc<dyn std::error::Error>) {
+        // The component keeps its previous layout until the nearest error boundary replaces it
+        // with the fallback UI.
+        self.resume_existing_suspension();
+
+        let comp_scope = self.inner.any_scope();
+        let boundary_scope = comp_scope
+            .find_parent_scope::<BaseErrorBoundary>()
+            .unwrap_or_else(|| {
+                panic!("component rendering failed without an <ErrorBoundary />: {error}")
+            });
+
+        match self.render_state {
+            #[cfg(feature = "csr")]
+            ComponentRenderState::Render { .. } => {}
+            #[cfg(feature = "hydration")]
+            ComponentRenderState::Hydration { .. } => {}
+            // Dropping the sender completes the server-side rendering of this component with an
+            // empty layout, which is discarded by the error boundary.
+            #[cfg(feature = "ssr")]
+            ComponentRenderState::Ssr { ref mut sender } => {
+                sender.take();
+            }
+        }
+
+        BaseErrorBoundary::catch(&boundary_scope, error);
     }
 
     fn suspend(&mut self, shared_state: &Shared<Option<ComponentState>>, suspension: Suspension) {
@@ -446,6 +549,11 @@ impl ComponentState {
 
         if suspension.resumed() {
             // schedule a render immediately if suspension is resumed.
+            #[cfg(feature = "devtools")]
+            crate::devtools::profiler::render_requested(
+                self.comp_id,
+                crate::devtools::profiler::RenderCause::Suspension,
+            );
             scheduler::push_component_render(
                 self.comp_id,
                 Box::new(RenderRunner {
@@ -463,6 +571,11 @@ impl ComponentState {
             let comp_id = self.comp_id;
             let shared_state = shared_state.clone();
             suspension.listen(Callback::from(move |_| {
+                #[cfg(feature = "devtools")]
+                crate::devtools::profiler::render_requested(
+                    comp_id,
+                    crate::devtools::profiler::RenderCause::Suspension,
+                );
                 scheduler::push_component_render(
                     comp_id,
                     Box::new(RenderRunner {
@@ -523,6 +636,17 @@ impl ComponentState {
                         .expect("a resuming component must have a Suspense ancestor");
                     BaseSuspense::defer_rendered(&suspense_scope, self.comp_id, pending);
                 } else {
+                    // Layout effects run once the children are rendered, before the scheduler
+                    // gets a chance to yield to the browser.
+                    scheduler::push_component_layout_rendered(
+                        self.comp_id,
+                        Box::new(LayoutRenderedRunner {
+                            state: shared_state.clone(),
+                            first_render,
+                        }),
+                        first_render,
+                    );
+
                     scheduler::push_component_rendered(
                         self.comp_id,
                         Box::new(RenderedRunner {
@@ -562,9 +686,7 @@ impl ComponentState {
                     &mut Some(own_slot.clone()),
                 );
 
-                // We trim all text nodes before checking as it's likely these are whitespaces.
-                fragment.trim_start_text_nodes();
-                assert!(fragment.is_empty(), "expected end of component, found node");
+                fragment.check_end(root, &scope, parent, "component");
 
                 self.render_state = ComponentRenderState::Render {
                     root: root.clone(),
@@ -588,13 +710,31 @@ impl ComponentState {
 
 impl Runnable for RenderRunner {
     fn run(self: Box<Self>) {
-        let mut state = self.state.borrow_mut();
-        let state = match state.as_mut() {
+        let mut state_ref = self.state.borrow_mut();
+        let state = match state_ref.as_mut() {
             None => return, // skip for components that have already been destroyed
             Some(state) => state,
         };
 
-        state.render(&self.state);
+        #[cfg(feature = "devtools")]
+        let comp_id = state.comp_id;
+        #[cfg(feature = "devtools")]
+        let profiled = crate::devtools::profiler::now().map(|start| (start, !state.has_rendered));
+
+        let _committed = state.render(&self.state);
+
+        #[cfg(feature = "devtools")]
+        if let Some((start, first_render)) = profiled {
+            let type_name = state.inner.any_scope().get_type_name();
+            crate::devtools::profiler::rendered(comp_id, type_name, start, first_render);
+        }
+
+        #[cfg(feature = "devtools")]
+        if _committed {
+            // Subscribers are notified once the component is released, so they can inspect it.
+            drop(state_ref);
+            crate::devtools::component_rendered(comp_id);
+        }
     }
 }
 
@@ -697,8 +837,22 @@ mod feat_csr {
             } = *self;
 
             if let Some(state) = shared_state.borrow_mut().as_mut() {
+                #[cfg(feature = "devtools")]
+                let start = crate::devtools::profiler::now();
+
                 let schedule_render = state.changed(props, next_sibling_slot);
 
+                #[cfg(feature = "devtools")]
+                if let Some(start) = start {
+                    let type_name = state.inner.any_scope().get_type_name();
+                    crate::devtools::profiler::props_updated(
+                        state.comp_id,
+                        type_name,
+                        start,
+                        schedule_render,
+                    );
+                }
+
                 if schedule_render {
                     scheduler::push_component_render(
                         state.comp_id,
@@ -712,6 +866,19 @@ mod feat_csr {
         }
     }
 
+    pub(crate) struct LayoutRenderedRunner {
+        pub state: Shared<Option<ComponentState>>,
+        pub first_render: bool,
+    }
+
+    impl Runnable for LayoutRenderedRunner {
+        fn run(self: Box<Self>) {
+            if let Some(state) = self.state.borrow_mut().as_mut() {
+                state.layout_rendered(self.first_render);
+            }
+        }
+    }
+
     pub(crate) struct RenderedRunner {
         pub state: Shared<Option<ComponentState>>,
         pub first_render: bool,
@@ -741,12 +908,23 @@ mod feat_csr {
             self.first_render |= later.first_render;
         }
 
-        /// Push this onto the scheduler's `rendered` queue.
+        /// Push the deferred `layout_rendered` and `rendered` lifecycles onto the scheduler's
+        /// queues.
         pub(crate) fn schedule(self, comp_id: usize) {
             let PendingRendered {
                 state,
                 first_render,
             } = self;
+
+            scheduler::push_component_layout_rendered(
+                comp_id,
+                Box::new(LayoutRenderedRunner {
+                    state: state.clone(),
+                    first_render,
+                }),
+                false,
+            );
+
             scheduler::push_component_rendered(
                 comp_id,
                 Box::new(RenderedRunner {
@@ -759,6 +937,17 @@ mod feat_csr {
     }
 
     impl ComponentState {
+        #[tracing::instrument(
+            level = tracing::Level::DEBUG,
+            skip(self),
+            fields(component.id = self.comp_id)
+        )]
+        pub(super) fn layout_rendered(&mut self, first_render: bool) {
+            if self.suspension.is_none() {
+                self.inner.layout_rendered(first_render);
+            }
+        }
+
         #[tracing::instrument(
             level = tracing::Level::DEBUG,
             skip(self),
@@ -800,6 +989,32 @@ mod feat_csr {
 #[cfg(feature = "csr")]
 pub(crate) use feat_csr::*;
 
+#[cfg(feature = "hydration")]
+mod feat_hydration {
+    use super::*;
+    use crate::suspense::SuspensionHandle;
+
+    /// The first render of a component that is hydrated as part of a suspense boundary.
+    ///
+    /// The boundary is kept suspended by `suspension` until the component has been hydrated.
+    pub(crate) struct HydrationRunner {
+        pub render: RenderRunner,
+        pub suspension: SuspensionHandle,
+    }
+
+    impl Runnable for HydrationRunner {
+        fn run(self: Box<Self>) {
+            let Self { render, suspension } = *self;
+
+            Box::new(render).run();
+            suspension.resume();
+        }
+    }
+}
+
+#[cfg(feature = "hydration")]
+pub(crate) use feat_hydration::*;
+
 #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
 #[cfg(test)]
 mod tests {
@@ -110,6 +110,13 @@ pub trait BaseComponent: Sized + 'static {
     /// Notified after a layout is rendered.
     fn rendered(&mut self, ctx: &Context<Self>, first_render: bool);
 
+    /// Notified after the DOM has been updated by a render, before the browser paints it.
+    ///
+    /// This is called once the children of the component have been rendered, and before the
+    /// `rendered` method of the component and of its children.
+    #[expect(unused_variables)]
+    fn layout_rendered(&mut self, ctx: &Context<Self>, first_render: bool) {}
+
     /// Notified before a component is destroyed.
     fn destroy(&mut self, ctx: &Context<Self>);
 
@@ -178,6 +185,14 @@ pub trait Component: Sized + 'static {
     #[expect(unused_variables)]
     fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {}
 
+    /// The `layout_rendered` method is called after each time a Component is rendered, once its
+    /// children have been rendered, and before the browser gets a chance to paint the page.
+    ///
+    /// It is called before `rendered()`, for the children of the component first. Reading the
+    /// layout of the DOM in this method is synchronous, so keep the work done here short.
+    #[expect(unused_variables)]
+    fn layout_rendered(&mut self, ctx: &Context<Self>, first_render: bool) {}
+
     /// Prepares the state during server side rendering.
     ///
     /// This state will be sent to the client side and is available via `ctx.prepared_state()`.
@@ -220,6 +235,10 @@ where
         Component::rendered(self, ctx, first_render)
     }
 
+    fn layout_rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        Component::layout_rendered(self, ctx, first_render)
+    }
+
     fn destroy(&mut self, ctx: &Context<Self>) {
         Component::destroy(self, ctx)
     }
@@ -9,6 +9,27 @@ pub trait Properties: PartialEq {
 
     /// Entrypoint for building properties
     fn builder() -> Self::Builder;
+
+    /// Formats the properties with their [`Debug`](std::fmt::Debug) implementation, if there is
+    /// one.
+    ///
+    /// This is implemented by `#[derive(Properties)]` and used to inspect mounted components.
+    #[cfg(feature = "devtools")]
+    #[doc(hidden)]
+    fn __debug_props(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns the names of the fields that differ from `other`.
+    ///
+    /// This is implemented by `#[derive(Properties)]` and used to profile renders. Fields that do
+    /// not implement [`PartialEq`] are not compared.
+    #[cfg(feature = "devtools")]
+    #[doc(hidden)]
+    fn __changed_fields(&self, other: &Self) -> Vec<&'static str> {
+        let _ = other;
+        Vec::new()
+    }
 }
 
 #[doc(hidden)]
@@ -100,6 +121,68 @@ mod __macro {
     {
     }
 
+    /// Wrapper used by `#[derive(Properties)]` to format properties that implement `Debug`.
+    ///
+    /// `(&DebugProps(props)).debug_props()` resolves to [`ViaDebug`] if the properties implement
+    /// `Debug` and falls back to [`ViaNoDebug`] otherwise.
+    #[derive(Debug)]
+    pub struct DebugProps<'a, T>(pub &'a T);
+
+    /// Formats properties that implement `Debug`.
+    pub trait ViaDebug {
+        /// Formats the properties.
+        fn debug_props(&self) -> Option<String>;
+    }
+
+    impl<T: std::fmt::Debug> ViaDebug for DebugProps<'_, T> {
+        fn debug_props(&self) -> Option<String> {
+            Some(format!("{:?}", self.0))
+        }
+    }
+
+    /// Fallback for properties that do not implement `Debug`.
+    pub trait ViaNoDebug {
+        /// Returns [`None`] as the properties can not be formatted.
+        fn debug_props(&self) -> Option<String>;
+    }
+
+    impl<T> ViaNoDebug for &DebugProps<'_, T> {
+        fn debug_props(&self) -> Option<String> {
+            None
+        }
+    }
+
+    /// Wrapper used by `#[derive(Properties)]` to compare fields that implement `PartialEq`.
+    ///
+    /// `(&FieldEq(a, b)).field_changed()` resolves to [`ViaPartialEq`] if the field implements
+    /// `PartialEq` and falls back to [`ViaNoPartialEq`] otherwise.
+    #[derive(Debug)]
+    pub struct FieldEq<'a, T>(pub &'a T, pub &'a T);
+
+    /// Compares fields that implement `PartialEq`.
+    pub trait ViaPartialEq {
+        /// Returns whether the field has changed.
+        fn field_changed(&self) -> bool;
+    }
+
+    impl<T: PartialEq> ViaPartialEq for FieldEq<'_, T> {
+        fn field_changed(&self) -> bool {
+            self.0 != self.1
+        }
+    }
+
+    /// Fallback for fields that do not implement `PartialEq`.
+    pub trait ViaNoPartialEq {
+        /// Returns `false` as the field can not be compared.
+        fn field_changed(&self) -> bool;
+    }
+
+    impl<T> ViaNoPartialEq for &FieldEq<'_, T> {
+        fn field_changed(&self) -> bool {
+            false
+        }
+    }
+
     /// Dummy struct targeted by assertions that all props were set
     #[derive(Debug)]
     pub struct AssertAllProps;
@@ -114,6 +197,11 @@ mod __macro {
         fn builder() -> Self::Builder {
             EmptyBuilder
         }
+
+        #[cfg(feature = "devtools")]
+        fn __debug_props(&self) -> Option<String> {
+            Some("()".to_owned())
+        }
     }
 
     impl<T> Buildable<T> for EmptyBuilder {
@@ -128,4 +216,7 @@ mod __macro {
 }
 
 #[doc(hidden)]
-pub use __macro::{AllPropsFor, AssertAllProps, Buildable, HasAllProps, HasProp};
+pub use __macro::{
+    AllPropsFor, AssertAllProps, Buildable, DebugProps, FieldEq, HasAllProps, HasProp, ViaDebug,
+    ViaNoDebug, ViaNoPartialEq, ViaPartialEq,
+};
@@ -21,7 +21,12 @@ use crate::scheduler::Shared;
 #[derive(Clone)]
 pub struct AnyScope {
     type_id: TypeId,
+    type_name: &'static str,
+    #[cfg(feature = "devtools")]
+    id: usize,
     parent: Option<Rc<AnyScope>>,
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    position: Rc<TreePosition>,
     typed_scope: Rc<dyn Any>,
 }
 
@@ -35,7 +40,12 @@ impl<COMP: BaseComponent> From<Scope<COMP>> for AnyScope {
     fn from(scope: Scope<COMP>) -> Self {
         AnyScope {
             type_id: TypeId::of::<COMP>(),
+            type_name: std::any::type_name::<COMP>(),
+            #[cfg(feature = "devtools")]
+            id: scope.id,
             parent: scope.parent.clone(),
+            #[cfg(any(feature = "csr", feature = "ssr"))]
+            position: scope.position.clone(),
             typed_scope: Rc::new(scope),
         }
     }
@@ -52,6 +62,25 @@ impl AnyScope {
         &self.type_id
     }
 
+    /// Returns the type name of the linked component
+    pub fn get_type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns the id of the linked component
+    #[cfg(feature = "devtools")]
+    pub(crate) fn get_id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns the position of the linked component in the component tree.
+    ///
+    /// The position is made of the indices of the component and its parents among the components
+    /// created by their parents, separated by `-`.
+    pub(crate) fn tree_path(&self) -> &str {
+        self.arch_tree_path()
+    }
+
     /// Attempts to downcast into a typed scope
     ///
     /// # Panics
@@ -100,6 +129,9 @@ pub struct Scope<COMP: BaseComponent> {
     #[cfg(any(feature = "csr", feature = "ssr"))]
     pub(crate) state: Shared<Option<ComponentState>>,
 
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    position: Rc<TreePosition>,
+
     pub(crate) id: usize,
 }
 
@@ -121,6 +153,9 @@ impl<COMP: BaseComponent> Clone for Scope<COMP> {
             #[cfg(any(feature = "csr", feature = "ssr"))]
             state: self.state.clone(),
 
+            #[cfg(any(feature = "csr", feature = "ssr"))]
+            position: self.position.clone(),
+
             id: self.id,
         }
     }
@@ -292,7 +327,8 @@ mod feat_ssr {
     use std::fmt::Write;
 
     use super::*;
-    use crate::feat_ssr::VTagKind;
+    use crate::error_boundary::{BaseErrorBoundary, BaseErrorBoundaryProps};
+    use crate::feat_ssr::{DeferredSuspense, VTagKind};
     use crate::html::component::lifecycle::{
         ComponentRenderState, CreateRunner, DestroyRunner, RenderRunner,
     };
@@ -307,6 +343,37 @@ mod feat_ssr {
             w: &mut BufWriter,
             props: Rc<COMP::Properties>,
             hydratable: bool,
+            deferred: &DeferredSuspense,
+            parent_vtag_kind: VTagKind,
+        ) {
+            // Error boundaries need to render their children ahead of time.
+            if let Some(scope) = (self as &dyn Any).downcast_ref::<Scope<BaseErrorBoundary>>() {
+                let props = (props as Rc<dyn Any>)
+                    .downcast::<BaseErrorBoundaryProps>()
+                    .expect("props of a BaseErrorBoundary");
+                BaseErrorBoundary::render_into_stream(
+                    scope,
+                    w,
+                    props,
+                    hydratable,
+                    deferred,
+                    parent_vtag_kind,
+                )
+                .await;
+
+                return;
+            }
+
+            self.render_component_into_stream(w, props, hydratable, deferred, parent_vtag_kind)
+                .await;
+        }
+
+        pub(crate) async fn render_component_into_stream(
+            &self,
+            w: &mut BufWriter,
+            props: Rc<COMP::Properties>,
+            hydratable: bool,
+            deferred: &DeferredSuspense,
             parent_vtag_kind: VTagKind,
         ) {
             // Rust's Future implementation is stack-allocated and incurs zero runtime-cost.
@@ -337,10 +404,12 @@ mod feat_ssr {
                 collectable.write_open_tag(w);
             }
 
-            let html = rx.await.unwrap();
+            // The sender is dropped if the component fails to render. The error is reported to
+            // the nearest error boundary, which discards the output of this component.
+            let html = rx.await.unwrap_or_default();
 
             let self_any_scope = AnyScope::from(self.clone());
-            html.render_into_stream(w, &self_any_scope, hydratable, parent_vtag_kind)
+            html.render_into_stream(w, &self_any_scope, hydratable, deferred, parent_vtag_kind)
                 .await;
 
             if let Some(prepared_state) = self.get_component().unwrap().prepare_state() {
@@ -367,6 +436,12 @@ mod feat_no_csr_ssr {
     use super::*;
 
     // Skeleton code to provide public methods when no renderer are enabled.
+    impl AnyScope {
+        pub(super) fn arch_tree_path(&self) -> &str {
+            ""
+        }
+    }
+
     impl<COMP: BaseComponent> Scope<COMP> {
         pub(super) fn arch_get_component(&self) -> Option<impl Deref<Target = COMP> + '_> {
             Option::<&COMP>::None
@@ -384,7 +459,7 @@ mod feat_no_csr_ssr {
 
 #[cfg(any(feature = "ssr", feature = "csr"))]
 mod feat_csr_ssr {
-    use std::cell::{Ref, RefCell};
+    use std::cell::{Cell, Ref, RefCell};
     use std::sync::atomic::{AtomicUsize, Ordering};
 
     use super::*;
@@ -429,6 +504,48 @@ mod feat_csr_ssr {
         }
     }
 
+    /// The position of a component in the component tree.
+    ///
+    /// Components are numbered in the order they are created by their parent, which is the same
+    /// when a component tree is rendered on the server and when it is hydrated.
+    #[derive(Debug)]
+    pub(crate) struct TreePosition {
+        path: String,
+        children: Cell<usize>,
+    }
+
+    impl TreePosition {
+        /// The position of the root component of an application, prefixed with `id_prefix` to
+        /// tell the applications rendered on the same page apart.
+        pub(super) fn root(id_prefix: Option<&str>) -> Self {
+            let path = match id_prefix {
+                Some(id_prefix) => format!("{id_prefix}-0"),
+                None => "0".to_owned(),
+            };
+
+            Self {
+                path,
+                children: Cell::new(0),
+            }
+        }
+
+        fn next_child(&self) -> Self {
+            let index = self.children.get();
+            self.children.set(index + 1);
+
+            Self {
+                path: format!("{}-{index}", self.path),
+                children: Cell::new(0),
+            }
+        }
+    }
+
+    impl AnyScope {
+        pub(super) fn arch_tree_path(&self) -> &str {
+            &self.position.path
+        }
+    }
+
     static COMP_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
     impl<COMP: BaseComponent> Scope<COMP> {
@@ -436,6 +553,21 @@ mod feat_csr_ssr {
         pub(crate) fn new(parent: Option<AnyScope>) -> Self {
             let parent = parent.map(Rc::new);
 
+            let position = match parent {
+                Some(ref m) => m.position.next_child(),
+                None => TreePosition::root(None),
+            };
+
+            Self::with_position(parent, position)
+        }
+
+        /// Creates the scope of the root component of an application, whose ids generated by
+        /// [`use_id`](crate::functional::use_id) are prefixed with `id_prefix`.
+        pub(crate) fn new_root(id_prefix: Option<&str>) -> Self {
+            Self::with_position(None, TreePosition::root(id_prefix))
+        }
+
+        fn with_position(parent: Option<Rc<AnyScope>>, position: TreePosition) -> Self {
             let state = Rc::new(RefCell::new(None));
 
             let pending_messages = MsgQueue::new();
@@ -447,11 +579,19 @@ mod feat_csr_ssr {
 
                 state,
                 parent,
+                position: Rc::new(position),
 
                 id: COMP_ID_COUNTER.fetch_add(1, Ordering::SeqCst),
             }
         }
 
+        /// Skips the position of the next child of the component, for the children created
+        /// after it to keep the positions they have when the child is created.
+        #[cfg(feature = "hydration")]
+        pub(crate) fn skip_child_position(&self) {
+            self.position.next_child();
+        }
+
         #[inline]
         pub(super) fn arch_get_component(&self) -> Option<impl Deref<Target = COMP> + '_> {
             self.state.try_borrow().ok().and_then(|state_ref| {
@@ -464,6 +604,10 @@ mod feat_csr_ssr {
 
         #[inline]
         fn schedule_update(&self) {
+            // The component is hydrated before it handles messages.
+            #[cfg(feature = "hydration")]
+            scheduler::expedite_hydration(self.id);
+
             scheduler::push_component_update(Box::new(UpdateRunner {
                 state: self.state.clone(),
             }));
@@ -476,6 +620,14 @@ mod feat_csr_ssr {
         where
             T: Into<COMP::Message>,
         {
+            #[cfg(feature = "devtools")]
+            crate::devtools::profiler::render_requested(
+                self.id,
+                crate::devtools::profiler::RenderCause::Message,
+            );
+
+            scheduler::message_sent(self.id);
+
             // We are the first message in queue, so we queue the update.
             if self.pending_messages.push(msg.into()) == 1 {
                 self.schedule_update();
@@ -486,6 +638,16 @@ mod feat_csr_ssr {
         pub(super) fn arch_send_message_batch(&self, mut messages: Vec<COMP::Message>) {
             let msg_len = messages.len();
 
+            if msg_len > 0 {
+                #[cfg(feature = "devtools")]
+                crate::devtools::profiler::render_requested(
+                    self.id,
+                    crate::devtools::profiler::RenderCause::Message,
+                );
+
+                scheduler::message_sent(self.id);
+            }
+
             // The queue was empty, so we queue the update
             if self.pending_messages.append(&mut messages) == msg_len {
                 self.schedule_update();
@@ -515,7 +677,11 @@ mod feat_csr {
         pub(crate) fn test() -> Self {
             Self {
                 type_id: TypeId::of::<()>(),
+                type_name: "()",
+                #[cfg(feature = "devtools")]
+                id: usize::MAX,
                 parent: None,
+                position: Rc::new(TreePosition::root(None)),
                 typed_scope: Rc::new(()),
             }
         }
@@ -579,6 +745,10 @@ mod feat_csr {
         }
 
         pub(crate) fn reuse(&self, props: Rc<COMP::Properties>, slot: DomSlot) {
+            // The component is hydrated before its props are updated.
+            #[cfg(feature = "hydration")]
+            scheduler::expedite_hydration(self.id);
+
             if let Some(state) = self.state.borrow_mut().as_mut() {
                 match &state.render_state {
                     ComponentRenderState::Render { sibling_slot, .. } => {
@@ -655,8 +825,11 @@ mod feat_hydration {
 
     use super::*;
     use crate::dom_bundle::{BSubtree, DomSlot, DynamicDomSlot, Fragment};
-    use crate::html::component::lifecycle::{ComponentRenderState, CreateRunner, RenderRunner};
+    use crate::html::component::lifecycle::{
+        ComponentRenderState, CreateRunner, HydrationRunner, RenderRunner,
+    };
     use crate::scheduler;
+    use crate::suspense::BaseSuspense;
     use crate::virtual_dom::Collectable;
 
     impl<COMP> Scope<COMP>
@@ -690,7 +863,13 @@ mod feat_hydration {
 
             let collectable = Collectable::for_component::<COMP>();
 
-            let mut fragment = Fragment::collect_between(fragment, &collectable, &parent);
+            let mut fragment = Fragment::collect_between(
+                &root,
+                &AnyScope::from(self.clone()),
+                fragment,
+                &collectable,
+                &parent,
+            );
 
             let prepared_state = match fragment
                 .back()
@@ -715,6 +894,18 @@ mod feat_hydration {
                 prev_next_sibling.reassign(shared_slot.to_position());
             }
             *prev_next_sibling = Some(sibling_slot.clone());
+
+            // With progressive hydration, components inside a suspense are hydrated one at a
+            // time, the suspense keeps showing the server-rendered content until all of them are
+            // hydrated.
+            let suspense = root
+                .progressive_hydration()
+                .then(|| {
+                    self.get_parent()
+                        .and_then(|m| m.find_parent_scope::<BaseSuspense>())
+                })
+                .flatten();
+
             let state = ComponentRenderState::Hydration {
                 parent,
                 root,
@@ -723,18 +914,28 @@ mod feat_hydration {
                 fragment,
             };
 
-            scheduler::push_component_create(
-                self.id,
-                Box::new(CreateRunner {
-                    initial_render_state: state,
-                    props,
-                    scope: self.clone(),
-                    prepared_state,
-                }),
-                Box::new(RenderRunner {
-                    state: self.state.clone(),
-                }),
-            );
+            let create = Box::new(CreateRunner {
+                initial_render_state: state,
+                props,
+                scope: self.clone(),
+                prepared_state,
+            });
+            let first_render = RenderRunner {
+                state: self.state.clone(),
+            };
+
+            match suspense {
+                Some(suspense) => scheduler::push_component_hydrate(
+                    suspense.id,
+                    self.id,
+                    create,
+                    Box::new(HydrationRunner {
+                        render: first_render,
+                        suspension: BaseSuspense::defer_hydration(&suspense),
+                    }),
+                ),
+                None => scheduler::push_component_create(self.id, create, Box::new(first_render)),
+            }
 
             // Not guaranteed to already have the scheduler started
             scheduler::start();
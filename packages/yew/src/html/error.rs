@@ -1,13 +1,59 @@
+use std::rc::Rc;
+
 use thiserror::Error;
 
 use crate::suspense::Suspension;
 
 /// Render Error.
-#[derive(Error, Debug, Clone, PartialEq)]
+#[derive(Error, Debug, Clone)]
 pub enum RenderError {
     /// Component Rendering Suspended
     #[error("component rendering is suspended.")]
     Suspended(#[from] Suspension),
+
+    /// Component Rendering Failed
+    ///
+    /// The error is reported to the nearest [`ErrorBoundary`](crate::error_boundary::ErrorBoundary)
+    /// which replaces its children with its fallback UI.
+    ///
+    /// # Panics
+    ///
+    /// Rendering panics if the component returning this error has no `ErrorBoundary` ancestor.
+    #[error("component rendering failed: {0}")]
+    Error(Rc<dyn std::error::Error>),
+}
+
+impl RenderError {
+    /// Creates a [`RenderError::Error`] from any error type.
+    ///
+    /// This is useful to turn a fallible operation into an [`HtmlResult`](crate::HtmlResult):
+    ///
+    /// ```
+    /// # use yew::prelude::*;
+    /// # use yew::html::RenderError;
+    /// #[component]
+    /// fn Number() -> HtmlResult {
+    ///     let n: u32 = "42".parse().map_err(RenderError::error)?;
+    ///
+    ///     Ok(html! { <span>{n}</span> })
+    /// }
+    /// ```
+    pub fn error<E>(error: E) -> Self
+    where
+        E: std::error::Error + 'static,
+    {
+        Self::Error(Rc::new(error))
+    }
+}
+
+impl PartialEq for RenderError {
+    fn eq(&self, rhs: &Self) -> bool {
+        match (self, rhs) {
+            (Self::Suspended(l), Self::Suspended(r)) => l == r,
+            (Self::Error(l), Self::Error(r)) => Rc::ptr_eq(l, r),
+            _ => false,
+        }
+    }
 }
 
 /// Render Result.
@@ -0,0 +1,121 @@
+//! Diagnostics for mismatches between server-rendered markup and the client during hydration.
+//!
+//! When the markup rendered on the server does not match the layout rendered on the client, a
+//! [`HydrationMismatch`] is reported according to the [`HydrationMismatchPolicy`] set with
+//! [`Renderer::hydration_mismatch_policy`](crate::Renderer::hydration_mismatch_policy).
+
+use std::{fmt, iter};
+
+use crate::callback::Callback;
+use crate::html::AnyScope;
+
+/// An attribute that differs between the server-rendered element and the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttributeMismatch {
+    /// The name of the attribute.
+    pub name: String,
+    /// The value of the attribute in the server-rendered markup.
+    pub server: Option<String>,
+    /// The value of the attribute rendered on the client.
+    pub client: Option<String>,
+}
+
+/// A difference between the server-rendered markup and the layout rendered on the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HydrationMismatch {
+    pub(crate) component_path: Vec<&'static str>,
+    pub(crate) expected: String,
+    pub(crate) found: Option<String>,
+    pub(crate) attributes: Vec<AttributeMismatch>,
+}
+
+impl HydrationMismatch {
+    pub(crate) fn new(parent_scope: &AnyScope, expected: String, found: Option<String>) -> Self {
+        let mut component_path = iter::successors(Some(parent_scope), |m| m.get_parent())
+            .map(AnyScope::get_type_name)
+            .collect::<Vec<_>>();
+        component_path.reverse();
+
+        Self {
+            component_path,
+            expected,
+            found,
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Type names of the components from the root of the application to the component that
+    /// rendered the mismatched node.
+    pub fn component_path(&self) -> &[&'static str] {
+        &self.component_path
+    }
+
+    /// The node rendered on the client.
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// The node found in the server-rendered markup.
+    ///
+    /// Returns [`None`] if the server-rendered markup ended before the expected node.
+    pub fn found(&self) -> Option<&str> {
+        self.found.as_deref()
+    }
+
+    /// Attributes that differ between the server-rendered element and the client.
+    ///
+    /// Attributes are only compared in debug builds.
+    pub fn attributes(&self) -> &[AttributeMismatch] {
+        &self.attributes
+    }
+}
+
+impl fmt::Display for HydrationMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "hydration mismatch: expected {}, ", self.expected)?;
+        match self.found {
+            Some(ref found) => write!(f, "found {found}")?,
+            None => write!(f, "found end of server-rendered content")?,
+        }
+
+        if !self.component_path.is_empty() {
+            write!(f, "\n  in {}", self.component_path.join(" > "))?;
+        }
+
+        for m in self.attributes.iter() {
+            write!(
+                f,
+                "\n  attribute `{}`: server {:?}, client {:?}",
+                m.name, m.server, m.client
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// How a [`HydrationMismatch`] is handled.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum HydrationMismatchPolicy {
+    /// Panics with the mismatch.
+    #[default]
+    Panic,
+    /// Logs the mismatch to the console and renders the mismatched node on the client, replacing
+    /// the server-rendered markup.
+    Recover,
+    /// Calls the callback with the mismatch and renders the mismatched node on the client,
+    /// replacing the server-rendered markup.
+    Callback(Callback<HydrationMismatch>),
+}
+
+impl HydrationMismatchPolicy {
+    /// Reports a mismatch. Returns once hydration can continue by rendering the mismatched node
+    /// on the client.
+    pub(crate) fn report(&self, mismatch: HydrationMismatch) {
+        match self {
+            Self::Panic => panic!("{mismatch}"),
+            Self::Recover => gloo::console::error!(mismatch.to_string()),
+            Self::Callback(cb) => cb.emit(mismatch),
+        }
+    }
+}
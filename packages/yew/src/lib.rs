@@ -26,6 +26,7 @@
 //!   are making a Yew application (not a library).
 //! - `ssr`: Enables Server-side Rendering support and [`ServerRenderer`].
 //! - `hydration`: Enables Hydration support.
+//! - `devtools`: Enables inspection of mounted components with [`devtools`].
 //!
 //! ## Example
 //!
@@ -272,10 +273,15 @@ pub mod macros {
 
 pub mod callback;
 pub mod context;
+#[cfg(feature = "devtools")]
+pub mod devtools;
 #[cfg(feature = "csr")]
 mod dom_bundle;
+pub mod error_boundary;
 pub mod functional;
 pub mod html;
+#[cfg(feature = "hydration")]
+pub mod hydration;
 pub mod platform;
 pub mod scheduler;
 mod sealed;
@@ -329,6 +335,7 @@ pub mod prelude {
     pub use crate::app_handle::AppHandle;
     pub use crate::callback::{Callback, CallbackRef, CallbackRefMut};
     pub use crate::context::{ContextHandle, ContextProvider};
+    pub use crate::error_boundary::ErrorBoundary;
     pub use crate::events::*;
     pub use crate::functional::*;
     pub use crate::html::{
@@ -6,9 +6,12 @@ use web_sys::Element;
 
 use crate::app_handle::AppHandle;
 use crate::html::BaseComponent;
+#[cfg(feature = "hydration")]
+use crate::hydration::HydrationMismatchPolicy;
 
 thread_local! {
     static PANIC_HOOK_IS_SET: Cell<bool> = const { Cell::new(false) };
+    static RENDERED_APPS: Cell<usize> = const { Cell::new(0) };
 }
 
 /// Set a custom panic hook.
@@ -42,6 +45,11 @@ where
 {
     root: Element,
     props: COMP::Properties,
+    id_prefix: Option<String>,
+    #[cfg(feature = "hydration")]
+    hydration_mismatch_policy: HydrationMismatchPolicy,
+    #[cfg(feature = "hydration")]
+    progressive_hydration: bool,
 }
 
 impl<COMP> Default for Renderer<COMP>
@@ -85,13 +93,37 @@ where
 
     /// Creates a [Renderer] that renders into a custom root with custom properties.
     pub fn with_root_and_props(root: Element, props: COMP::Properties) -> Self {
-        Self { root, props }
+        Self {
+            root,
+            props,
+            id_prefix: None,
+            #[cfg(feature = "hydration")]
+            hydration_mismatch_policy: HydrationMismatchPolicy::default(),
+            #[cfg(feature = "hydration")]
+            progressive_hydration: false,
+        }
+    }
+
+    /// Sets the prefix of the ids generated by [`use_id`](crate::functional::use_id) in the
+    /// application, to tell them apart from the ids of other applications on the same page.
+    ///
+    /// Rendered applications are given a unique prefix by default. Hydrated applications have no
+    /// prefix by default, like applications rendered on the server, so an application hydrated
+    /// alongside other hydrated applications must be given the same prefix on the server and on
+    /// the client.
+    pub fn id_prefix(mut self, id_prefix: impl Into<String>) -> Self {
+        self.id_prefix = Some(id_prefix.into());
+        self
     }
 
     /// Renders the application.
     pub fn render(self) -> AppHandle<COMP> {
         set_default_panic_hook();
-        AppHandle::<COMP>::mount_with_props(self.root, Rc::new(self.props))
+        let id_prefix = self.id_prefix.unwrap_or_else(|| {
+            let index = RENDERED_APPS.with(|m| m.replace(m.get() + 1));
+            format!("r{index}")
+        });
+        AppHandle::<COMP>::mount_with_props(self.root, Rc::new(self.props), Some(&id_prefix))
     }
 }
 
@@ -103,10 +135,38 @@ mod feat_hydration {
     where
         COMP: BaseComponent + 'static,
     {
+        /// Sets how a mismatch between the server-rendered markup and the application is handled
+        /// during hydration.
+        ///
+        /// Defaults to [`HydrationMismatchPolicy::Panic`].
+        pub fn hydration_mismatch_policy(mut self, policy: HydrationMismatchPolicy) -> Self {
+            self.hydration_mismatch_policy = policy;
+            self
+        }
+
+        /// Sets whether `<Suspense />` components are hydrated progressively.
+        ///
+        /// When enabled, suspenses are hydrated one component at a time once there is no other
+        /// work to do, so the page stays responsive while a large document is hydrated. A suspense
+        /// the user interacts with is hydrated ahead of the others, and the interactions are
+        /// replayed once its children are in place.
+        ///
+        /// Defaults to `false`.
+        pub fn progressive_hydration(mut self, progressive: bool) -> Self {
+            self.progressive_hydration = progressive;
+            self
+        }
+
         /// Hydrates the application.
         pub fn hydrate(self) -> AppHandle<COMP> {
             set_default_panic_hook();
-            AppHandle::<COMP>::hydrate_with_props(self.root, Rc::new(self.props))
+            AppHandle::<COMP>::hydrate_with_props(
+                self.root,
+                Rc::new(self.props),
+                self.id_prefix.as_deref(),
+                self.hydration_mismatch_policy,
+                self.progressive_hydration,
+            )
         }
     }
 }
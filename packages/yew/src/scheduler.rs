@@ -43,6 +43,23 @@ pub trait Runnable {
 
 struct QueueEntry {
     task: Box<dyn Runnable>,
+    // Whether the task is run as part of a transition.
+    transition: bool,
+}
+
+impl QueueEntry {
+    fn new(task: Box<dyn Runnable>) -> Self {
+        Self {
+            task,
+            transition: false,
+        }
+    }
+
+    fn run(self) {
+        let prev = with(|s| std::mem::replace(&mut s.in_transition, self.transition));
+        self.task.run();
+        with(|s| s.in_transition = prev);
+    }
 }
 
 #[derive(Default)]
@@ -56,7 +73,7 @@ impl FifoQueue {
     }
 
     fn push(&mut self, task: Box<dyn Runnable>) {
-        self.inner.push(QueueEntry { task });
+        self.inner.push(QueueEntry::new(task));
     }
 
     fn drain_into(&mut self, queue: &mut Vec<QueueEntry>) {
@@ -80,7 +97,7 @@ impl TopologicalQueue {
 
     #[cfg(any(feature = "ssr", feature = "csr"))]
     fn push(&mut self, component_id: usize, task: Box<dyn Runnable>) {
-        self.inner.insert(component_id, QueueEntry { task });
+        self.inner.insert(component_id, QueueEntry::new(task));
     }
 
     /// Take a single entry, preferring parents over children
@@ -100,6 +117,77 @@ impl TopologicalQueue {
     }
 }
 
+/// Component work scheduled by transitions.
+///
+/// Transitions are run once there is no other work to do, one component at a time, so they can be
+/// interrupted by urgent work and yield to the browser.
+#[derive(Default)]
+struct TransitionQueue {
+    props_update: FifoQueue,
+
+    render: TopologicalQueue,
+    render_first: TopologicalQueue,
+
+    layout_rendered_first: TopologicalQueue,
+    layout_rendered: TopologicalQueue,
+
+    rendered_first: TopologicalQueue,
+    rendered: TopologicalQueue,
+
+    // Run once all work scheduled by transitions is done.
+    done: FifoQueue,
+}
+
+impl TransitionQueue {
+    const fn new() -> Self {
+        Self {
+            props_update: FifoQueue::new(),
+            render: TopologicalQueue::new(),
+            render_first: TopologicalQueue::new(),
+            layout_rendered_first: TopologicalQueue::new(),
+            layout_rendered: TopologicalQueue::new(),
+            rendered_first: TopologicalQueue::new(),
+            rendered: TopologicalQueue::new(),
+            done: FifoQueue::new(),
+        }
+    }
+
+    /// Fill vector with tasks of transitions, in the same order as [`Scheduler::fill_queue`]
+    fn fill_queue(&mut self, to_run: &mut Vec<QueueEntry>) {
+        if let Some(r) = self.render_first.pop_topmost() {
+            to_run.push(r);
+        } else {
+            self.props_update.drain_into(to_run);
+            if to_run.is_empty() && self.render.inner.is_empty() {
+                self.layout_rendered_first.drain_post_order_into(to_run);
+                self.layout_rendered.drain_post_order_into(to_run);
+            }
+            if self.layout_rendered_first.inner.is_empty() && self.layout_rendered.inner.is_empty()
+            {
+                self.rendered_first.drain_post_order_into(to_run);
+            }
+
+            if to_run.is_empty() {
+                if let Some(r) = self.render.pop_topmost() {
+                    to_run.push(r);
+                } else {
+                    self.rendered.drain_post_order_into(to_run);
+                }
+            }
+        }
+
+        if to_run.is_empty() {
+            // Callbacks for finished transitions are urgent.
+            self.done.drain_into(to_run);
+            return;
+        }
+
+        for m in to_run.iter_mut() {
+            m.transition = true;
+        }
+    }
+}
+
 /// This is a global scheduler suitable to schedule and run any tasks.
 #[derive(Default)]
 struct Scheduler {
@@ -117,8 +205,24 @@ struct Scheduler {
     render_first: TopologicalQueue,
     render_priority: TopologicalQueue,
 
+    layout_rendered_first: TopologicalQueue,
+    layout_rendered: TopologicalQueue,
+
     rendered_first: TopologicalQueue,
     rendered: TopologicalQueue,
+
+    // First renders of components hydrated inside a suspense boundary, by boundary.
+    hydrate: BTreeMap<usize, TopologicalQueue>,
+    // Boundaries the user interacted with, hydrated ahead of the others.
+    hydrate_priority: Vec<usize>,
+
+    transition: TransitionQueue,
+    // Lanes of pending messages, by component. Only recorded for components a message has been
+    // sent to in a transition, messages are urgent otherwise.
+    #[cfg(any(feature = "ssr", feature = "csr"))]
+    update_lanes: BTreeMap<usize, UpdateLanes>,
+    // Whether the running task has been started in a transition.
+    in_transition: bool,
 }
 
 impl Scheduler {
@@ -132,8 +236,16 @@ impl Scheduler {
             render: TopologicalQueue::new(),
             render_first: TopologicalQueue::new(),
             render_priority: TopologicalQueue::new(),
+            layout_rendered_first: TopologicalQueue::new(),
+            layout_rendered: TopologicalQueue::new(),
             rendered_first: TopologicalQueue::new(),
             rendered: TopologicalQueue::new(),
+            hydrate: BTreeMap::new(),
+            hydrate_priority: Vec::new(),
+            transition: TransitionQueue::new(),
+            #[cfg(any(feature = "ssr", feature = "csr"))]
+            update_lanes: BTreeMap::new(),
+            in_transition: false,
         }
     }
 }
@@ -160,9 +272,40 @@ pub fn push(runnable: Box<dyn Runnable>) {
     start();
 }
 
+/// Run `f` in a transition.
+///
+/// Component work scheduled by `f` is run in the transition lane.
+pub(crate) fn start_transition<R>(f: impl FnOnce() -> R) -> R {
+    let prev = with(|s| std::mem::replace(&mut s.in_transition, true));
+    let result = f();
+    with(|s| s.in_transition = prev);
+    result
+}
+
+/// Returns whether the caller runs in a transition.
+pub(crate) fn in_transition() -> bool {
+    with(|s| s.in_transition)
+}
+
+/// Push a [Runnable] to be executed once all work scheduled by transitions is done.
+pub(crate) fn push_transition_done(runnable: Box<dyn Runnable>) {
+    with(|s| s.transition.done.push(runnable));
+    start();
+}
+
 #[cfg(any(feature = "ssr", feature = "csr"))]
 mod feat_csr_ssr {
     use super::*;
+
+    /// The lanes in which the messages pending for a component have been sent.
+    #[derive(Debug, Clone, Copy, Default)]
+    pub(crate) struct UpdateLanes {
+        /// A message has been sent outside of a transition.
+        pub urgent: bool,
+        /// A message has been sent in a transition.
+        pub transition: bool,
+    }
+
     /// Push a component creation, first render and first rendered [Runnable]s to be executed
     pub(crate) fn push_component_create(
         component_id: usize,
@@ -171,7 +314,11 @@ mod feat_csr_ssr {
     ) {
         with(|s| {
             s.create.push(create);
-            s.render_first.push(component_id, first_render);
+            if s.in_transition {
+                s.transition.render_first.push(component_id, first_render);
+            } else {
+                s.render_first.push(component_id, first_render);
+            }
         });
     }
 
@@ -183,14 +330,47 @@ mod feat_csr_ssr {
     /// Push a component render [Runnable]s to be executed
     pub(crate) fn push_component_render(component_id: usize, render: Box<dyn Runnable>) {
         with(|s| {
-            s.render.push(component_id, render);
+            if s.in_transition {
+                s.transition.render.push(component_id, render);
+            } else {
+                s.render.push(component_id, render);
+            }
         });
     }
 
     /// Push a component update [Runnable] to be executed
+    ///
+    /// Updates are always urgent. The lanes of the renders they cause are recorded with
+    /// [`message_sent`].
     pub(crate) fn push_component_update(runnable: Box<dyn Runnable>) {
         with(|s| s.update.push(runnable));
     }
+
+    /// Record the lane in which a message has been sent to the component with the id
+    /// `component_id`.
+    pub(crate) fn message_sent(component_id: usize) {
+        with(|s| {
+            if s.in_transition {
+                s.update_lanes.entry(component_id).or_default().transition = true;
+            } else if let Some(lanes) = s.update_lanes.get_mut(&component_id) {
+                lanes.urgent = true;
+            }
+        });
+    }
+
+    /// Take the lanes of the messages sent to the component with the id `component_id`.
+    pub(crate) fn take_update_lanes(component_id: usize) -> UpdateLanes {
+        with(|s| s.update_lanes.remove(&component_id)).unwrap_or(UpdateLanes {
+            urgent: true,
+            transition: false,
+        })
+    }
+
+    /// Forget the lanes of the messages sent to the destroyed component with the id
+    /// `component_id`.
+    pub(crate) fn component_destroyed(component_id: usize) {
+        with(|s| s.update_lanes.remove(&component_id));
+    }
 }
 
 #[cfg(any(feature = "ssr", feature = "csr"))]
@@ -200,22 +380,59 @@ pub(crate) use feat_csr_ssr::*;
 mod feat_csr {
     use super::*;
 
+    /// Push a component `layout_rendered` [Runnable] to be executed once the children of the
+    /// component are rendered, before the scheduler yields to the browser.
+    pub(crate) fn push_component_layout_rendered(
+        component_id: usize,
+        layout_rendered: Box<dyn Runnable>,
+        first_render: bool,
+    ) {
+        with(|s| {
+            let (layout_rendered_first, layout_rendered_queue) = if s.in_transition {
+                (
+                    &mut s.transition.layout_rendered_first,
+                    &mut s.transition.layout_rendered,
+                )
+            } else {
+                (&mut s.layout_rendered_first, &mut s.layout_rendered)
+            };
+
+            if first_render {
+                layout_rendered_first.push(component_id, layout_rendered);
+            } else {
+                layout_rendered_queue.push(component_id, layout_rendered);
+            }
+        });
+    }
+
     pub(crate) fn push_component_rendered(
         component_id: usize,
         rendered: Box<dyn Runnable>,
         first_render: bool,
     ) {
         with(|s| {
+            let (rendered_first, rendered_queue) = if s.in_transition {
+                (&mut s.transition.rendered_first, &mut s.transition.rendered)
+            } else {
+                (&mut s.rendered_first, &mut s.rendered)
+            };
+
             if first_render {
-                s.rendered_first.push(component_id, rendered);
+                rendered_first.push(component_id, rendered);
             } else {
-                s.rendered.push(component_id, rendered);
+                rendered_queue.push(component_id, rendered);
             }
         });
     }
 
     pub(crate) fn push_component_props_update(props_update: Box<dyn Runnable>) {
-        with(|s| s.props_update.push(props_update));
+        with(|s| {
+            if s.in_transition {
+                s.transition.props_update.push(props_update);
+            } else {
+                s.props_update.push(props_update);
+            }
+        });
     }
 }
 
@@ -231,6 +448,56 @@ mod feat_hydration {
             s.render_priority.push(component_id, render);
         });
     }
+
+    /// Push a component creation and a first render [Runnable] that hydrates the component as
+    /// part of the suspense boundary with the id `boundary_id`.
+    ///
+    /// Unlike other first renders, these yield to any other work, so boundaries are hydrated
+    /// progressively.
+    pub(crate) fn push_component_hydrate(
+        boundary_id: usize,
+        component_id: usize,
+        create: Box<dyn Runnable>,
+        first_render: Box<dyn Runnable>,
+    ) {
+        with(|s| {
+            s.create.push(create);
+            s.hydrate
+                .entry(boundary_id)
+                .or_default()
+                .push(component_id, first_render);
+        });
+    }
+
+    /// Move the hydration of the component with the id `component_id`, if still pending, ahead
+    /// of any other render, so it is hydrated before it is updated.
+    pub(crate) fn expedite_hydration(component_id: usize) {
+        with(|s| {
+            let Some((boundary_id, render)) = s
+                .hydrate
+                .iter_mut()
+                .find_map(|(id, m)| Some((*id, m.inner.remove(&component_id)?)))
+            else {
+                return;
+            };
+
+            if s.hydrate
+                .get(&boundary_id)
+                .is_some_and(|m| m.inner.is_empty())
+            {
+                s.hydrate.remove(&boundary_id);
+            }
+            s.render_first.inner.insert(component_id, render);
+        });
+    }
+
+    /// Hydrate the suspense boundary with the id `boundary_id` before any other boundary.
+    pub(crate) fn prioritize_hydration(boundary_id: usize) {
+        with(|s| {
+            s.hydrate_priority.retain(|m| *m != boundary_id);
+            s.hydrate_priority.insert(0, boundary_id);
+        });
+    }
 }
 
 #[cfg(feature = "hydration")]
@@ -254,7 +521,7 @@ pub(crate) fn start_now() {
                 break;
             }
             for r in queue.drain(..) {
-                r.task.run();
+                r.run();
             }
         }
     }
@@ -320,7 +587,7 @@ mod arch {
                 break;
             }
             for r in queue.drain(..) {
-                r.task.run();
+                r.run();
             }
             if js_sys::Date::now() >= deadline {
                 // Only yield when no DOM-mutating work is pending, so event
@@ -431,6 +698,8 @@ impl Scheduler {
             && self.render_first.inner.is_empty()
             && self.render.inner.is_empty()
             && self.render_priority.inner.is_empty()
+            && self.layout_rendered_first.inner.is_empty()
+            && self.layout_rendered.inner.is_empty()
     }
 
     /// Fill vector with tasks to be executed according to Runnable type execution priority
@@ -472,8 +741,18 @@ impl Scheduler {
             return;
         }
 
-        // Children rendered lifecycle happen before parents.
-        self.rendered_first.drain_post_order_into(to_run);
+        // Layout effects run once the components have rendered their children, so they observe
+        // the whole DOM of the render, and before the scheduler yields to the browser.
+        // Children layout lifecycle happen before parents.
+        if to_run.is_empty() && self.render.inner.is_empty() {
+            self.layout_rendered_first.drain_post_order_into(to_run);
+            self.layout_rendered.drain_post_order_into(to_run);
+        }
+
+        // Children rendered lifecycle happen before parents, and after the layout lifecycle.
+        if self.layout_rendered_first.inner.is_empty() && self.layout_rendered.inner.is_empty() {
+            self.rendered_first.drain_post_order_into(to_run);
+        }
 
         // Updates are after the first render to ensure we always have the entire child tree
         // rendered, once an update is processed.
@@ -502,6 +781,50 @@ impl Scheduler {
         // Should be run only after all renders have finished.
         // Children rendered lifecycle happen before parents.
         self.rendered.drain_post_order_into(to_run);
+
+        if !to_run.is_empty() {
+            return;
+        }
+
+        // Transitions are run when there is no urgent work, so they are interrupted by it.
+        // Not considered by `can_yield`, so the scheduler can yield between them.
+        self.transition.fill_queue(to_run);
+
+        // Progressive hydration is done when there is nothing else to do.
+        //
+        // Should be processed one at time, so the scheduler can yield between them.
+        if !to_run.is_empty() {
+            return;
+        }
+
+        if let Some(r) = self.pop_hydrate() {
+            to_run.push(r);
+        }
+    }
+
+    /// Take the first render of a single component to be hydrated, preferring boundaries the
+    /// user interacted with over other boundaries and outer boundaries over inner ones.
+    fn pop_hydrate(&mut self) -> Option<QueueEntry> {
+        while let Some(&boundary_id) = self.hydrate_priority.first() {
+            if let Some(r) = self
+                .hydrate
+                .get_mut(&boundary_id)
+                .and_then(TopologicalQueue::pop_topmost)
+            {
+                return Some(r);
+            }
+
+            self.hydrate.remove(&boundary_id);
+            self.hydrate_priority.remove(0);
+        }
+
+        let mut queue = self.hydrate.first_entry()?;
+        let r = queue.get_mut().pop_topmost();
+        if queue.get().inner.is_empty() {
+            queue.remove();
+        }
+
+        r
     }
 }
 
@@ -527,4 +850,80 @@ mod tests {
         push(Box::new(Test));
         FLAG.with(|v| assert!(v.get()));
     }
+
+    #[cfg(feature = "hydration")]
+    #[test]
+    fn prioritized_boundaries_are_hydrated_first() {
+        thread_local! {
+            static HYDRATED: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+        }
+
+        struct Create;
+        impl Runnable for Create {
+            fn run(self: Box<Self>) {}
+        }
+
+        struct Hydrate(usize);
+        impl Runnable for Hydrate {
+            fn run(self: Box<Self>) {
+                HYDRATED.with(|m| m.borrow_mut().push(self.0));
+            }
+        }
+
+        push_component_hydrate(1, 3, Box::new(Create), Box::new(Hydrate(3)));
+        push_component_hydrate(1, 2, Box::new(Create), Box::new(Hydrate(2)));
+        push_component_hydrate(4, 5, Box::new(Create), Box::new(Hydrate(5)));
+        prioritize_hydration(4);
+        start_now();
+
+        HYDRATED.with(|m| assert_eq!(*m.borrow(), [5, 2, 3]));
+    }
+
+    #[cfg(feature = "hydration")]
+    #[test]
+    fn components_are_hydrated_before_updates() {
+        thread_local! {
+            static RAN: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+        }
+
+        struct Create;
+        impl Runnable for Create {
+            fn run(self: Box<Self>) {}
+        }
+
+        struct Record(&'static str);
+        impl Runnable for Record {
+            fn run(self: Box<Self>) {
+                RAN.with(|m| m.borrow_mut().push(self.0));
+            }
+        }
+
+        push_component_hydrate(1, 2, Box::new(Create), Box::new(Record("hydrate")));
+        push_component_update(Box::new(Record("update")));
+        expedite_hydration(2);
+        start_now();
+
+        RAN.with(|m| assert_eq!(*m.borrow(), ["hydrate", "update"]));
+    }
+
+    #[cfg(any(feature = "ssr", feature = "csr"))]
+    #[test]
+    fn transitions_run_after_urgent_work() {
+        thread_local! {
+            static RENDERED: RefCell<Vec<(usize, bool)>> = const { RefCell::new(Vec::new()) };
+        }
+
+        struct Render(usize);
+        impl Runnable for Render {
+            fn run(self: Box<Self>) {
+                RENDERED.with(|m| m.borrow_mut().push((self.0, in_transition())));
+            }
+        }
+
+        start_transition(|| push_component_render(1, Box::new(Render(1))));
+        push_component_render(2, Box::new(Render(2)));
+        start_now();
+
+        RENDERED.with(|m| assert_eq!(*m.borrow(), [(2, false), (1, true)]));
+    }
 }
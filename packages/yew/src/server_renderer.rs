@@ -4,12 +4,22 @@ use futures::pin_mut;
 use futures::stream::{Stream, StreamExt};
 use tracing::Instrument;
 
+use crate::feat_ssr::DeferredSuspense;
 use crate::html::{BaseComponent, Scope};
 use crate::platform::fmt::BufStream;
 use crate::platform::{LocalHandle, Runtime};
 
 #[cfg(feature = "ssr")]
 pub(crate) mod feat_ssr {
+    use std::cell::{Cell, RefCell};
+    use std::fmt::Write;
+    use std::rc::Rc;
+
+    use futures::future::{FutureExt, LocalBoxFuture};
+    use futures::stream::{FuturesUnordered, StreamExt};
+
+    use crate::platform::fmt::BufWriter;
+
     /// Passed top-down as context for `render_into_stream` functions to know the current innermost
     /// `VTag` kind to apply appropriate text escaping.
     /// Right now this is used to make `VText` nodes aware of their environment and correctly
@@ -37,6 +47,90 @@ pub(crate) mod feat_ssr {
             }
         }
     }
+
+    /// Moves the content of a resolved `<template>` chunk into the place of its placeholder and
+    /// removes the fallback rendered after the placeholder.
+    ///
+    /// A boundary nested in another deferred boundary resolves before its placeholder is part of
+    /// the document, so every successful swap retries the chunks that are still around.
+    const SWAP_SCRIPT: &str = r#"<script>window.__yew_suspense_swap=function(){var d=document;function s(i){var r=d.querySelector('template[data-yew-resolved="'+i+'"]'),p=d.querySelector('template[data-yew-pending="'+i+'"]');if(!r||!p)return!1;for(var n=p.nextSibling,l=0;n;){if(n.nodeType===8){if(n.data==="</?>"){if(l===0)break;l--}else if(n.data==="<?>")l++}var x=n.nextSibling;n.remove();n=x}p.parentNode.insertBefore(r.content,p);p.remove();r.remove();return!0}return function(i){for(var o=s(i);o;){o=!1;d.querySelectorAll("template[data-yew-resolved]").forEach(function(t){s(t.getAttribute("data-yew-resolved"))&&(o=!0)})}}}()</script>"#;
+
+    #[derive(Default)]
+    struct DeferredSuspenseInner {
+        next_id: Cell<usize>,
+        pending: RefCell<Vec<LocalBoxFuture<'static, (usize, String)>>>,
+    }
+
+    /// Passed top-down as context for `render_into_stream` functions to collect the children of
+    /// suspended `Suspense` boundaries.
+    ///
+    /// When out-of-order streaming is enabled, a `Suspense` boundary that cannot be rendered
+    /// right away writes its fallback with a placeholder and defers its children, which are
+    /// written at the end of the document once they are resolved.
+    #[derive(Clone)]
+    pub(crate) struct DeferredSuspense {
+        inner: Option<Rc<DeferredSuspenseInner>>,
+    }
+
+    impl DeferredSuspense {
+        pub fn new(enabled: bool) -> Self {
+            Self {
+                inner: enabled.then(Default::default),
+            }
+        }
+
+        pub fn is_enabled(&self) -> bool {
+            self.inner.is_some()
+        }
+
+        /// Defers the rendering of the children of a `Suspense` boundary and returns the id of
+        /// its placeholder.
+        pub fn defer(&self, children: LocalBoxFuture<'static, String>) -> usize {
+            let inner = self
+                .inner
+                .as_ref()
+                .expect("out-of-order streaming is not enabled");
+
+            let id = inner.next_id.get();
+            inner.next_id.set(id + 1);
+
+            inner
+                .pending
+                .borrow_mut()
+                .push(children.map(move |children| (id, children)).boxed_local());
+
+            id
+        }
+
+        /// Writes the deferred children as soon as they are resolved.
+        pub async fn render_into_stream(&self, w: &mut BufWriter) {
+            let Some(inner) = self.inner.as_ref() else {
+                return;
+            };
+
+            let mut rendering = FuturesUnordered::new();
+            let mut swap_script_written = false;
+
+            loop {
+                // Deferred children may contain other deferred boundaries.
+                rendering.extend(inner.pending.borrow_mut().drain(..));
+
+                let Some((id, children)) = rendering.next().await else {
+                    break;
+                };
+
+                if !swap_script_written {
+                    let _ = w.write_str(SWAP_SCRIPT);
+                    swap_script_written = true;
+                }
+
+                let _ = write!(
+                    w,
+                    r#"<template data-yew-resolved="{id}">{children}</template><script>__yew_suspense_swap({id})</script>"#
+                );
+            }
+        }
+    }
 }
 
 /// A Yew Server-side Renderer that renders on the current thread.
@@ -56,6 +150,8 @@ where
 {
     props: COMP::Properties,
     hydratable: bool,
+    out_of_order_streaming: bool,
+    id_prefix: Option<String>,
 }
 
 impl<COMP> Default for LocalServerRenderer<COMP>
@@ -86,6 +182,8 @@ where
         Self {
             props,
             hydratable: true,
+            out_of_order_streaming: false,
+            id_prefix: None,
         }
     }
 
@@ -101,6 +199,31 @@ where
         self
     }
 
+    /// Sets whether suspended `Suspense` boundaries are streamed out of order.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// When this is set to `true`, the fallback of a `Suspense` boundary whose children are not
+    /// ready is rendered in place so the rest of the document can be streamed without waiting.
+    /// The resolved children are streamed at the end of the document in `<template>` chunks,
+    /// each followed by a small inline script that swaps it into the place of the fallback.
+    pub fn out_of_order_streaming(mut self, val: bool) -> Self {
+        self.out_of_order_streaming = val;
+
+        self
+    }
+
+    /// Sets the prefix of the ids generated by [`use_id`](crate::functional::use_id) in the
+    /// application, to tell them apart from the ids of other applications on the same page.
+    ///
+    /// Defaults to no prefix. The application must be hydrated with the same prefix, see
+    /// `Renderer::id_prefix`.
+    pub fn id_prefix(mut self, id_prefix: impl Into<String>) -> Self {
+        self.id_prefix = Some(id_prefix.into());
+
+        self
+    }
+
     /// Renders Yew Application.
     pub async fn render(self) -> String {
         let s = self.render_stream();
@@ -120,21 +243,29 @@ where
     }
 
     fn render_stream_inner(self) -> impl Stream<Item = String> {
-        let scope = Scope::<COMP>::new(None);
+        let scope = Scope::<COMP>::new_root(self.id_prefix.as_deref());
 
         let outer_span = tracing::Span::current();
         BufStream::new(move |mut w| async move {
             let render_span = tracing::debug_span!("render_stream_item");
             render_span.follows_from(outer_span);
-            scope
-                .render_into_stream(
-                    &mut w,
-                    self.props.into(),
-                    self.hydratable,
-                    Default::default(),
-                )
-                .instrument(render_span)
-                .await;
+            let deferred = DeferredSuspense::new(self.out_of_order_streaming);
+
+            async {
+                scope
+                    .render_into_stream(
+                        &mut w,
+                        self.props.into(),
+                        self.hydratable,
+                        &deferred,
+                        Default::default(),
+                    )
+                    .await;
+
+                deferred.render_into_stream(&mut w).await;
+            }
+            .instrument(render_span)
+            .await;
         })
     }
 
@@ -146,7 +277,10 @@ where
         level = tracing::Level::DEBUG,
         name = "render_stream",
         skip(self),
-        fields(hydratable = self.hydratable),
+        fields(
+            hydratable = self.hydratable,
+            out_of_order_streaming = self.out_of_order_streaming,
+        ),
     )]
     #[inline(always)]
     pub fn render_stream(self) -> impl Stream<Item = String> {
@@ -167,6 +301,8 @@ where
 {
     create_props: Box<dyn Send + FnOnce() -> COMP::Properties>,
     hydratable: bool,
+    out_of_order_streaming: bool,
+    id_prefix: Option<String>,
     rt: Option<Runtime>,
 }
 
@@ -215,6 +351,8 @@ where
         Self {
             create_props: Box::new(create_props),
             hydratable: true,
+            out_of_order_streaming: false,
+            id_prefix: None,
             rt: None,
         }
     }
@@ -238,21 +376,49 @@ where
         self
     }
 
+    /// Sets whether suspended `Suspense` boundaries are streamed out of order.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// When this is set to `true`, the fallback of a `Suspense` boundary whose children are not
+    /// ready is rendered in place so the rest of the document can be streamed without waiting.
+    /// The resolved children are streamed at the end of the document in `<template>` chunks,
+    /// each followed by a small inline script that swaps it into the place of the fallback.
+    pub fn out_of_order_streaming(mut self, val: bool) -> Self {
+        self.out_of_order_streaming = val;
+
+        self
+    }
+
+    /// Sets the prefix of the ids generated by [`use_id`](crate::functional::use_id) in the
+    /// application, to tell them apart from the ids of other applications on the same page.
+    ///
+    /// Defaults to no prefix. The application must be hydrated with the same prefix, see
+    /// `Renderer::id_prefix`.
+    pub fn id_prefix(mut self, id_prefix: impl Into<String>) -> Self {
+        self.id_prefix = Some(id_prefix.into());
+
+        self
+    }
+
     /// Renders Yew Application.
     pub async fn render(self) -> String {
         let Self {
             create_props,
             hydratable,
+            out_of_order_streaming,
+            id_prefix,
             rt,
         } = self;
 
         let (tx, rx) = futures::channel::oneshot::channel();
         let create_task = move || async move {
             let props = create_props();
-            let s = LocalServerRenderer::<COMP>::with_props(props)
+            let mut renderer = LocalServerRenderer::<COMP>::with_props(props)
                 .hydratable(hydratable)
-                .render()
-                .await;
+                .out_of_order_streaming(out_of_order_streaming);
+            renderer.id_prefix = id_prefix;
+            let s = renderer.render().await;
 
             let _ = tx.send(s);
         };
@@ -294,15 +460,19 @@ where
         let Self {
             create_props,
             hydratable,
+            out_of_order_streaming,
+            id_prefix,
             rt,
         } = self;
 
         let (tx, rx) = futures::channel::mpsc::unbounded();
         let create_task = move || async move {
             let props = create_props();
-            let s = LocalServerRenderer::<COMP>::with_props(props)
+            let mut renderer = LocalServerRenderer::<COMP>::with_props(props)
                 .hydratable(hydratable)
-                .render_stream();
+                .out_of_order_streaming(out_of_order_streaming);
+            renderer.id_prefix = id_prefix;
+            let s = renderer.render_stream();
             pin_mut!(s);
 
             while let Some(m) = s.next().await {
@@ -184,6 +184,22 @@ mod feat_csr_ssr {
             scope.send_message(BaseSuspenseMsg::Resume(s));
         }
 
+        /// Keeps the suspense suspended until the returned handle is resumed or dropped.
+        ///
+        /// This is used to keep the server-rendered content in place while the children of the
+        /// suspense are hydrated.
+        #[cfg(feature = "hydration")]
+        pub(crate) fn defer_hydration(scope: &Scope<Self>) -> SuspensionHandle {
+            use crate::callback::Callback;
+
+            let (s, handle) = Suspension::new();
+            let link = scope.clone();
+            s.listen(Callback::from(move |s| Self::resume(&link, s)));
+            Self::suspend(scope, s);
+
+            handle
+        }
+
         /// Queue a child component's `rendered` lifecycle to be scheduled once
         /// this Suspense fully un-suspends and its reconcile has shifted the
         /// child's DOM into the live tree. If the child already has a pending
@@ -20,7 +20,10 @@ use crate::html::Scoped;
 #[cfg(any(feature = "ssr", feature = "csr"))]
 use crate::html::{AnyScope, Scope};
 #[cfg(feature = "ssr")]
-use crate::{feat_ssr::VTagKind, platform::fmt::BufWriter};
+use crate::{
+    feat_ssr::{DeferredSuspense, VTagKind},
+    platform::fmt::BufWriter,
+};
 
 /// A virtual component.
 pub struct VComp {
@@ -76,6 +79,7 @@ pub(crate) trait Mountable {
         w: &'a mut BufWriter,
         parent_scope: &'a AnyScope,
         hydratable: bool,
+        deferred: &'a DeferredSuspense,
         parent_vtag_kind: VTagKind,
     ) -> LocalBoxFuture<'a, ()>;
 
@@ -145,13 +149,20 @@ impl<COMP: BaseComponent> Mountable for PropsWrapper<COMP> {
         w: &'a mut BufWriter,
         parent_scope: &'a AnyScope,
         hydratable: bool,
+        deferred: &'a DeferredSuspense,
         parent_vtag_kind: VTagKind,
     ) -> LocalBoxFuture<'a, ()> {
         let scope: Scope<COMP> = Scope::new(Some(parent_scope.clone()));
 
         async move {
             scope
-                .render_into_stream(w, self.props.clone(), hydratable, parent_vtag_kind)
+                .render_into_stream(
+                    w,
+                    self.props.clone(),
+                    hydratable,
+                    deferred,
+                    parent_vtag_kind,
+                )
                 .await;
         }
         .boxed_local()
@@ -276,11 +287,12 @@ mod feat_ssr {
             w: &mut BufWriter,
             parent_scope: &AnyScope,
             hydratable: bool,
+            deferred: &DeferredSuspense,
             parent_vtag_kind: VTagKind,
         ) {
             self.mountable
                 .as_ref()
-                .render_into_stream(w, parent_scope, hydratable, parent_vtag_kind)
+                .render_into_stream(w, parent_scope, hydratable, deferred, parent_vtag_kind)
                 .await;
         }
     }
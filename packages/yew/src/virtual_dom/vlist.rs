@@ -256,7 +256,7 @@ mod feat_ssr {
     use futures::{FutureExt, join, pin_mut, poll};
 
     use super::*;
-    use crate::feat_ssr::VTagKind;
+    use crate::feat_ssr::{DeferredSuspense, VTagKind};
     use crate::html::AnyScope;
     use crate::platform::fmt::{self, BufWriter};
 
@@ -266,13 +266,14 @@ mod feat_ssr {
             w: &mut BufWriter,
             parent_scope: &AnyScope,
             hydratable: bool,
+            deferred: &DeferredSuspense,
             parent_vtag_kind: VTagKind,
         ) {
             match &self[..] {
                 [] => {}
                 [child] => {
                     child
-                        .render_into_stream(w, parent_scope, hydratable, parent_vtag_kind)
+                        .render_into_stream(w, parent_scope, hydratable, deferred, parent_vtag_kind)
                         .await;
                 }
                 _ => {
@@ -281,6 +282,7 @@ mod feat_ssr {
                         w: &mut BufWriter,
                         parent_scope: &AnyScope,
                         hydratable: bool,
+                        deferred: &DeferredSuspense,
                         parent_vtag_kind: VTagKind,
                     ) where
                         I: Iterator<Item = &'a VNode>,
@@ -294,8 +296,14 @@ mod feat_ssr {
                                 //
                                 // We capture and return the mutable reference to avoid this.
 
-                                m.render_into_stream(w, parent_scope, hydratable, parent_vtag_kind)
-                                    .await;
+                                m.render_into_stream(
+                                    w,
+                                    parent_scope,
+                                    hydratable,
+                                    deferred,
+                                    parent_vtag_kind,
+                                )
+                                .await;
                                 w
                             };
                             pin_mut!(child_fur);
@@ -311,6 +319,7 @@ mod feat_ssr {
                                             &mut next_w,
                                             parent_scope,
                                             hydratable,
+                                            deferred,
                                             parent_vtag_kind,
                                         )
                                         .await;
@@ -338,8 +347,15 @@ mod feat_ssr {
                     }
 
                     let children = self.iter();
-                    render_child_iter(children, w, parent_scope, hydratable, parent_vtag_kind)
-                        .await;
+                    render_child_iter(
+                        children,
+                        w,
+                        parent_scope,
+                        hydratable,
+                        deferred,
+                        parent_vtag_kind,
+                    )
+                    .await;
                 }
             }
         }
@@ -193,7 +193,7 @@ mod feat_ssr {
     use futures::future::{FutureExt, LocalBoxFuture};
 
     use super::*;
-    use crate::feat_ssr::VTagKind;
+    use crate::feat_ssr::{DeferredSuspense, VTagKind};
     use crate::html::AnyScope;
     use crate::platform::fmt::BufWriter;
 
@@ -203,6 +203,7 @@ mod feat_ssr {
             w: &'a mut BufWriter,
             parent_scope: &'a AnyScope,
             hydratable: bool,
+            deferred: &'a DeferredSuspense,
             parent_vtag_kind: VTagKind,
         ) -> LocalBoxFuture<'a, ()> {
             async fn render_into_stream_(
@@ -210,10 +211,14 @@ mod feat_ssr {
                 w: &mut BufWriter,
                 parent_scope: &AnyScope,
                 hydratable: bool,
+                deferred: &DeferredSuspense,
                 parent_vtag_kind: VTagKind,
             ) {
                 match this {
-                    VNode::VTag(vtag) => vtag.render_into_stream(w, parent_scope, hydratable).await,
+                    VNode::VTag(vtag) => {
+                        vtag.render_into_stream(w, parent_scope, hydratable, deferred)
+                            .await
+                    }
                     VNode::VText(vtext) => {
                         vtext
                             .render_into_stream(w, parent_scope, hydratable, parent_vtag_kind)
@@ -221,12 +226,24 @@ mod feat_ssr {
                     }
                     VNode::VComp(vcomp) => {
                         vcomp
-                            .render_into_stream(w, parent_scope, hydratable, parent_vtag_kind)
+                            .render_into_stream(
+                                w,
+                                parent_scope,
+                                hydratable,
+                                deferred,
+                                parent_vtag_kind,
+                            )
                             .await
                     }
                     VNode::VList(vlist) => {
                         vlist
-                            .render_into_stream(w, parent_scope, hydratable, parent_vtag_kind)
+                            .render_into_stream(
+                                w,
+                                parent_scope,
+                                hydratable,
+                                deferred,
+                                parent_vtag_kind,
+                            )
                             .await
                     }
                     // We are pretty safe here as it's not possible to get a web_sys::Node without
@@ -241,7 +258,13 @@ mod feat_ssr {
                     VNode::VPortal(_) => {}
                     VNode::VSuspense(vsuspense) => {
                         vsuspense
-                            .render_into_stream(w, parent_scope, hydratable, parent_vtag_kind)
+                            .render_into_stream(
+                                w,
+                                parent_scope,
+                                hydratable,
+                                deferred,
+                                parent_vtag_kind,
+                            )
                             .await
                     }
 
@@ -250,8 +273,17 @@ mod feat_ssr {
             }
 
             async move {
-                render_into_stream_(self, w, parent_scope, hydratable, parent_vtag_kind).await
-            }.boxed_local()
+                render_into_stream_(
+                    self,
+                    w,
+                    parent_scope,
+                    hydratable,
+                    deferred,
+                    parent_vtag_kind,
+                )
+                .await
+            }
+            .boxed_local()
         }
     }
 }
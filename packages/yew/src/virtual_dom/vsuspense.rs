@@ -27,29 +27,162 @@ impl VSuspense {
 
 #[cfg(feature = "ssr")]
 mod feat_ssr {
+    use std::fmt::Write;
+    use std::future::{Future, poll_fn};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::task::{Context, Poll, Wake, Waker};
+
+    use futures::future::FutureExt;
+    use futures::stream::StreamExt;
+
     use super::*;
-    use crate::feat_ssr::VTagKind;
+    use crate::feat_ssr::{DeferredSuspense, VTagKind};
     use crate::html::AnyScope;
-    use crate::platform::fmt::BufWriter;
+    use crate::platform::fmt::{BufStream, BufWriter};
     use crate::virtual_dom::Collectable;
 
+    /// Records whether a future was woken since it was last polled.
+    struct Progress {
+        woken: AtomicBool,
+        waker: Waker,
+    }
+
+    impl Wake for Progress {
+        fn wake(self: Arc<Self>) {
+            self.wake_by_ref();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.woken.store(true, Ordering::Relaxed);
+            self.waker.wake_by_ref();
+        }
+    }
+
+    /// Yields to the executor once, so the tasks woken by the polled future can run.
+    async fn yield_now() {
+        let mut yielded = false;
+        poll_fn(|cx| {
+            if yielded {
+                return Poll::Ready(());
+            }
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        })
+        .await
+    }
+
+    /// Polls `fut` until it is ready, or until it stops making progress: it is pending and not
+    /// woken after yielding to the executor.
+    async fn poll_until_stalled<F>(fut: &mut F) -> Poll<F::Output>
+    where
+        F: Future + Unpin,
+    {
+        loop {
+            let (result, progress) = poll_fn(|cx| {
+                let progress = Arc::new(Progress {
+                    woken: AtomicBool::new(false),
+                    waker: cx.waker().clone(),
+                });
+                let waker = Waker::from(progress.clone());
+                let result = fut.poll_unpin(&mut Context::from_waker(&waker));
+
+                Poll::Ready((result, progress))
+            })
+            .await;
+
+            if result.is_ready() {
+                return result;
+            }
+
+            yield_now().await;
+
+            if !progress.woken.load(Ordering::Relaxed) {
+                return Poll::Pending;
+            }
+        }
+    }
+
     impl VSuspense {
         pub(crate) async fn render_into_stream(
             &self,
             w: &mut BufWriter,
             parent_scope: &AnyScope,
             hydratable: bool,
+            deferred: &DeferredSuspense,
             parent_vtag_kind: VTagKind,
         ) {
             let collectable = Collectable::Suspense;
 
+            if deferred.is_enabled() {
+                let mut children = {
+                    let children = self.children.clone();
+                    let parent_scope = parent_scope.clone();
+                    let deferred = deferred.clone();
+
+                    BufStream::new(move |mut w| async move {
+                        children
+                            .render_into_stream(
+                                &mut w,
+                                &parent_scope,
+                                hydratable,
+                                &deferred,
+                                parent_vtag_kind,
+                            )
+                            .await;
+                    })
+                    .collect::<String>()
+                    .boxed_local()
+                };
+
+                // A child may need a few wakeups to render even if it is not suspended.
+                match poll_until_stalled(&mut children).await {
+                    // The children are not suspended, they are rendered in place.
+                    Poll::Ready(children) => {
+                        if hydratable {
+                            collectable.write_open_tag(w);
+                        }
+
+                        let _ = w.write_str(&children);
+
+                        if hydratable {
+                            collectable.write_close_tag(w);
+                        }
+                    }
+                    // The fallback is rendered in place of the children, which are swapped in
+                    // once they are resolved. The markers are always written so the swap script
+                    // can find the end of the fallback.
+                    Poll::Pending => {
+                        let id = deferred.defer(children);
+
+                        collectable.write_open_tag(w);
+                        let _ = write!(w, r#"<template data-yew-pending="{id}"></template>"#);
+
+                        self.fallback
+                            .render_into_stream(
+                                w,
+                                parent_scope,
+                                hydratable,
+                                deferred,
+                                parent_vtag_kind,
+                            )
+                            .await;
+
+                        collectable.write_close_tag(w);
+                    }
+                }
+
+                return;
+            }
+
             if hydratable {
                 collectable.write_open_tag(w);
             }
 
             // always render children on the server side.
             self.children
-                .render_into_stream(w, parent_scope, hydratable, parent_vtag_kind)
+                .render_into_stream(w, parent_scope, hydratable, deferred, parent_vtag_kind)
                 .await;
 
             if hydratable {
@@ -158,3 +291,210 @@ mod ssr_tests {
         );
     }
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_out_of_order_tests {
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    use tokio::task::{LocalSet, spawn_local};
+    use tokio::test;
+
+    use crate::ServerRenderer;
+    use crate::platform::time::sleep;
+    use crate::prelude::*;
+    use crate::suspense::{Suspension, SuspensionResult};
+
+    #[derive(PartialEq)]
+    pub struct SleepState {
+        s: Suspension,
+    }
+
+    impl SleepState {
+        fn new() -> Self {
+            let (s, handle) = Suspension::new();
+
+            // we use tokio spawn local here.
+            spawn_local(async move {
+                // we use tokio sleep here.
+                sleep(Duration::from_millis(50)).await;
+
+                handle.resume();
+            });
+
+            Self { s }
+        }
+    }
+
+    impl Reducible for SleepState {
+        type Action = ();
+
+        fn reduce(self: Rc<Self>, _action: Self::Action) -> Rc<Self> {
+            Self::new().into()
+        }
+    }
+
+    #[hook]
+    pub fn use_sleep() -> SuspensionResult<Rc<dyn Fn()>> {
+        let sleep_state = use_reducer(SleepState::new);
+
+        if sleep_state.s.resumed() {
+            Ok(Rc::new(move || sleep_state.dispatch(())))
+        } else {
+            Err(sleep_state.s.clone())
+        }
+    }
+
+    #[derive(PartialEq, Properties, Debug)]
+    struct ChildProps {
+        name: String,
+    }
+
+    #[component]
+    fn Child(props: &ChildProps) -> HtmlResult {
+        use_sleep()?;
+        Ok(html! { <div>{"Hello, "}{&props.name}{"!"}</div> })
+    }
+
+    #[test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_suspense_out_of_order() {
+        #[component]
+        fn Comp() -> Html {
+            let fallback = html! {"loading..."};
+
+            html! {
+                <>
+                    <Suspense {fallback}>
+                        <Child name="Jane" />
+                        <Child name="John" />
+                    </Suspense>
+                    <div>{"after"}</div>
+                </>
+            }
+        }
+
+        let local = LocalSet::new();
+
+        let s = local
+            .run_until(async move {
+                ServerRenderer::<Comp>::new()
+                    .hydratable(false)
+                    .out_of_order_streaming(true)
+                    .render()
+                    .await
+            })
+            .await;
+
+        assert!(s.starts_with(
+            r#"<!--<?>--><template data-yew-pending="0"></template>loading...<!--</?>--><div>after</div><script>"#
+        ));
+        assert!(s.ends_with(
+            r#"<template data-yew-resolved="0"><div>Hello, Jane!</div><div>Hello, John!</div></template><script>__yew_suspense_swap(0)</script>"#
+        ));
+    }
+
+    #[test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_suspense_out_of_order_nested() {
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <Suspense fallback={html! {"outer"}}>
+                    <Child name="Jane" />
+                    <Suspense fallback={html! {"inner"}}>
+                        <Child name="John" />
+                    </Suspense>
+                </Suspense>
+            }
+        }
+
+        let local = LocalSet::new();
+
+        let s = local
+            .run_until(async move {
+                ServerRenderer::<Comp>::new()
+                    .hydratable(false)
+                    .out_of_order_streaming(true)
+                    .render()
+                    .await
+            })
+            .await;
+
+        // The inner boundary is deferred while the outer boundary tries to render its children.
+        assert!(s.starts_with(
+            r#"<!--<?>--><template data-yew-pending="1"></template>outer<!--</?>--><script>"#
+        ));
+        assert!(s.contains(
+            r#"<template data-yew-resolved="1"><div>Hello, Jane!</div><!--<?>--><template data-yew-pending="0"></template>inner<!--</?>--></template>"#
+        ));
+        assert!(s.contains(
+            r#"<template data-yew-resolved="0"><div>Hello, John!</div></template><script>__yew_suspense_swap(0)</script>"#
+        ));
+    }
+
+    #[test]
+    async fn test_suspense_out_of_order_not_suspended() {
+        #[component]
+        fn Comp() -> Html {
+            let fallback = html! {"loading..."};
+
+            html! {
+                <Suspense {fallback}>
+                    <div>{"Hello!"}</div>
+                </Suspense>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .out_of_order_streaming(true)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div>Hello!</div>");
+    }
+
+    #[test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_suspense_out_of_order_resumed_after_yield() {
+        #[component]
+        fn Ready() -> HtmlResult {
+            // The suspension is resumed by a task that is run as soon as the renderer yields.
+            let suspension = use_state(|| {
+                let (s, handle) = Suspension::new();
+                spawn_local(async move { handle.resume() });
+                s
+            });
+            if !suspension.resumed() {
+                return Err((*suspension).clone().into());
+            }
+
+            Ok(html! { <div>{"Hello!"}</div> })
+        }
+
+        #[component]
+        fn Comp() -> Html {
+            let fallback = html! {"loading..."};
+
+            html! {
+                <Suspense {fallback}>
+                    <Ready />
+                </Suspense>
+            }
+        }
+
+        let local = LocalSet::new();
+
+        let s = local
+            .run_until(async move {
+                ServerRenderer::<Comp>::new()
+                    .hydratable(false)
+                    .out_of_order_streaming(true)
+                    .render()
+                    .await
+            })
+            .await;
+
+        assert_eq!(s, "<div>Hello!</div>");
+    }
+}
@@ -476,7 +476,7 @@ mod feat_ssr {
     use std::fmt::Write;
 
     use super::*;
-    use crate::feat_ssr::VTagKind;
+    use crate::feat_ssr::{DeferredSuspense, VTagKind};
     use crate::html::AnyScope;
     use crate::platform::fmt::BufWriter;
     use crate::virtual_dom::VText;
@@ -493,6 +493,7 @@ mod feat_ssr {
             w: &mut BufWriter,
             parent_scope: &AnyScope,
             hydratable: bool,
+            deferred: &DeferredSuspense,
         ) {
             let _ = w.write_str("<");
             let _ = w.write_str(self.tag());
@@ -544,7 +545,7 @@ mod feat_ssr {
                     let lowercase_tag = tag.to_ascii_lowercase();
                     if !VOID_ELEMENTS.contains(&lowercase_tag.as_ref()) {
                         children
-                            .render_into_stream(w, parent_scope, hydratable, tag.into())
+                            .render_into_stream(w, parent_scope, hydratable, deferred, tag.into())
                             .await;
 
                         let _ = w.write_str("</");
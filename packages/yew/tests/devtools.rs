@@ -0,0 +1,182 @@
+#![cfg(feature = "devtools")]
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::devtools::profiler::{self, RenderCause, RunKind};
+use yew::devtools::{self, ComponentEvent};
+use yew::prelude::*;
+use yew::scheduler;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq, Debug)]
+struct CounterProps {
+    start: u32,
+}
+
+#[component]
+fn Counter(props: &CounterProps) -> Html {
+    let counter = use_state(|| props.start);
+
+    html! { <span>{*counter}</span> }
+}
+
+#[component]
+fn App() -> Html {
+    html! {
+        <div id="result">
+            <Counter start={3} />
+        </div>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn devtools_inspects_components() {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let _subscription = devtools::subscribe({
+        let events = events.clone();
+        Callback::from(move |event| events.borrow_mut().push(event))
+    });
+
+    let app = yew::Renderer::<App>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+
+    scheduler::flush().await;
+    assert_eq!(obtain_result(), "<span>3</span>");
+
+    let tree = devtools::component_tree();
+    let app_node = tree
+        .iter()
+        .find(|m| m.type_name.ends_with("::App"))
+        .expect("app is mounted");
+    assert_eq!(app_node.children.len(), 1);
+
+    let counter = &app_node.children[0];
+    assert!(counter.type_name.ends_with("::Counter"));
+    assert!(counter.children.is_empty());
+
+    assert_eq!(
+        devtools::props(counter.id).as_deref(),
+        Some("CounterProps { start: 3 }")
+    );
+    assert_eq!(devtools::props(app_node.id).as_deref(), Some("()"));
+
+    let hooks = devtools::hooks(counter.id).expect("counter is a function component");
+    assert_eq!(hooks.len(), 1);
+    assert!(hooks[0].type_name.contains("u32"));
+    assert_eq!(
+        hooks[0].value.as_deref(),
+        Some("UseStateHandle { value: \"3\", .. }")
+    );
+
+    assert!(
+        events
+            .borrow()
+            .contains(&ComponentEvent::Created { id: counter.id })
+    );
+    assert!(
+        events
+            .borrow()
+            .contains(&ComponentEvent::Rendered { id: counter.id })
+    );
+
+    app.destroy();
+    scheduler::flush().await;
+
+    assert!(
+        events
+            .borrow()
+            .contains(&ComponentEvent::Destroyed { id: counter.id })
+    );
+    assert_eq!(devtools::props(counter.id), None);
+    assert_eq!(devtools::hooks(counter.id), None);
+}
+
+#[derive(Properties, PartialEq)]
+struct LabelProps {
+    text: AttrValue,
+    count: u32,
+}
+
+#[component]
+fn Label(props: &LabelProps) -> Html {
+    html! { <span>{&props.text}{props.count}</span> }
+}
+
+thread_local! {
+    static SET_COUNT: RefCell<Option<UseStateSetter<u32>>> = const { RefCell::new(None) };
+}
+
+#[component]
+fn ProfiledApp() -> Html {
+    let count = use_state(|| 0);
+    SET_COUNT.with(|m| *m.borrow_mut() = Some(count.setter()));
+
+    html! {
+        <div id="result">
+            <Label text="count: " count={*count} />
+        </div>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn profiler_records_render_causes() {
+    profiler::start();
+
+    let app = yew::Renderer::<ProfiledApp>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+
+    scheduler::flush().await;
+    assert_eq!(obtain_result(), "<span>count: 0</span>");
+
+    SET_COUNT.with(|m| m.borrow().as_ref().unwrap().set(1));
+    scheduler::flush().await;
+    assert_eq!(obtain_result(), "<span>count: 1</span>");
+
+    let profile = profiler::stop();
+    assert!(!profiler::is_profiling());
+
+    let causes_of = |suffix: &str, kind: RunKind| {
+        profile
+            .entries()
+            .iter()
+            .filter(|m| m.type_name.ends_with(suffix) && m.kind == kind)
+            .map(|m| m.causes.clone())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        causes_of("::ProfiledApp", RunKind::Render),
+        vec![vec![RenderCause::FirstRender], vec![RenderCause::Message]]
+    );
+
+    let props_changed = RenderCause::Props {
+        changed_fields: vec!["count"],
+    };
+    assert_eq!(
+        causes_of("::Label", RunKind::PropsUpdate),
+        vec![vec![props_changed.clone()]]
+    );
+    assert_eq!(
+        causes_of("::Label", RunKind::Render),
+        vec![vec![RenderCause::FirstRender], vec![props_changed]]
+    );
+
+    let trace = profile.to_chrome_trace();
+    assert!(trace.starts_with(r#"{"traceEvents":[{"name":""#));
+    assert!(trace.contains(r#""cat":"props_update""#));
+    assert!(trace.contains(r#""causes":["props (count)"]"#));
+
+    app.destroy();
+    scheduler::flush().await;
+}
@@ -0,0 +1,154 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use common::obtain_result;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::error_boundary::CaughtError;
+use yew::html::RenderError;
+use yew::prelude::*;
+use yew::scheduler;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn click(selector: &str) {
+    gloo::utils::document()
+        .query_selector(selector)
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+}
+
+#[derive(Properties, PartialEq)]
+struct ContentProps {
+    fail: bool,
+}
+
+#[component]
+fn Content(props: &ContentProps) -> HtmlResult {
+    if props.fail {
+        return Err(RenderError::error(std::fmt::Error));
+    }
+
+    Ok(html! { <div class="content">{"content"}</div> })
+}
+
+#[wasm_bindgen_test]
+async fn error_boundary_works() {
+    #[component(App)]
+    fn app() -> Html {
+        let fail = use_state(|| true);
+
+        let fallback = {
+            let fail = fail.clone();
+            Callback::from(move |e: CaughtError| {
+                let onclick = {
+                    let fail = fail.clone();
+                    let reset = e.reset();
+                    Callback::from(move |_: MouseEvent| {
+                        fail.set(false);
+                        reset.emit(());
+                    })
+                };
+
+                html! { <button class="retry" {onclick}>{e.to_string()}</button> }
+            })
+        };
+
+        html! {
+            <div id="result">
+                <ErrorBoundary {fallback}>
+                    <Content fail={*fail} />
+                </ErrorBoundary>
+            </div>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    scheduler::flush().await;
+    assert_eq!(
+        obtain_result(),
+        r#"<button class="retry">an error occurred when formatting an argument</button>"#
+    );
+
+    click(".retry");
+
+    scheduler::flush().await;
+    assert_eq!(obtain_result(), r#"<div class="content">content</div>"#);
+}
+
+#[wasm_bindgen_test]
+async fn error_boundary_catches_errors_after_first_render() {
+    #[component(App)]
+    fn app() -> Html {
+        let fail = use_state(|| false);
+
+        let fallback = Callback::from(|e: CaughtError| html! { <span>{e.to_string()}</span> });
+        let onclick = {
+            let fail = fail.clone();
+            Callback::from(move |_: MouseEvent| fail.set(true))
+        };
+
+        html! {
+            <>
+                <button class="fail" {onclick}>{"fail"}</button>
+                <div id="result">
+                    <div class="before" />
+                    <ErrorBoundary {fallback}>
+                        <Content fail={false} />
+                        <Content fail={*fail} />
+                    </ErrorBoundary>
+                    <div class="after" />
+                </div>
+            </>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    scheduler::flush().await;
+    assert_eq!(
+        obtain_result(),
+        r#"<div class="before"></div><div class="content">content</div><div class="content">content</div><div class="after"></div>"#
+    );
+
+    click(".fail");
+
+    scheduler::flush().await;
+    assert_eq!(
+        obtain_result(),
+        r#"<div class="before"></div><span>an error occurred when formatting an argument</span><div class="after"></div>"#
+    );
+}
+
+#[wasm_bindgen_test]
+async fn error_boundary_fallback_errors_are_caught_by_parent() {
+    #[component(App)]
+    fn app() -> Html {
+        let outer = Callback::from(|_: CaughtError| html! { <span>{"outer"}</span> });
+        let inner = Callback::from(|_: CaughtError| html! { <Content fail=true /> });
+
+        html! {
+            <div id="result">
+                <ErrorBoundary fallback={outer}>
+                    <ErrorBoundary fallback={inner}>
+                        <Content fail=true />
+                    </ErrorBoundary>
+                </ErrorBoundary>
+            </div>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    scheduler::flush().await;
+    assert_eq!(obtain_result(), r#"<span>outer</span>"#);
+}
@@ -14,6 +14,7 @@ use wasm_bindgen::closure::Closure;
 use wasm_bindgen_futures::spawn_local;
 use wasm_bindgen_test::*;
 use web_sys::{HtmlElement, HtmlTextAreaElement};
+use yew::hydration::{AttributeMismatch, HydrationMismatch, HydrationMismatchPolicy};
 use yew::platform::time::sleep;
 use yew::prelude::*;
 use yew::suspense::{Suspension, SuspensionResult, use_future};
@@ -1319,3 +1320,578 @@ async fn hydration_does_not_reapply_matching_attributes() {
     assert_eq!(img.get_attribute("alt").as_deref(), Some("a picture"));
     assert_eq!(img.get_attribute("class").as_deref(), Some("pic"));
 }
+
+#[wasm_bindgen_test]
+async fn hydration_with_error_boundary() {
+    #[component]
+    fn Broken() -> HtmlResult {
+        Err(yew::html::RenderError::error(std::fmt::Error))
+    }
+
+    #[component(App)]
+    fn app() -> Html {
+        let fallback = Callback::from(|e: yew::error_boundary::CaughtError| {
+            html! { <p class="fallback">{e.to_string()}</p> }
+        });
+
+        html! {
+            <div id="result">
+                <ErrorBoundary {fallback}>
+                    <Broken />
+                </ErrorBoundary>
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    scheduler::flush().await;
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate();
+
+    scheduler::flush().await;
+
+    // The fallback rendered on the server is hydrated in place.
+    assert_eq!(
+        obtain_result(),
+        r#"<p class="fallback">an error occurred when formatting an argument</p>"#
+    );
+}
+
+#[wasm_bindgen_test]
+async fn hydration_with_out_of_order_suspense() {
+    #[component(Content)]
+    fn content() -> HtmlResult {
+        use_future(|| async {
+            sleep(Duration::from_millis(50)).await;
+        })?;
+
+        Ok(html! { <div class="content-area">{"done"}</div> })
+    }
+
+    #[component(App)]
+    fn app() -> Html {
+        let fallback = html! {<div>{"wait..."}</div>};
+
+        html! {
+            <div id="result">
+                <Suspense {fallback}>
+                    <Content />
+                </Suspense>
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new()
+        .out_of_order_streaming(true)
+        .render()
+        .await;
+
+    assert!(s.contains(r#"<template data-yew-pending="0"></template>"#));
+    assert!(s.contains(r#"<template data-yew-resolved="0">"#));
+
+    // Scripts inserted with `innerHTML` are not executed, so the children are never swapped in
+    // before hydration.
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    scheduler::flush().await;
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate();
+
+    sleep(Duration::from_millis(10)).await;
+
+    // the fallback rendered by the server is shown while the children are rendered.
+    let result = obtain_result();
+    assert!(result.contains("<div>wait...</div>"));
+    assert!(!result.contains("<template"));
+
+    sleep(Duration::from_millis(60)).await;
+
+    let result = obtain_result();
+    assert_eq!(result.as_str(), r#"<div class="content-area">done</div>"#);
+}
+
+#[wasm_bindgen_test]
+async fn hydration_with_suspense_replays_events() {
+    #[component(Content)]
+    fn content() -> HtmlResult {
+        let ctr = use_state(|| 0);
+
+        use_future(|| async {
+            sleep(Duration::from_millis(50)).await;
+        })?;
+
+        let onclick = {
+            let ctr = ctr.clone();
+            Callback::from(move |_: MouseEvent| ctr.set(*ctr + 1))
+        };
+
+        Ok(html! {
+            <>
+                <span>{*ctr}</span>
+                <button class="increase" {onclick}>{"increase"}</button>
+            </>
+        })
+    }
+
+    thread_local! {
+        static OUTER_CLICKS: std::cell::Cell<u32> = const { std::cell::Cell::new(0) };
+    }
+
+    #[component(App)]
+    fn app() -> Html {
+        let fallback = html! {<div>{"wait..."}</div>};
+        let onclick = Callback::from(|_: MouseEvent| OUTER_CLICKS.with(|m| m.set(m.get() + 1)));
+
+        html! {
+            <div id="result" {onclick}>
+                <Suspense {fallback}>
+                    <Content />
+                </Suspense>
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    scheduler::flush().await;
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .progressive_hydration(true)
+        .hydrate();
+
+    sleep(Duration::from_millis(10)).await;
+
+    // The server-rendered button is clicked while the suspense is still hydrating.
+    gloo::utils::document()
+        .query_selector(".increase")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    // Listeners outside of the suspense receive the click right away.
+    assert_eq!(OUTER_CLICKS.with(|m| m.get()), 1);
+
+    sleep(Duration::from_millis(10)).await;
+
+    let result = obtain_result();
+    assert_eq!(
+        result.as_str(),
+        r#"<!--<[hydration::hydration_with_suspense_replays_events::{{closure}}::Content]>--><span>0</span><button class="increase">increase</button><!--</[hydration::hydration_with_suspense_replays_events::{{closure}}::Content]>-->"#
+    );
+
+    sleep(Duration::from_millis(50)).await;
+
+    // The click is replayed once the suspense is hydrated.
+    let result = obtain_result();
+    assert_eq!(
+        result.as_str(),
+        r#"<span>1</span><button class="increase">increase</button>"#
+    );
+    // The replayed click does not reach them again.
+    assert_eq!(OUTER_CLICKS.with(|m| m.get()), 1);
+}
+
+#[derive(Properties, PartialEq)]
+struct MismatchProps {
+    client: bool,
+}
+
+async fn hydrate_mismatched<COMP>() -> Vec<HydrationMismatch>
+where
+    COMP: BaseComponent<Properties = MismatchProps>,
+{
+    let s = yew::LocalServerRenderer::<COMP>::with_props(MismatchProps { client: false })
+        .render()
+        .await;
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    scheduler::flush().await;
+
+    let mismatches = Rc::new(RefCell::new(Vec::new()));
+    let policy = HydrationMismatchPolicy::Callback({
+        let mismatches = mismatches.clone();
+        Callback::from(move |m| mismatches.borrow_mut().push(m))
+    });
+
+    Renderer::<COMP>::with_root_and_props(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+        MismatchProps { client: true },
+    )
+    .hydration_mismatch_policy(policy)
+    .hydrate();
+
+    scheduler::flush().await;
+
+    mismatches.take()
+}
+
+#[wasm_bindgen_test]
+async fn hydration_mismatch_renders_on_client() {
+    #[component(App)]
+    fn app(props: &MismatchProps) -> Html {
+        let content = if props.client {
+            html! { <p class="client">{"client"}</p> }
+        } else {
+            html! { <span>{"server"}</span> }
+        };
+
+        html! {
+            <div id="result">
+                {content}
+                <div class="after">{"after"}</div>
+            </div>
+        }
+    }
+
+    let mismatches = hydrate_mismatched::<App>().await;
+
+    assert_eq!(
+        obtain_result(),
+        r#"<p class="client">client</p><div class="after">after</div>"#
+    );
+
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].expected(), r#"<p class="client">"#);
+    assert_eq!(mismatches[0].found(), Some("<span>"));
+    assert!(
+        mismatches[0]
+            .component_path()
+            .last()
+            .unwrap()
+            .ends_with("App")
+    );
+}
+
+#[cfg(debug_assertions)]
+#[wasm_bindgen_test]
+async fn hydration_mismatch_reports_attributes() {
+    #[component(App)]
+    fn app(props: &MismatchProps) -> Html {
+        let class = if props.client { "client" } else { "server" };
+
+        html! {
+            <div id="result">
+                <div {class}>{"content"}</div>
+            </div>
+        }
+    }
+
+    let mismatches = hydrate_mismatched::<App>().await;
+
+    assert_eq!(obtain_result(), r#"<div class="client">content</div>"#);
+
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(
+        mismatches[0].attributes(),
+        [AttributeMismatch {
+            name: "class".to_owned(),
+            server: Some("server".to_owned()),
+            client: Some("client".to_owned()),
+        }]
+    );
+}
+
+#[cfg(debug_assertions)]
+#[wasm_bindgen_test]
+async fn hydration_mismatch_reports_server_only_attributes() {
+    #[component(App)]
+    fn app(props: &MismatchProps) -> Html {
+        let title = (!props.client).then_some("server");
+
+        html! {
+            <div id="result">
+                <div class="content" {title}>{"content"}</div>
+            </div>
+        }
+    }
+
+    let mismatches = hydrate_mismatched::<App>().await;
+
+    assert_eq!(obtain_result(), r#"<div class="content">content</div>"#);
+
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(
+        mismatches[0].attributes(),
+        [AttributeMismatch {
+            name: "title".to_owned(),
+            server: Some("server".to_owned()),
+            client: None,
+        }]
+    );
+}
+
+#[wasm_bindgen_test]
+async fn hydration_mismatch_skips_nodes_in_place_of_component() {
+    #[component(Child)]
+    fn child() -> Html {
+        html! { <p>{"child"}</p> }
+    }
+
+    #[component(App)]
+    fn app(props: &MismatchProps) -> Html {
+        let content = if props.client {
+            html! { <Child /> }
+        } else {
+            html! { <span>{"server"}</span> }
+        };
+
+        html! {
+            <div id="result">
+                {content}
+                <div class="after">{"after"}</div>
+            </div>
+        }
+    }
+
+    let mismatches = hydrate_mismatched::<App>().await;
+
+    assert_eq!(
+        obtain_result(),
+        r#"<p>child</p><div class="after">after</div>"#
+    );
+
+    // The following sibling is hydrated without a mismatch of its own.
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].found(), Some("<span>"));
+}
+
+#[wasm_bindgen_test]
+async fn hydration_use_id_matches_server() {
+    thread_local! {
+        static IDS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn take_ids() -> Vec<String> {
+        let mut ids = IDS.with(|m| m.take());
+        ids.sort();
+        ids.dedup();
+        ids
+    }
+
+    #[component(Field)]
+    fn field() -> Html {
+        let id = use_id();
+        IDS.with(|m| m.borrow_mut().push(id.to_string()));
+
+        html! { <label for={id}>{"field"}</label> }
+    }
+
+    #[component(Content)]
+    fn content() -> HtmlResult {
+        let id = use_id();
+        IDS.with(|m| m.borrow_mut().push(id.to_string()));
+
+        use_future(|| async {
+            sleep(Duration::from_millis(50)).await;
+        })?;
+
+        Ok(html! {
+            <>
+                <label for={id}>{"content"}</label>
+                <Field />
+            </>
+        })
+    }
+
+    #[component(App)]
+    fn app() -> Html {
+        let fallback = html! {<div>{"wait..."}</div>};
+
+        html! {
+            <div id="result">
+                <Field />
+                <Suspense {fallback}>
+                    <Content />
+                </Suspense>
+                <Field />
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+    let server_ids = take_ids();
+    assert_eq!(server_ids.len(), 4);
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    scheduler::flush().await;
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate();
+
+    sleep(Duration::from_millis(100)).await;
+
+    assert_eq!(take_ids(), server_ids);
+
+    let result = obtain_result();
+    for id in server_ids.iter() {
+        assert!(result.contains(&format!(r#"for="{id}""#)));
+    }
+}
+
+#[wasm_bindgen_test]
+async fn hydration_use_id_matches_server_in_error_boundary() {
+    thread_local! {
+        static IDS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn take_ids() -> Vec<String> {
+        let mut ids = IDS.with(|m| m.take());
+        ids.sort();
+        ids.dedup();
+        ids
+    }
+
+    #[component(Field)]
+    fn field() -> Html {
+        let id = use_id();
+        IDS.with(|m| m.borrow_mut().push(id.to_string()));
+
+        html! { <label for={id}>{"field"}</label> }
+    }
+
+    #[component(Broken)]
+    fn broken() -> HtmlResult {
+        Err(yew::html::RenderError::error(std::fmt::Error))
+    }
+
+    #[component(App)]
+    fn app() -> Html {
+        let fallback = Callback::from(|_: yew::error_boundary::CaughtError| {
+            html! { <Field /> }
+        });
+
+        html! {
+            <div id="result">
+                <ErrorBoundary {fallback}>
+                    <Field />
+                    <Broken />
+                </ErrorBoundary>
+                <Field />
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().id_prefix("app").render().await;
+    // The ids of the children that failed to render are not in the document.
+    let server_ids = take_ids()
+        .into_iter()
+        .filter(|id| s.contains(&format!(r#"for="{id}""#)))
+        .collect::<Vec<_>>();
+    assert_eq!(server_ids.len(), 2);
+    assert!(server_ids.iter().all(|id| id.starts_with("yew-app-")));
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    scheduler::flush().await;
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .id_prefix("app")
+        .hydrate();
+
+    scheduler::flush().await;
+
+    // The fallback is hydrated with the ids generated on the server.
+    assert_eq!(take_ids(), server_ids);
+
+    let result = obtain_result();
+    for id in server_ids.iter() {
+        assert!(result.contains(&format!(r#"for="{id}""#)));
+    }
+}
+
+#[wasm_bindgen_test]
+async fn hydration_with_out_of_order_suspense_swapped() {
+    #[component(Content)]
+    fn content() -> HtmlResult {
+        use_future(|| async {
+            sleep(Duration::from_millis(50)).await;
+        })?;
+
+        Ok(html! { <div class="content-area">{"done"}</div> })
+    }
+
+    #[component(App)]
+    fn app() -> Html {
+        let fallback = html! {<div>{"wait..."}</div>};
+
+        html! {
+            <div id="result">
+                <Suspense {fallback}>
+                    <Content />
+                </Suspense>
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new()
+        .out_of_order_streaming(true)
+        .render()
+        .await;
+
+    let output = gloo::utils::document().get_element_by_id("output").unwrap();
+    output.set_inner_html(&s);
+
+    // Scripts inserted with `innerHTML` are not executed, so they are run by hand to swap the
+    // children in before hydration, as they would be when the document is streamed.
+    let scripts = output.query_selector_all("script").unwrap();
+    for i in 0..scripts.length() {
+        let script = scripts.item(i).unwrap().text_content().unwrap();
+        js_sys::eval(&script).unwrap();
+    }
+
+    let result = obtain_result();
+    assert!(result.contains(r#"<div class="content-area">done</div>"#));
+    assert!(!result.contains("wait..."));
+    assert!(!output.inner_html().contains("<template"));
+
+    scheduler::flush().await;
+
+    Renderer::<App>::with_root(output).hydrate();
+
+    sleep(Duration::from_millis(10)).await;
+
+    // the children swapped in are shown while they are hydrated.
+    let result = obtain_result();
+    assert!(result.contains(r#"<div class="content-area">done</div>"#));
+    assert!(!result.contains("wait..."));
+
+    sleep(Duration::from_millis(60)).await;
+
+    let result = obtain_result();
+    assert_eq!(result.as_str(), r#"<div class="content-area">done</div>"#);
+}
@@ -2,6 +2,7 @@
 
 mod common;
 
+use std::cell::RefCell;
 use std::ops::{Deref, DerefMut};
 use std::rc::Rc;
 
@@ -185,3 +186,114 @@ async fn use_effect_refires_on_dependency_change() {
 
     assert_eq!(result.as_str(), "11");
 }
+
+#[wasm_bindgen_test]
+async fn use_layout_effect_runs_before_use_effect() {
+    thread_local! {
+        static EVENTS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+        static SET_COUNTER: RefCell<Option<UseStateSetter<u32>>> = const { RefCell::new(None) };
+    }
+
+    fn push_event(event: &str) {
+        EVENTS.with(|m| m.borrow_mut().push(event.to_owned()));
+    }
+
+    #[component]
+    fn Measured() -> Html {
+        let counter = use_state(|| 0);
+        SET_COUNTER.with(|m| *m.borrow_mut() = Some(counter.setter()));
+
+        let result_ref = use_node_ref();
+
+        use_effect(|| push_event("effect"));
+
+        {
+            let result_ref = result_ref.clone();
+            use_layout_effect(move || {
+                // The DOM has been updated when layout effects run.
+                let text = result_ref.get().and_then(|m| m.text_content()).unwrap();
+                push_event(&format!("layout {text}"));
+
+                || push_event("layout destroy")
+            });
+        }
+
+        html! { <div id="result" ref={result_ref}>{*counter}</div> }
+    }
+
+    yew::Renderer::<Measured>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    scheduler::flush().await;
+
+    SET_COUNTER.with(|m| m.borrow().as_ref().unwrap().set(1));
+    scheduler::flush().await;
+
+    assert_eq!(obtain_result(), "1");
+    EVENTS.with(|m| {
+        assert_eq!(
+            *m.borrow(),
+            ["layout 0", "effect", "layout destroy", "layout 1", "effect"]
+        )
+    });
+}
+
+#[wasm_bindgen_test]
+async fn use_layout_effect_runs_after_children_are_rendered() {
+    thread_local! {
+        static EVENTS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn push_event(event: &str) {
+        EVENTS.with(|m| m.borrow_mut().push(event.to_owned()));
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct ChildProps {
+        node_ref: NodeRef,
+    }
+
+    #[component]
+    fn Child(props: &ChildProps) -> Html {
+        use_layout_effect(|| push_event("child layout"));
+        use_effect(|| push_event("child effect"));
+
+        html! { <div ref={props.node_ref.clone()}>{"child"}</div> }
+    }
+
+    #[component]
+    fn Parent() -> Html {
+        let child_ref = use_node_ref();
+
+        {
+            let child_ref = child_ref.clone();
+            use_layout_effect(move || {
+                // The DOM of the children has been rendered when layout effects run.
+                let text = child_ref.get().and_then(|m| m.text_content()).unwrap();
+                push_event(&format!("parent layout {text}"));
+            });
+        }
+        use_effect(|| push_event("parent effect"));
+
+        html! { <Child node_ref={child_ref} /> }
+    }
+
+    yew::Renderer::<Parent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    scheduler::flush().await;
+
+    EVENTS.with(|m| {
+        assert_eq!(
+            *m.borrow(),
+            [
+                "child layout",
+                "parent layout child",
+                "child effect",
+                "parent effect"
+            ]
+        )
+    });
+}
@@ -0,0 +1,63 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+use std::cell::RefCell;
+
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::scheduler;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_id_is_unique_across_renderers() {
+    thread_local! {
+        static IDS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    #[component(Field)]
+    fn field() -> Html {
+        let id = use_id();
+        IDS.with(|m| m.borrow_mut().push(id.to_string()));
+
+        html! { <label for={id}>{"field"}</label> }
+    }
+
+    let document = gloo::utils::document();
+    let output = document.get_element_by_id("output").unwrap();
+    let roots = (0..2)
+        .map(|_| {
+            let root = document.create_element("div").unwrap();
+            output.append_child(&root).unwrap();
+            root
+        })
+        .collect::<Vec<_>>();
+
+    let apps = roots
+        .into_iter()
+        .map(|root| yew::Renderer::<Field>::with_root(root).render())
+        .collect::<Vec<_>>();
+
+    scheduler::flush().await;
+
+    let ids = IDS.with(|m| m.take());
+    assert_eq!(ids.len(), 2);
+    assert_ne!(ids[0], ids[1]);
+
+    // Ids can be prefixed to tell the applications apart.
+    let root = document.create_element("div").unwrap();
+    output.append_child(&root).unwrap();
+    let app = yew::Renderer::<Field>::with_root(root)
+        .id_prefix("sidebar")
+        .render();
+
+    scheduler::flush().await;
+
+    let ids = IDS.with(|m| m.take());
+    assert_eq!(ids.len(), 1);
+    assert!(ids[0].starts_with("yew-sidebar-"));
+
+    for app in apps {
+        app.destroy();
+    }
+    app.destroy();
+}
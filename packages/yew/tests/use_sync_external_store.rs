@@ -0,0 +1,105 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::cell::RefCell;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::scheduler;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_sync_external_store_follows_store() {
+    #[derive(Default)]
+    struct Store {
+        value: u32,
+        listeners: Vec<(usize, Callback<()>)>,
+        next_id: usize,
+    }
+
+    impl Store {
+        fn set(value: u32) {
+            let listeners: Vec<_> = STORE.with(|m| {
+                let mut m = m.borrow_mut();
+                m.value = value;
+                m.listeners.iter().map(|(_, m)| m.clone()).collect()
+            });
+
+            for listener in listeners {
+                listener.emit(());
+            }
+        }
+
+        fn listener_count() -> usize {
+            STORE.with(|m| m.borrow().listeners.len())
+        }
+    }
+
+    thread_local! {
+        static STORE: RefCell<Store> = RefCell::default();
+        static SET_SHOW: RefCell<Option<UseStateSetter<bool>>> = const { RefCell::new(None) };
+    }
+
+    fn use_store() -> impl Hook<Output = u32> {
+        use_sync_external_store(
+            |on_change| {
+                let id = STORE.with(|m| {
+                    let mut m = m.borrow_mut();
+                    let id = m.next_id;
+                    m.next_id += 1;
+                    m.listeners.push((id, on_change));
+                    id
+                });
+
+                move || STORE.with(|m| m.borrow_mut().listeners.retain(|(m, _)| *m != id))
+            },
+            || STORE.with(|m| m.borrow().value),
+            || 0,
+        )
+    }
+
+    #[component]
+    fn Counter() -> Html {
+        let value = use_store();
+
+        html! { <span>{value}</span> }
+    }
+
+    #[component]
+    fn App() -> Html {
+        let show = use_state(|| true);
+        SET_SHOW.with(|m| *m.borrow_mut() = Some(show.setter()));
+
+        html! {
+            <div id="result">
+                if *show {
+                    <Counter />
+                    <Counter />
+                }
+            </div>
+        }
+    }
+
+    Store::set(1);
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+    scheduler::flush().await;
+
+    assert_eq!(obtain_result(), "<span>1</span><span>1</span>");
+    assert_eq!(Store::listener_count(), 2);
+
+    Store::set(2);
+    scheduler::flush().await;
+
+    assert_eq!(obtain_result(), "<span>2</span><span>2</span>");
+
+    SET_SHOW.with(|m| m.borrow().as_ref().unwrap().set(false));
+    scheduler::flush().await;
+
+    assert_eq!(obtain_result(), "");
+    assert_eq!(Store::listener_count(), 0);
+}
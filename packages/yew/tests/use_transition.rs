@@ -0,0 +1,107 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::cell::RefCell;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::scheduler;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_transition_renders_urgent_updates_first() {
+    thread_local! {
+        static RENDERS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+        static SEARCH: RefCell<Option<Callback<String>>> = const { RefCell::new(None) };
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct ResultsProps {
+        query: String,
+    }
+
+    #[component]
+    fn Results(props: &ResultsProps) -> Html {
+        html! { <span>{&props.query}</span> }
+    }
+
+    #[component]
+    fn Search() -> Html {
+        let input = use_state(String::new);
+        let query = use_state(String::new);
+        let (is_pending, start_transition) = use_transition();
+
+        RENDERS.with(|m| {
+            m.borrow_mut()
+                .push(format!("{}|{}|{}", *input, *query, is_pending))
+        });
+
+        let search = {
+            let input = input.clone();
+            let query = query.clone();
+            Callback::from(move |value: String| {
+                input.set(value.clone());
+                let query = query.clone();
+                start_transition.start(move || query.set(value));
+            })
+        };
+        SEARCH.with(|m| *m.borrow_mut() = Some(search));
+
+        html! {
+            <div id="result">
+                <Results query={(*query).clone()} />
+            </div>
+        }
+    }
+
+    yew::Renderer::<Search>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    scheduler::flush().await;
+
+    SEARCH.with(|m| m.borrow().clone().unwrap().emit("yew".to_owned()));
+    scheduler::flush().await;
+
+    assert_eq!(obtain_result(), "<span>yew</span>");
+    RENDERS.with(|m| {
+        assert_eq!(
+            *m.borrow(),
+            ["||false", "yew||true", "yew|yew|true", "yew|yew|false"]
+        )
+    });
+}
+
+#[wasm_bindgen_test]
+async fn use_deferred_value_renders_new_value_in_transition() {
+    thread_local! {
+        static RENDERS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+        static SET_VALUE: RefCell<Option<UseStateSetter<u32>>> = const { RefCell::new(None) };
+    }
+
+    #[component]
+    fn Deferred() -> Html {
+        let value = use_state(|| 0);
+        SET_VALUE.with(|m| *m.borrow_mut() = Some(value.setter()));
+
+        let deferred = use_deferred_value(*value);
+        RENDERS.with(|m| m.borrow_mut().push(format!("{}|{}", *value, deferred)));
+
+        html! { <div id="result">{deferred}</div> }
+    }
+
+    yew::Renderer::<Deferred>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    scheduler::flush().await;
+
+    SET_VALUE.with(|m| m.borrow().as_ref().unwrap().set(1));
+    scheduler::flush().await;
+
+    assert_eq!(obtain_result(), "1");
+    RENDERS.with(|m| assert_eq!(*m.borrow(), ["0|0", "1|0", "1|1"]));
+}